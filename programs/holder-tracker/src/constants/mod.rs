@@ -0,0 +1,6 @@
+pub const ANCHOR_DISCRIMINATOR_SIZE: usize = 8;
+
+// Seeds
+pub const EXTRA_ACCOUNT_METAS_SEED: &[u8] = b"extra-account-metas"; // Fixed by the transfer hook interface
+pub const HOLDER_STATS_SEED: &[u8] = b"holder_stats";
+pub const POSITION_SEED: &[u8] = b"position";
@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Mint does not use this program as its transfer hook")]
+    InvalidTransferHook,
+
+    #[msg("Execute can only be called by Token-2022 during a transfer")]
+    NotTransferring,
+
+    #[msg("Arithmetic overflow")]
+    ArithmeticOverflow,
+
+    #[msg("Arithmetic underflow")]
+    ArithmeticUnderflow,
+}
@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022::extension::transfer_hook::TransferHookAccount;
+use anchor_spl::token_2022::spl_token_2022::extension::{BaseStateWithExtensions, StateWithExtensions};
+use anchor_spl::token_2022::spl_token_2022::state::Account as SplAccount;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::states::HolderStats;
+use crate::utils::sync_holder_position;
+
+/// Whether Token-2022 flagged the token account as mid-transfer, which it only
+/// does around its own call into the hook
+fn is_transferring(token_account: &AccountInfo) -> Result<bool> {
+    let data = token_account.try_borrow_data()?;
+    let account = StateWithExtensions::<SplAccount>::unpack(&data)?;
+    let transfer_hook_account = account
+        .get_extension::<TransferHookAccount>()
+        .map_err(|_| ErrorCode::NotTransferring)?;
+    Ok(bool::from(transfer_hook_account.transferring))
+}
+
+/// Transfer hook entrypoint, invoked by Token-2022 after every transfer of a tracked mint.
+/// Syncs the source and destination positions to their new balances.
+pub fn execute(ctx: Context<ExecuteCtx>, amount: u64) -> Result<()> {
+    let source_token = &ctx.accounts.source_token;
+    let destination_token = &ctx.accounts.destination_token;
+
+    // Direct calls could otherwise feed in arbitrary token accounts
+    if !is_transferring(&source_token.to_account_info())?
+        || !is_transferring(&destination_token.to_account_info())?
+    {
+        return Err(ErrorCode::NotTransferring.into());
+    }
+
+    let system_program = ctx.accounts.system_program.to_account_info();
+    sync_holder_position(
+        &mut ctx.accounts.holder_stats,
+        &ctx.accounts.source_position.to_account_info(),
+        &source_token.key(),
+        source_token.amount,
+        ctx.bumps.source_position,
+        &system_program,
+        None,
+    )?;
+
+    // Self-transfers leave the balance unchanged
+    if source_token.key() != destination_token.key() {
+        sync_holder_position(
+            &mut ctx.accounts.holder_stats,
+            &ctx.accounts.destination_position.to_account_info(),
+            &destination_token.key(),
+            destination_token.amount,
+            ctx.bumps.destination_position,
+            &system_program,
+            None,
+        )?;
+    }
+
+    msg!("Transfer of {} tracked, holders: {}", amount, ctx.accounts.holder_stats.holder_count);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ExecuteCtx<'info> {
+    #[account(token::mint = mint)]
+    pub source_token: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(token::mint = mint)]
+    pub destination_token: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: Source owner or delegate, authorized by Token-2022.
+    pub owner: UncheckedAccount<'info>,
+
+    /// CHECK: Extra account meta list for the mint.
+    #[account(
+        seeds = [EXTRA_ACCOUNT_METAS_SEED, mint.key().as_ref()],
+        bump
+    )]
+    pub extra_account_meta_list: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [HOLDER_STATS_SEED, mint.key().as_ref()],
+        bump = holder_stats.bump
+    )]
+    pub holder_stats: Account<'info, HolderStats>,
+
+    /// CHECK: Source token account's position, may not be opened yet.
+    #[account(
+        mut,
+        seeds = [POSITION_SEED, source_token.key().as_ref()],
+        bump
+    )]
+    pub source_position: UncheckedAccount<'info>,
+
+    /// CHECK: Destination token account's position, may not be opened yet.
+    #[account(
+        mut,
+        seeds = [POSITION_SEED, destination_token.key().as_ref()],
+        bump
+    )]
+    pub destination_position: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
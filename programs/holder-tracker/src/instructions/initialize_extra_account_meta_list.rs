@@ -0,0 +1,105 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022::extension::transfer_hook::TransferHook;
+use anchor_spl::token_2022::spl_token_2022::extension::{BaseStateWithExtensions, StateWithExtensions};
+use anchor_spl::token_2022::spl_token_2022::state::Mint as SplMint;
+use anchor_spl::token_interface::Mint;
+use spl_tlv_account_resolution::account::ExtraAccountMeta;
+use spl_tlv_account_resolution::seeds::Seed;
+use spl_tlv_account_resolution::state::ExtraAccountMetaList;
+use spl_transfer_hook_interface::instruction::ExecuteInstruction;
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::states::HolderStats;
+
+/// Accounts Token-2022 appends to every `execute` call for the mint:
+/// holder stats, the source and destination positions, then the system program
+pub fn extra_account_metas() -> Result<Vec<ExtraAccountMeta>> {
+    // Execute account indexes: 0 source, 1 mint, 2 destination, 3 owner, 4 meta list
+    let position_seeds = |token_account_index: u8| {
+        [
+            Seed::Literal {
+                bytes: POSITION_SEED.to_vec(),
+            },
+            Seed::AccountKey {
+                index: token_account_index,
+            },
+        ]
+    };
+
+    Ok(vec![
+        ExtraAccountMeta::new_with_seeds(
+            &[
+                Seed::Literal {
+                    bytes: HOLDER_STATS_SEED.to_vec(),
+                },
+                Seed::AccountKey { index: 1 },
+            ],
+            false,
+            true,
+        )?,
+        ExtraAccountMeta::new_with_seeds(&position_seeds(0), false, true)?,
+        ExtraAccountMeta::new_with_seeds(&position_seeds(2), false, true)?,
+        ExtraAccountMeta::new_with_pubkey(&system_program::ID, false, false)?,
+    ])
+}
+
+pub fn initialize_extra_account_meta_list(
+    ctx: Context<InitializeExtraAccountMetaListCtx>,
+) -> Result<()> {
+    // Only mints that route their transfer hook here can be tracked
+    {
+        let mint_info = ctx.accounts.mint.to_account_info();
+        let mint_data = mint_info.try_borrow_data()?;
+        let mint_state = StateWithExtensions::<SplMint>::unpack(&mint_data)?;
+        let transfer_hook = mint_state
+            .get_extension::<TransferHook>()
+            .map_err(|_| ErrorCode::InvalidTransferHook)?;
+        if Option::<Pubkey>::from(transfer_hook.program_id) != Some(crate::ID) {
+            return Err(ErrorCode::InvalidTransferHook.into());
+        }
+    }
+
+    ExtraAccountMetaList::init::<ExecuteInstruction>(
+        &mut ctx.accounts.extra_account_meta_list.try_borrow_mut_data()?,
+        &extra_account_metas()?,
+    )?;
+
+    let holder_stats = &mut ctx.accounts.holder_stats;
+    holder_stats.mint = ctx.accounts.mint.key();
+    holder_stats.holder_count = 0;
+    holder_stats.bump = ctx.bumps.holder_stats;
+
+    msg!("Holder tracking enabled for mint: {}", holder_stats.mint);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeExtraAccountMetaListCtx<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Extra account meta list, written in the handler.
+    #[account(
+        init,
+        payer = payer,
+        space = ExtraAccountMetaList::size_of(extra_account_metas()?.len())?,
+        seeds = [EXTRA_ACCOUNT_METAS_SEED, mint.key().as_ref()],
+        bump
+    )]
+    pub extra_account_meta_list: UncheckedAccount<'info>,
+
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = ANCHOR_DISCRIMINATOR_SIZE + HolderStats::INIT_SPACE,
+        seeds = [HOLDER_STATS_SEED, mint.key().as_ref()],
+        bump
+    )]
+    pub holder_stats: Account<'info, HolderStats>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,7 @@
+pub mod initialize_extra_account_meta_list;
+pub mod execute;
+pub mod sync_position;
+
+pub use initialize_extra_account_meta_list::*;
+pub use execute::*;
+pub use sync_position::*;
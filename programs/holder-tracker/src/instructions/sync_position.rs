@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+
+use crate::constants::*;
+use crate::states::HolderStats;
+use crate::utils::sync_holder_position;
+
+/// Permissionlessly syncs a token account's position to its balance. Mints and burns
+/// bypass the transfer hook, so the launchpad calls this after minting and anyone can
+/// call it after a burn. `payer` covers the rent if a position has to be opened.
+pub fn sync_position(ctx: Context<SyncPositionCtx>) -> Result<()> {
+    let token_account = &ctx.accounts.token_account;
+
+    sync_holder_position(
+        &mut ctx.accounts.holder_stats,
+        &ctx.accounts.position.to_account_info(),
+        &token_account.key(),
+        token_account.amount,
+        ctx.bumps.position,
+        &ctx.accounts.system_program.to_account_info(),
+        Some(&ctx.accounts.payer.to_account_info()),
+    )?;
+
+    msg!(
+        "Position synced: {} holds {}, holders: {}",
+        token_account.key(),
+        token_account.amount,
+        ctx.accounts.holder_stats.holder_count
+    );
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SyncPositionCtx<'info> {
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(token::mint = mint)]
+    pub token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [HOLDER_STATS_SEED, mint.key().as_ref()],
+        bump = holder_stats.bump
+    )]
+    pub holder_stats: Account<'info, HolderStats>,
+
+    /// CHECK: Token account's position, opened in the handler once it holds tokens.
+    #[account(
+        mut,
+        seeds = [POSITION_SEED, token_account.key().as_ref()],
+        bump
+    )]
+    pub position: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
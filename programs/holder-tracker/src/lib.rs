@@ -0,0 +1,35 @@
+#![allow(unexpected_cfgs)]
+#![allow(deprecated)]
+use anchor_lang::prelude::*;
+use spl_discriminator::SplDiscriminate;
+use spl_transfer_hook_interface::instruction::ExecuteInstruction;
+
+pub mod constants;
+pub mod errors;
+pub mod instructions;
+pub mod states;
+pub mod utils;
+
+use instructions::*;
+
+declare_id!("7gfVkkN4DQpTjZCHZtEY2Sjys1K5dQk9yV992piFrELc");
+
+#[program]
+pub mod holder_tracker {
+    use super::*;
+
+    pub fn initialize_extra_account_meta_list(
+        ctx: Context<InitializeExtraAccountMetaListCtx>,
+    ) -> Result<()> {
+        instructions::initialize_extra_account_meta_list(ctx)
+    }
+
+    #[instruction(discriminator = ExecuteInstruction::SPL_DISCRIMINATOR_SLICE)]
+    pub fn execute(ctx: Context<ExecuteCtx>, amount: u64) -> Result<()> {
+        instructions::execute(ctx, amount)
+    }
+
+    pub fn sync_position(ctx: Context<SyncPositionCtx>) -> Result<()> {
+        instructions::sync_position(ctx)
+    }
+}
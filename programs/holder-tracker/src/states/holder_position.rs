@@ -0,0 +1,14 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct HolderPosition {
+    /// Tracked token account
+    pub token_account: Pubkey,
+
+    /// Last recorded balance, counted in `HolderStats.holder_count` while non-zero
+    pub balance: u64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
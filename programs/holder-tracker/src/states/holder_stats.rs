@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+
+#[account]
+#[derive(InitSpace)]
+pub struct HolderStats {
+    /// Tracked mint
+    pub mint: Pubkey,
+
+    /// Number of positions with a non-zero balance
+    pub holder_count: u64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl HolderStats {
+    /// Count a token account in or out when its balance crosses zero
+    pub fn track_balance_change(&mut self, previous_balance: u64, balance: u64) -> Result<()> {
+        match (previous_balance > 0, balance > 0) {
+            (false, true) => {
+                self.holder_count = self
+                    .holder_count
+                    .checked_add(1)
+                    .ok_or(ErrorCode::ArithmeticOverflow)?;
+            }
+            (true, false) => {
+                self.holder_count = self
+                    .holder_count
+                    .checked_sub(1)
+                    .ok_or(ErrorCode::ArithmeticUnderflow)?;
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,5 @@
+pub mod holder_position;
+pub mod holder_stats;
+
+pub use holder_position::*;
+pub use holder_stats::*;
@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Allocate, Assign, Transfer};
+
+use crate::constants::*;
+use crate::states::{HolderPosition, HolderStats};
+
+/// Whether a position PDA has been opened
+fn is_open(position: &AccountInfo) -> bool {
+    position.owner == &crate::ID && !position.data_is_empty()
+}
+
+/// Brings a token account's position in line with its `balance`, counting the holder in
+/// or out of `holder_stats`. Only accounts with a position are counted: positions open once
+/// the account holds tokens and close back into the holder stats rent reserve when it is
+/// emptied. New positions are paid by `payer`, or by the reserve when there is none; with
+/// the reserve used up the account stays uncounted until someone syncs it.
+pub fn sync_holder_position<'info>(
+    holder_stats: &mut Account<'info, HolderStats>,
+    position: &AccountInfo<'info>,
+    token_account: &Pubkey,
+    balance: u64,
+    bump: u8,
+    system_program: &AccountInfo<'info>,
+    payer: Option<&AccountInfo<'info>>,
+) -> Result<()> {
+    if is_open(position) {
+        let mut holder_position = {
+            let data = position.try_borrow_data()?;
+            HolderPosition::try_deserialize(&mut &data[..])?
+        };
+        holder_stats.track_balance_change(holder_position.balance, balance)?;
+
+        if balance == 0 {
+            // Close the position, returning its rent to the reserve
+            holder_stats.add_lamports(position.lamports())?;
+            position.sub_lamports(position.lamports())?;
+            position.assign(&system_program::ID);
+            position.resize(0)?;
+        } else {
+            holder_position.balance = balance;
+            holder_position.try_serialize(&mut &mut position.try_borrow_mut_data()?[..])?;
+        }
+        return Ok(());
+    }
+
+    if balance == 0 {
+        return Ok(());
+    }
+
+    let space = ANCHOR_DISCRIMINATOR_SIZE + HolderPosition::INIT_SPACE;
+    let rent = Rent::get()?
+        .minimum_balance(space)
+        .saturating_sub(position.lamports());
+    match payer {
+        Some(payer) => system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                Transfer {
+                    from: payer.clone(),
+                    to: position.clone(),
+                },
+            ),
+            rent,
+        )?,
+        None => {
+            let holder_stats_info = holder_stats.to_account_info();
+            let reserve = holder_stats_info
+                .lamports()
+                .saturating_sub(Rent::get()?.minimum_balance(holder_stats_info.data_len()));
+            if reserve < rent {
+                msg!("Position reserve used up, {} is not tracked until synced", token_account);
+                return Ok(());
+            }
+            holder_stats_info.sub_lamports(rent)?;
+            position.add_lamports(rent)?;
+        }
+    }
+
+    let position_seeds: &[&[u8]] = &[POSITION_SEED, token_account.as_ref(), &[bump]];
+    system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            Allocate {
+                account_to_allocate: position.clone(),
+            },
+            &[position_seeds],
+        ),
+        space as u64,
+    )?;
+    system_program::assign(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            Assign {
+                account_to_assign: position.clone(),
+            },
+            &[position_seeds],
+        ),
+        &crate::ID,
+    )?;
+
+    let holder_position = HolderPosition {
+        token_account: *token_account,
+        balance,
+        bump,
+    };
+    holder_position.try_serialize(&mut &mut position.try_borrow_mut_data()?[..])?;
+    holder_stats.track_balance_change(0, balance)
+}
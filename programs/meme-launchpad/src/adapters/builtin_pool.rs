@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::adapters::{GraduationAdapter, LiquidityDeposit};
+use crate::states::LiquidityPool;
+
+/// Keeps the token trading in this program's own `LiquidityPool`
+pub struct BuiltinPoolAdapter<'a, 'info> {
+    pub liquidity_pool: &'a mut Account<'info, LiquidityPool>,
+    pub pool_token_vault: AccountInfo<'info>,
+    pub pool_bump: u8,
+}
+
+impl<'info> GraduationAdapter<'info> for BuiltinPoolAdapter<'_, 'info> {
+    fn sol_destination(&self) -> AccountInfo<'info> {
+        self.liquidity_pool.to_account_info()
+    }
+
+    fn token_destination(&self) -> AccountInfo<'info> {
+        self.pool_token_vault.clone()
+    }
+
+    fn create_pool(&mut self, deposit: &LiquidityDeposit<'info>) -> Result<Pubkey> {
+        let current_time = Clock::get()?.unix_timestamp;
+        let liquidity_pool = &mut self.liquidity_pool;
+
+        liquidity_pool.token_id = deposit.token_id;
+        liquidity_pool.mint = deposit.mint.key();
+        liquidity_pool.token_vault = self.pool_token_vault.key();
+        liquidity_pool.sol_reserves = deposit.sol_amount;
+        liquidity_pool.token_reserves = deposit.token_amount;
+        liquidity_pool.total_sol_volume = 0;
+        liquidity_pool.total_token_volume = 0;
+        liquidity_pool.created_at = current_time;
+        liquidity_pool.last_updated = current_time;
+        liquidity_pool.bump = self.pool_bump;
+
+        Ok(liquidity_pool.key())
+    }
+}
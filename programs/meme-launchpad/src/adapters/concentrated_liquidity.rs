@@ -0,0 +1,97 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
+
+use crate::adapters::{GraduationAdapter, LiquidityDeposit};
+use crate::constants::{AMM_POOL_SEED, LAUNCH_ESCROW_SEED};
+use crate::errors::ErrorCode;
+
+/// Anchor discriminator of `open_full_range_position` (sha256("global:open_full_range_position")[..8])
+const OPEN_FULL_RANGE_POSITION_DISCRIMINATOR: [u8; 8] = [219, 157, 81, 120, 147, 181, 14, 82];
+
+/// Opens a full-range position on an external concentrated-liquidity AMM.
+///
+/// The target program must expose an Anchor-style
+/// `open_full_range_position(sol_amount: u64, token_amount: u64)` instruction taking
+/// `[depositor, depositor_token_account, token_mint, payer, token_program, system_program]`
+/// followed by its own pool accounts, passed through as remaining accounts with the
+/// pool address first. The pool must be the AMM's `[AMM_POOL_SEED, mint, launch_escrow]`
+/// PDA and be owned by the AMM once the position is opened.
+pub struct ConcentratedLiquidityAdapter<'a, 'info> {
+    pub launch_escrow: AccountInfo<'info>,
+    pub escrow_bump: u8,
+    pub escrow_token_account: AccountInfo<'info>,
+    pub amm_program: AccountInfo<'info>,
+    pub pool_accounts: &'a [AccountInfo<'info>],
+}
+
+impl<'info> GraduationAdapter<'info> for ConcentratedLiquidityAdapter<'_, 'info> {
+    fn sol_destination(&self) -> AccountInfo<'info> {
+        self.launch_escrow.clone()
+    }
+
+    fn token_destination(&self) -> AccountInfo<'info> {
+        self.escrow_token_account.clone()
+    }
+
+    fn create_pool(&mut self, deposit: &LiquidityDeposit<'info>) -> Result<Pubkey> {
+        let pool = self
+            .pool_accounts
+            .first()
+            .ok_or(ErrorCode::InvalidPoolAccount)?;
+        let (expected_pool, _) = Pubkey::find_program_address(
+            &[AMM_POOL_SEED, deposit.mint.key.as_ref(), self.launch_escrow.key.as_ref()],
+            self.amm_program.key,
+        );
+        if pool.key() != expected_pool {
+            return Err(ErrorCode::InvalidPoolAccount.into());
+        }
+
+        let mut accounts = vec![
+            AccountMeta::new(self.launch_escrow.key(), true),
+            AccountMeta::new(self.escrow_token_account.key(), false),
+            AccountMeta::new_readonly(deposit.mint.key(), false),
+            AccountMeta::new(deposit.payer.key(), true),
+            AccountMeta::new_readonly(deposit.token_program.key(), false),
+            AccountMeta::new_readonly(deposit.system_program.key(), false),
+        ];
+        accounts.extend(self.pool_accounts.iter().map(|account| AccountMeta {
+            pubkey: account.key(),
+            is_signer: account.is_signer,
+            is_writable: account.is_writable,
+        }));
+
+        let mut data = OPEN_FULL_RANGE_POSITION_DISCRIMINATOR.to_vec();
+        data.extend_from_slice(&deposit.sol_amount.to_le_bytes());
+        data.extend_from_slice(&deposit.token_amount.to_le_bytes());
+
+        let instruction = Instruction {
+            program_id: self.amm_program.key(),
+            accounts,
+            data,
+        };
+
+        let mut account_infos = vec![
+            self.launch_escrow.clone(),
+            self.escrow_token_account.clone(),
+            deposit.mint.clone(),
+            deposit.payer.clone(),
+            deposit.token_program.clone(),
+            deposit.system_program.clone(),
+            self.amm_program.clone(),
+        ];
+        account_infos.extend_from_slice(self.pool_accounts);
+
+        // Deposit from the escrow (escrow PDA is signer)
+        let token_id_bytes = deposit.token_id.to_le_bytes();
+        let escrow_seeds: &[&[u8]] = &[LAUNCH_ESCROW_SEED, &token_id_bytes, &[self.escrow_bump]];
+
+        invoke_signed(&instruction, &account_infos, &[escrow_seeds])?;
+
+        if pool.owner != self.amm_program.key {
+            return Err(ErrorCode::InvalidPoolAccount.into());
+        }
+
+        Ok(pool.key())
+    }
+}
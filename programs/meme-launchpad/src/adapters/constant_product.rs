@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+
+use crate::adapters::{GraduationAdapter, LiquidityDeposit};
+use crate::constants::LAUNCH_ESCROW_SEED;
+
+/// Seeds an external constant-product AMM exposing the `simple_amm` interface
+pub struct ConstantProductAdapter<'info> {
+    pub launch_escrow: AccountInfo<'info>,
+    pub escrow_bump: u8,
+    pub escrow_token_account: AccountInfo<'info>,
+    pub escrow_lp_account: AccountInfo<'info>,
+    pub amm_pool: AccountInfo<'info>,
+    pub amm_lp_mint: AccountInfo<'info>,
+    pub amm_token_vault: AccountInfo<'info>,
+    pub amm_program: AccountInfo<'info>,
+    /// Transfer hook accounts forwarded to the AMM's token deposit
+    pub hook_accounts: &'info [AccountInfo<'info>],
+}
+
+impl<'info> GraduationAdapter<'info> for ConstantProductAdapter<'info> {
+    fn sol_destination(&self) -> AccountInfo<'info> {
+        self.launch_escrow.clone()
+    }
+
+    fn token_destination(&self) -> AccountInfo<'info> {
+        self.escrow_token_account.clone()
+    }
+
+    fn create_pool(&mut self, deposit: &LiquidityDeposit<'info>) -> Result<Pubkey> {
+        // Seed the AMM pool from the escrow (escrow PDA is signer)
+        let token_id_bytes = deposit.token_id.to_le_bytes();
+        let escrow_seeds: &[&[u8]] = &[LAUNCH_ESCROW_SEED, &token_id_bytes, &[self.escrow_bump]];
+        let escrow_signer_seeds: &[&[&[u8]]] = &[escrow_seeds];
+
+        let pool_accounts = simple_amm::cpi::accounts::InitializePoolCtx {
+            pool: self.amm_pool.clone(),
+            token_mint: deposit.mint.clone(),
+            lp_mint: self.amm_lp_mint.clone(),
+            token_vault: self.amm_token_vault.clone(),
+            depositor: self.launch_escrow.clone(),
+            depositor_token_account: self.escrow_token_account.clone(),
+            depositor_lp_account: self.escrow_lp_account.clone(),
+            payer: deposit.payer.clone(),
+            token_program: deposit.token_program.clone(),
+            associated_token_program: deposit.associated_token_program.clone(),
+            system_program: deposit.system_program.clone(),
+        };
+
+        let pool_ctx = CpiContext::new_with_signer(
+            self.amm_program.clone(),
+            pool_accounts,
+            escrow_signer_seeds,
+        )
+        .with_remaining_accounts(self.hook_accounts.to_vec());
+
+        simple_amm::cpi::initialize_pool(pool_ctx, deposit.sol_amount, deposit.token_amount)?;
+
+        Ok(self.amm_pool.key())
+    }
+
+    fn lp_token_account(&self) -> Option<AccountInfo<'info>> {
+        Some(self.escrow_lp_account.clone())
+    }
+}
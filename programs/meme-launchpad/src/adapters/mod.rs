@@ -0,0 +1,122 @@
+pub mod builtin_pool;
+pub mod constant_product;
+pub mod concentrated_liquidity;
+
+pub use builtin_pool::*;
+pub use constant_product::*;
+pub use concentrated_liquidity::*;
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::{self, MintTo};
+use holder_tracker::cpi::accounts::SyncPositionCtx;
+
+use crate::constants::BONDING_CURVE_SEED;
+use crate::errors::ErrorCode;
+
+/// Liquidity released from the bonding curve at launch
+pub struct LiquidityDeposit<'info> {
+    pub token_id: u64,
+    pub sol_amount: u64,
+    pub token_amount: u64,
+    pub mint: AccountInfo<'info>,
+    pub bonding_curve: AccountInfo<'info>,
+    pub bonding_curve_bump: u8,
+    pub payer: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    pub associated_token_program: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+
+    /// Holder tracker program, holder stats and the position of the adapter's token
+    /// destination, for tokens with holder tracking
+    pub holder_tracking: Option<(AccountInfo<'info>, AccountInfo<'info>, AccountInfo<'info>)>,
+}
+
+/// Pool created by an adapter
+pub struct SeededPool<'info> {
+    /// Pool address recorded on `TokenInfo.dex_pool`
+    pub pool: Pubkey,
+
+    /// Escrow-held LP token account, if the backend issues fungible LP tokens
+    pub lp_token_account: Option<AccountInfo<'info>>,
+}
+
+/// A graduation target that can receive launch liquidity
+pub trait GraduationAdapter<'info> {
+    /// Account credited with the liquidity SOL
+    fn sol_destination(&self) -> AccountInfo<'info>;
+
+    /// Token account the reserved liquidity tokens are minted into
+    fn token_destination(&self) -> AccountInfo<'info>;
+
+    /// Create the pool from the staged liquidity and return its address
+    fn create_pool(&mut self, deposit: &LiquidityDeposit<'info>) -> Result<Pubkey>;
+
+    /// LP token account credited by the pool, held by the launch escrow
+    fn lp_token_account(&self) -> Option<AccountInfo<'info>> {
+        None
+    }
+}
+
+/// Stage the liquidity at the adapter's destinations, then let it create the pool
+pub fn seed_liquidity<'info, A: GraduationAdapter<'info>>(
+    adapter: &mut A,
+    deposit: &LiquidityDeposit<'info>,
+) -> Result<SeededPool<'info>> {
+    let token_id_bytes = deposit.token_id.to_le_bytes();
+    let bonding_curve_seeds: &[&[u8]] = &[
+        BONDING_CURVE_SEED,
+        &token_id_bytes,
+        &[deposit.bonding_curve_bump],
+    ];
+    let signer_seeds: &[&[&[u8]]] = &[bonding_curve_seeds];
+
+    // Mint the reserved liquidity allocation (bonding_curve PDA is signer)
+    let token_destination = adapter.token_destination();
+    let mint_accounts = MintTo {
+        mint: deposit.mint.clone(),
+        to: token_destination.clone(),
+        authority: deposit.bonding_curve.clone(),
+    };
+
+    let mint_ctx = CpiContext::new_with_signer(
+        deposit.token_program.clone(),
+        mint_accounts,
+        signer_seeds,
+    );
+
+    token_2022::mint_to(mint_ctx, deposit.token_amount)?;
+
+    // Mints bypass the transfer hook, so sync the destination before the pool moves it
+    if let Some((holder_tracker_program, holder_stats, position)) = &deposit.holder_tracking {
+        holder_tracker::cpi::sync_position(CpiContext::new(
+            holder_tracker_program.clone(),
+            SyncPositionCtx {
+                mint: deposit.mint.clone(),
+                token_account: token_destination,
+                holder_stats: holder_stats.clone(),
+                position: position.clone(),
+                payer: deposit.payer.clone(),
+                system_program: deposit.system_program.clone(),
+            },
+        ))?;
+    }
+
+    // Move liquidity SOL out of the bonding curve PDA
+    let sol_destination = adapter.sol_destination();
+    **deposit.bonding_curve.try_borrow_mut_lamports()? = deposit
+        .bonding_curve
+        .lamports()
+        .checked_sub(deposit.sol_amount)
+        .ok_or(ErrorCode::ArithmeticUnderflow)?;
+    **sol_destination.try_borrow_mut_lamports()? = sol_destination
+        .lamports()
+        .checked_add(deposit.sol_amount)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    let pool = adapter.create_pool(deposit)?;
+
+    Ok(SeededPool {
+        pool,
+        lp_token_account: adapter.lp_token_account(),
+    })
+}
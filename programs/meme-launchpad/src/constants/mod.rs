@@ -2,9 +2,24 @@ pub const ANCHOR_DISCRIMINATOR_SIZE: usize = 8;
 
 // Platform constants
 pub const PLATFORM_FEE_RATE: u64 = 250; // 2.5% in basis points
-pub const LAUNCH_THRESHOLD: u64 = 1000_000_000_000; // 1000 SOL in lamports
-pub const MIN_TOKEN_PURCHASE: u64 = 100_000_000; // 0.1 SOL minimum purchase
-pub const MAX_TOKEN_PURCHASE: u64 = 10_000_000_000; // 10 SOL maximum purchase per transaction
+pub const LAUNCH_THRESHOLD: u64 = 1_000_000_000_000; // 1000 SOL in lamports
+pub const MIN_TOKEN_PURCHASE: u64 = 100_000_000; // 0.1 SOL default minimum purchase
+pub const MAX_TOKEN_PURCHASE: u64 = 10_000_000_000; // 10 SOL default maximum purchase per transaction
+pub const LAUNCH_LIQUIDITY_BPS: u64 = 8000; // Default 80% of reserves seeded as liquidity, 20% launch fee
+pub const MAX_PLATFORM_FEE_RATE: u64 = 1000; // Default 10% cap on the platform fee rate
+pub const MIN_LAUNCH_THRESHOLD: u64 = 100_000_000_000; // Default 100 SOL floor on the launch threshold
+pub const LAUNCHER_REWARD_BPS: u64 = 500; // 5% of the platform launch fee goes to the launcher
+pub const MAX_LAUNCHER_REWARD_BPS: u64 = 5000; // Launcher can get at most half of the launch fee
+
+// Hard bounds on `ProgramState.limits`
+pub const MIN_TOKEN_PURCHASE_FLOOR: u64 = 1_000_000; // 0.001 SOL
+pub const MAX_TOKEN_PURCHASE_CEILING: u64 = 1_000_000_000_000; // 1000 SOL
+pub const MAX_MIN_TRADING_TIME: i64 = 604800; // 7 days
+pub const MIN_LAUNCH_LIQUIDITY_BPS: u64 = 5000; // At least half of the reserves become liquidity
+pub const PLATFORM_FEE_RATE_CEILING: u64 = 2000; // 20%
+pub const LAUNCH_THRESHOLD_FLOOR: u64 = 1_000_000_000; // 1 SOL
+
+pub const MAX_GRADUATION_PROGRAMS: usize = 8; // Must match `ProgramState::graduation_programs` max_len
 
 // Token constants
 pub const TOKEN_NAME_MAX_LEN: usize = 32;
@@ -16,6 +31,12 @@ pub const TOKEN_DESCRIPTION_MAX_LEN: usize = 512;
 pub const INITIAL_VIRTUAL_SOL_RESERVES: u64 = 30_000_000_000; // 30 SOL
 pub const INITIAL_VIRTUAL_TOKEN_RESERVES: u64 = 1_073_000_000_000_000; // ~73% of max supply
 pub const MAX_TOKEN_SUPPLY: u64 = 1_000_000_000_000_000; // 1 billion tokens (with 6 decimals)
+pub const LIQUIDITY_RESERVE_BPS: u64 = 2000; // 20% of supply reserved for DEX liquidity
+pub const MAX_TRANSFER_FEE_BPS: u16 = 500; // 5% cap on the optional Token-2022 transfer fee
+pub const MAX_CREATOR_ALLOCATION_BPS: u16 = 1000; // Creator can vest at most 10% of supply
+pub const MAX_AIRDROP_ALLOCATION_BPS: u16 = 2000; // At most 20% of supply can be airdropped
+pub const MAX_AIRDROP_PROOF_LEN: usize = 32; // Supports up to 2^32 claimants
+pub const HOLDER_POSITION_RESERVE: u64 = 100_000_000; // 0.1 SOL of rent for holder positions the transfer hook opens
 
 // Seeds
 pub const PROGRAM_STATE_SEED: &[u8] = b"program_state";
@@ -23,7 +44,24 @@ pub const TOKEN_INFO_SEED: &[u8] = b"token_info";
 pub const BONDING_CURVE_SEED: &[u8] = b"bonding_curve";
 pub const TRANSACTION_SEED: &[u8] = b"transaction";
 pub const USER_TOKEN_ACCOUNT_SEED: &[u8] = b"user_token_account";
+pub const LAUNCH_ESCROW_SEED: &[u8] = b"launch_escrow";
+pub const LIQUIDITY_POOL_SEED: &[u8] = b"liquidity_pool";
+pub const POOL_VAULT_SEED: &[u8] = b"pool_vault";
+pub const MINT_SEED: &[u8] = b"mint";
+pub const VESTING_ESCROW_SEED: &[u8] = b"vesting_escrow";
+pub const AIRDROP_SEED: &[u8] = b"airdrop";
+pub const AIRDROP_CLAIM_SEED: &[u8] = b"airdrop_claim";
+pub const CREATOR_VAULT_SEED: &[u8] = b"creator_vault";
+pub const PLATFORM_FEE_VAULT_SEED: &[u8] = b"platform_fee_vault";
+pub const AMM_POOL_SEED: &[u8] = b"pool"; // External AMM pool PDA: [AMM_POOL_SEED, mint, launch_escrow]
 
 // Timing constants (in seconds)
 pub const LAUNCH_COOLDOWN: i64 = 86400; // 24 hours
-pub const MIN_TRADING_TIME: i64 = 3600; // 1 hour minimum before launch
\ No newline at end of file
+pub const MIN_TRADING_TIME: i64 = 3600; // Default 1 hour minimum before launch
+pub const LAUNCH_GRACE_PERIOD: i64 = 3600; // Creator/authority-only launch window after curve completion
+pub const MAX_LAUNCH_GRACE_PERIOD: i64 = 604800; // 7 days
+pub const MAX_VESTING_DURATION: i64 = 126_144_000; // 4 years
+pub const MIN_LP_LOCK_DURATION: i64 = 2_592_000; // Locked LP stays in escrow at least 30 days after launch
+pub const GOVERNANCE_DELAY: i64 = 172800; // 48 hours notice before settings/treasury changes
+pub const MIN_GOVERNANCE_DELAY: i64 = 86400; // 24 hours
+pub const MAX_GOVERNANCE_DELAY: i64 = 2592000; // 30 days
\ No newline at end of file
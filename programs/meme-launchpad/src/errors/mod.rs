@@ -111,5 +111,134 @@ pub enum ErrorCode {
     InvalidCreator,
 
      #[msg("Invalid token account")]
-    InvalidTokenAccount
-}
\ No newline at end of file
+    InvalidTokenAccount,
+
+    #[msg("Invalid liquidity pool account")]
+    InvalidPoolAccount,
+
+    #[msg("Graduation program not allowlisted")]
+    GraduationProgramNotAllowed,
+
+    #[msg("Graduation program allowlist is full")]
+    GraduationProgramListFull,
+
+    #[msg("Invalid graduation target")]
+    InvalidGraduationTarget,
+
+    #[msg("Invalid unlock timestamp")]
+    InvalidUnlockTimestamp,
+
+    #[msg("LP tokens are still locked")]
+    LiquidityLocked,
+
+    #[msg("No locked LP tokens")]
+    NoLockedLiquidity,
+
+    #[msg("Bonding curve complete, awaiting launch")]
+    BondingCurveComplete,
+
+    #[msg("Launch grace period active, only the creator or authority may launch")]
+    LaunchGracePeriodActive,
+
+    #[msg("Invalid launcher reward")]
+    InvalidLauncherReward,
+
+    #[msg("Invalid launch grace period")]
+    InvalidLaunchGracePeriod,
+
+    #[msg("Invalid mint authority")]
+    InvalidMintAuthority,
+
+    #[msg("Compliance authority not set")]
+    ComplianceAuthorityNotSet,
+
+    #[msg("Bonding curve does not hold the freeze authority")]
+    FreezeAuthorityNotHeld,
+
+    #[msg("Token metadata is locked")]
+    MetadataLocked,
+
+    #[msg("Invalid transfer fee")]
+    InvalidTransferFee,
+
+    #[msg("Transfer fee not enabled for this token")]
+    TransferFeeNotEnabled,
+
+    #[msg("Transfer lock requires the bonding curve as freeze authority")]
+    InvalidTransferLock,
+
+    #[msg("Token is not transfer-locked or not launched yet")]
+    TransferLockActive,
+
+    #[msg("Holder tracker accounts missing")]
+    HolderTrackerAccountsMissing,
+
+    #[msg("Invalid vesting schedule")]
+    InvalidVestingSchedule,
+
+    #[msg("Vesting accounts missing")]
+    VestingAccountsMissing,
+
+    #[msg("No vested tokens to claim")]
+    NothingVested,
+
+    #[msg("Invalid airdrop allocation")]
+    InvalidAirdrop,
+
+    #[msg("Airdrop accounts missing")]
+    AirdropAccountsMissing,
+
+    #[msg("Invalid airdrop proof")]
+    InvalidAirdropProof,
+
+    #[msg("Airdrop allocation exhausted")]
+    AirdropExhausted,
+
+    #[msg("No fees to claim")]
+    NoFeesToClaim,
+
+    #[msg("Invalid platform authority")]
+    InvalidAuthority,
+
+    #[msg("No pending authority transfer")]
+    NoPendingAuthorityTransfer,
+
+    #[msg("Invalid governance delay")]
+    InvalidGovernanceDelay,
+
+    #[msg("No pending change scheduled")]
+    NoPendingChange,
+
+    #[msg("Governance delay has not elapsed")]
+    TimelockNotElapsed,
+
+    #[msg("Trading is halted for this token")]
+    TradingHalted,
+
+    #[msg("Trading is not halted for this token")]
+    TradingNotHalted,
+
+    #[msg("This action is paused")]
+    ActionPaused,
+
+    #[msg("Auto-unpause time must be in the future")]
+    InvalidPauseSchedule,
+
+    #[msg("Invalid platform limits")]
+    InvalidPlatformLimits,
+
+    #[msg("Graduation program is still allowlisted")]
+    GraduationProgramStillAllowed,
+
+    #[msg("Liquidity lock not supported by the graduation target")]
+    UnsupportedLiquidityLock,
+
+    #[msg("Holder accounts holding tokens are still frozen")]
+    HoldersStillFrozen,
+
+    #[msg("Holder stats do not belong to the mint")]
+    InvalidHolderStats,
+
+    #[msg("Token has not launched yet")]
+    TokenNotLaunched
+}
@@ -1,15 +1,26 @@
 use crate::constants::*;
 use crate::errors::ErrorCode;
-use crate::states::{BondingCurve, ProgramState, TokenInfo, Transaction, TransactionType};
+use crate::instructions::set_token_account_frozen::{holds_frozen_balance, is_frozen, set_frozen};
+use crate::states::{
+    BondingCurve, CreatorVault, GraduationTarget, LiquidityPool, PlatformFeeVault, ProgramState,
+    TokenInfo, Transaction, TransactionType,
+};
+use crate::utils::{holder_count, transfer_checked_with_hook};
 use anchor_lang::prelude::*;
-use anchor_lang::solana_program::program_pack::Pack;
 use anchor_lang::system_program;
 use anchor_spl::associated_token::AssociatedToken;
+use holder_tracker::cpi::accounts::SyncPositionCtx;
+use holder_tracker::program::HolderTracker;
 use anchor_spl::associated_token::Create;
-use anchor_spl::token_2022::{self, MintTo, Token2022};
+use anchor_spl::token_2022::{self, MintTo, Token2022, TransferChecked};
+use spl_token_2022::extension::StateWithExtensions;
 use spl_token_2022::state::Account as SplToken2022Account;
 
-pub fn buy_token(ctx: Context<BuyTokenCtx>, token_id: u64, sol_amount: u64) -> Result<()> {
+pub fn buy_token<'info>(
+    ctx: Context<'_, '_, 'info, 'info, BuyTokenCtx<'info>>,
+    token_id: u64,
+    sol_amount: u64,
+) -> Result<()> {
     let program_state = &ctx.accounts.program_state;
     let token_info = &mut ctx.accounts.token_info;
     let bonding_curve = &mut ctx.accounts.bonding_curve;
@@ -17,62 +28,135 @@ pub fn buy_token(ctx: Context<BuyTokenCtx>, token_id: u64, sol_amount: u64) -> R
     let buyer = &ctx.accounts.buyer;
 
     // Validation checks
-    if program_state.is_paused {
-        return Err(ErrorCode::TradingNotActive.into());
+    if program_state.active_pause_flags(Clock::get()?.unix_timestamp).buy {
+        return Err(ErrorCode::ActionPaused.into());
     }
 
     if token_info.token_id != token_id {
         return Err(ErrorCode::TokenNotFound.into());
     }
 
-    if token_info.launched_to_dex {
+    // Graduated tokens keep trading only through the built-in pool
+    let use_pool = token_info.launched_to_dex;
+    if use_pool && token_info.graduation_target != GraduationTarget::BuiltinPool {
         return Err(ErrorCode::TokenAlreadyLaunched.into());
     }
 
+    if token_info.trading_halt.is_some() {
+        return Err(ErrorCode::TradingHalted.into());
+    }
+
     if !token_info.trading_active {
         return Err(ErrorCode::TradingNotActive.into());
     }
 
-    if sol_amount < MIN_TOKEN_PURCHASE {
-        return Err(ErrorCode::PurchaseAmountTooSmall.into());
+    if !use_pool && bonding_curve.complete {
+        return Err(ErrorCode::BondingCurveComplete.into());
     }
 
-    if sol_amount > MAX_TOKEN_PURCHASE {
-        return Err(ErrorCode::PurchaseAmountTooLarge.into());
+    if sol_amount < program_state.limits.min_token_purchase {
+        return Err(ErrorCode::PurchaseAmountTooSmall.into());
     }
 
-    // Calculate token output using bonding curve
-    let token_output = bonding_curve.calculate_token_output(sol_amount)?;
-    if token_output == 0 {
-        return Err(ErrorCode::InvalidPurchaseAmount.into());
-    }
-    if token_output > bonding_curve.real_token_reserves {
-        return Err(ErrorCode::InsufficientReserves.into());
+    if sol_amount > program_state.limits.max_token_purchase {
+        return Err(ErrorCode::PurchaseAmountTooLarge.into());
     }
 
     // Calculate fees
-    let platform_fee = sol_amount
+    let mut sol_amount = sol_amount;
+    let mut platform_fee = sol_amount
         .checked_mul(program_state.platform_fee_rate)
         .ok_or(ErrorCode::ArithmeticOverflow)?
         .checked_div(10000)
         .ok_or(ErrorCode::DivisionByZero)?;
 
-    let creator_fee = sol_amount
+    let mut creator_fee = sol_amount
         .checked_mul(100) // 1%
         .ok_or(ErrorCode::ArithmeticOverflow)?
         .checked_div(10000)
         .ok_or(ErrorCode::DivisionByZero)?;
 
-    let net_sol_amount = sol_amount
+    let mut net_sol_amount = sol_amount
         .checked_sub(platform_fee)
         .ok_or(ErrorCode::ArithmeticUnderflow)?
         .checked_sub(creator_fee)
         .ok_or(ErrorCode::ArithmeticUnderflow)?;
 
-    // Transfer SOL from buyer to bonding curve
+    // Cap the final curve buy so it lands exactly on the launch threshold;
+    // the buyer is only charged for the filled portion
+    let remaining_to_threshold = program_state
+        .launch_threshold
+        .saturating_sub(bonding_curve.real_sol_reserves);
+    if !use_pool && remaining_to_threshold == 0 {
+        return Err(ErrorCode::BondingCurveComplete.into());
+    }
+    let mut refunded_sol = 0;
+    if !use_pool && net_sol_amount > remaining_to_threshold {
+        let capped_sol_amount = (remaining_to_threshold as u128)
+            .checked_mul(10000)
+            .ok_or(ErrorCode::ArithmeticOverflow)?
+            .div_ceil(
+                10000u128
+                    .checked_sub(program_state.platform_fee_rate as u128)
+                    .ok_or(ErrorCode::ArithmeticUnderflow)?
+                    .checked_sub(100)
+                    .ok_or(ErrorCode::ArithmeticUnderflow)?,
+            );
+        let capped_sol_amount =
+            u64::try_from(capped_sol_amount).map_err(|_| ErrorCode::ArithmeticOverflow)?;
+
+        creator_fee = capped_sol_amount
+            .checked_mul(100)
+            .ok_or(ErrorCode::ArithmeticOverflow)?
+            .checked_div(10000)
+            .ok_or(ErrorCode::DivisionByZero)?;
+        // Rounding dust from the cap goes to the platform so net SOL is exact
+        platform_fee = capped_sol_amount
+            .checked_sub(remaining_to_threshold)
+            .ok_or(ErrorCode::ArithmeticUnderflow)?
+            .checked_sub(creator_fee)
+            .ok_or(ErrorCode::ArithmeticUnderflow)?;
+        net_sol_amount = remaining_to_threshold;
+        refunded_sol = sol_amount
+            .checked_sub(capped_sol_amount)
+            .ok_or(ErrorCode::ArithmeticUnderflow)?;
+        sol_amount = capped_sol_amount;
+    }
+
+    // Calculate token output using the bonding curve, or the pool once graduated
+    let (token_output, available_tokens) = if use_pool {
+        let liquidity_pool = ctx
+            .accounts
+            .liquidity_pool
+            .as_ref()
+            .ok_or(ErrorCode::InvalidPoolAccount)?;
+        (
+            liquidity_pool.calculate_token_output(net_sol_amount)?,
+            liquidity_pool.token_reserves,
+        )
+    } else {
+        (
+            bonding_curve.calculate_token_output(sol_amount)?,
+            bonding_curve.real_token_reserves,
+        )
+    };
+    if token_output == 0 {
+        return Err(ErrorCode::InvalidPurchaseAmount.into());
+    }
+    if token_output > available_tokens {
+        return Err(ErrorCode::InsufficientReserves.into());
+    }
+
+    // SOL reserves live on the bonding curve, or on the pool once graduated
+    let reserve_account = match ctx.accounts.liquidity_pool.as_ref() {
+        Some(liquidity_pool) if use_pool => liquidity_pool.to_account_info(),
+        _ => bonding_curve.to_account_info(),
+    };
+
+    // Transfer SOL from buyer to reserves
     let transfer_accounts = system_program::Transfer {
         from: ctx.accounts.buyer.to_account_info(),
-        to: bonding_curve.to_account_info(),
+        to: reserve_account,
     };
 
     system_program::transfer(
@@ -87,7 +171,7 @@ pub fn buy_token(ctx: Context<BuyTokenCtx>, token_id: u64, sol_amount: u64) -> R
     if platform_fee > 0 {
         let platform_fee_accounts = system_program::Transfer {
             from: ctx.accounts.buyer.to_account_info(),
-            to: ctx.accounts.program_state.to_account_info(),
+            to: ctx.accounts.platform_fee_vault.to_account_info(),
         };
 
         system_program::transfer(
@@ -99,11 +183,11 @@ pub fn buy_token(ctx: Context<BuyTokenCtx>, token_id: u64, sol_amount: u64) -> R
         )?;
     }
 
-    // Transfer creator fee
+    // Accrue creator fee in the creator vault
     if creator_fee > 0 {
         let creator_fee_accounts = system_program::Transfer {
             from: ctx.accounts.buyer.to_account_info(),
-            to: ctx.accounts.token_creator.to_account_info(),
+            to: ctx.accounts.creator_vault.to_account_info(),
         };
 
         system_program::transfer(
@@ -132,10 +216,6 @@ pub fn buy_token(ctx: Context<BuyTokenCtx>, token_id: u64, sol_amount: u64) -> R
         signer_seeds,
     );
 
-    // Ensure the passed account is the expected ATA (compare &Pubkey to Pubkey via &expected_ata)
-    // Address check removed
-    // if ctx.accounts.buyer_token_account.key() != expected_ata { ... }
-
     // If ATA doesn't exist (account has zero data), create it via CPI to associated token program
     if ctx.accounts.buyer_token_account.data_is_empty() {
         msg!("ATA is empty, creating...");
@@ -160,8 +240,10 @@ pub fn buy_token(ctx: Context<BuyTokenCtx>, token_id: u64, sol_amount: u64) -> R
         // Only validate if present
 
         // Unpack and validate mint + owner fields
-        let ata = SplToken2022Account::unpack(&ctx.accounts.buyer_token_account.try_borrow_data()?)
-            .map_err(|_| ErrorCode::InvalidTokenAccount)?;
+        let ata_data = ctx.accounts.buyer_token_account.try_borrow_data()?;
+        let ata = StateWithExtensions::<SplToken2022Account>::unpack(&ata_data)
+            .map_err(|_| ErrorCode::InvalidTokenAccount)?
+            .base;
         if ata.mint != token_info.mint {
             msg!(
                 "Error: ATA mint mismatch. Expected: {}, Got: {}",
@@ -180,34 +262,163 @@ pub fn buy_token(ctx: Context<BuyTokenCtx>, token_id: u64, sol_amount: u64) -> R
         }
     }
 
-    token_2022::mint_to(mint_ctx, token_output)?;
+    // Transfer-locked holder accounts are only thawed for the duration of a curve trade
+    let transfer_locked = token_info.transfer_locked_until_launch;
+    let was_frozen_holder =
+        transfer_locked && holds_frozen_balance(&ctx.accounts.buyer_token_account)?;
+    if transfer_locked && is_frozen(&ctx.accounts.buyer_token_account)? {
+        set_frozen(
+            ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.buyer_token_account.to_account_info(),
+            ctx.accounts.mint.to_account_info(),
+            bonding_curve.to_account_info(),
+            signer_seeds,
+            false,
+        )?;
+    }
 
-    // Update bonding curve reserves
-    bonding_curve.update_reserves_buy(net_sol_amount, token_output)?;
-    bonding_curve.total_sol_volume = bonding_curve
-        .total_sol_volume
-        .checked_add(sol_amount)
-        .ok_or(ErrorCode::ArithmeticOverflow)?;
-    bonding_curve.total_token_volume = bonding_curve
-        .total_token_volume
-        .checked_add(token_output)
-        .ok_or(ErrorCode::ArithmeticOverflow)?;
-    bonding_curve.last_updated = Clock::get()?.unix_timestamp;
+    if use_pool {
+        let liquidity_pool = ctx
+            .accounts
+            .liquidity_pool
+            .as_mut()
+            .ok_or(ErrorCode::InvalidPoolAccount)?;
+        let pool_token_vault = ctx
+            .accounts
+            .pool_token_vault
+            .as_ref()
+            .ok_or(ErrorCode::InvalidPoolAccount)?;
+        if pool_token_vault.key() != liquidity_pool.token_vault {
+            return Err(ErrorCode::InvalidPoolAccount.into());
+        }
+
+        // Transfer tokens out of the pool vault (liquidity_pool PDA is signer)
+        let pool_seeds = &[LIQUIDITY_POOL_SEED, binding.as_ref(), &[liquidity_pool.bump]];
+        let pool_signer_seeds = &[&pool_seeds[..]];
+
+        let transfer_accounts = TransferChecked {
+            from: pool_token_vault.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            to: ctx.accounts.buyer_token_account.to_account_info(),
+            authority: liquidity_pool.to_account_info(),
+        };
+
+        transfer_checked_with_hook(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                transfer_accounts,
+                pool_signer_seeds,
+            )
+            .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+            token_output,
+            token_info.decimals,
+        )?;
+
+        // Update pool reserves
+        liquidity_pool.update_reserves_buy(net_sol_amount, token_output)?;
+        liquidity_pool.total_sol_volume = liquidity_pool
+            .total_sol_volume
+            .checked_add(sol_amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        liquidity_pool.total_token_volume = liquidity_pool
+            .total_token_volume
+            .checked_add(token_output)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        liquidity_pool.last_updated = Clock::get()?.unix_timestamp;
+    } else {
+        token_2022::mint_to(mint_ctx, token_output)?;
+
+        // Update bonding curve reserves
+        bonding_curve.update_reserves_buy(net_sol_amount, token_output)?;
+        bonding_curve.total_sol_volume = bonding_curve
+            .total_sol_volume
+            .checked_add(sol_amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        bonding_curve.total_token_volume = bonding_curve
+            .total_token_volume
+            .checked_add(token_output)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        bonding_curve.last_updated = Clock::get()?.unix_timestamp;
+
+        // Halt the curve as soon as the launch threshold is reached
+        if bonding_curve.real_sol_reserves >= program_state.launch_threshold {
+            bonding_curve.complete = true;
+            bonding_curve.completed_at = Some(bonding_curve.last_updated);
+            msg!("Bonding curve complete, ready for launch");
+        }
+
+        // Update token info
+        token_info.circulating_supply = token_info
+            .circulating_supply
+            .checked_add(token_output)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        token_info.total_sol_raised = token_info
+            .total_sol_raised
+            .checked_add(sol_amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+    }
+    if transfer_locked && !token_info.launched_to_dex {
+        set_frozen(
+            ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.buyer_token_account.to_account_info(),
+            ctx.accounts.mint.to_account_info(),
+            bonding_curve.to_account_info(),
+            signer_seeds,
+            true,
+        )?;
+    }
+    if transfer_locked {
+        token_info.track_frozen_holder(
+            was_frozen_holder,
+            holds_frozen_balance(&ctx.accounts.buyer_token_account)?,
+        )?;
+    }
+    if token_info.holder_tracking {
+        let holder_stats = ctx
+            .accounts
+            .holder_stats
+            .as_ref()
+            .ok_or(ErrorCode::HolderTrackerAccountsMissing)?;
+
+        // Curve mints bypass the transfer hook, so sync the buyer's position directly
+        if !use_pool {
+            let holder_tracker_program = ctx
+                .accounts
+                .holder_tracker_program
+                .as_ref()
+                .ok_or(ErrorCode::HolderTrackerAccountsMissing)?;
+            let holder_position = ctx
+                .accounts
+                .holder_position
+                .as_ref()
+                .ok_or(ErrorCode::HolderTrackerAccountsMissing)?;
+
+            holder_tracker::cpi::sync_position(CpiContext::new(
+                holder_tracker_program.to_account_info(),
+                SyncPositionCtx {
+                    mint: ctx.accounts.mint.to_account_info(),
+                    token_account: ctx.accounts.buyer_token_account.to_account_info(),
+                    holder_stats: holder_stats.to_account_info(),
+                    position: holder_position.to_account_info(),
+                    payer: ctx.accounts.buyer.to_account_info(),
+                    system_program: ctx.accounts.system_program.to_account_info(),
+                },
+            ))?;
+        }
+
+        token_info.holder_count = holder_count(holder_stats, &ctx.accounts.mint.key())?;
+    }
 
-    // Update token info
-    token_info.circulating_supply = token_info
-        .circulating_supply
-        .checked_add(token_output)
-        .ok_or(ErrorCode::ArithmeticOverflow)?;
-    token_info.total_sol_raised = token_info
-        .total_sol_raised
-        .checked_add(sol_amount)
-        .ok_or(ErrorCode::ArithmeticOverflow)?;
     token_info.transaction_count += 1;
     token_info.creator_fees_collected = token_info
         .creator_fees_collected
         .checked_add(creator_fee)
         .ok_or(ErrorCode::ArithmeticOverflow)?;
+    let program_state = &mut ctx.accounts.program_state;
+    program_state.total_fees_collected = program_state
+        .total_fees_collected
+        .checked_add(platform_fee)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
 
     // Record transaction
     transaction.transaction_id = token_info.transaction_count;
@@ -233,6 +444,9 @@ pub fn buy_token(ctx: Context<BuyTokenCtx>, token_id: u64, sol_amount: u64) -> R
     msg!("Token Output: {}", token_output);
     msg!("Platform Fee: {}", platform_fee);
     msg!("Creator Fee: {}", creator_fee);
+    if refunded_sol > 0 {
+        msg!("Unspent SOL returned: {}", refunded_sol);
+    }
 
     Ok(())
 }
@@ -275,10 +489,20 @@ pub struct BuyTokenCtx<'info> {
     )]
     pub transaction: Account<'info, Transaction>,
 
-    /// CHECK: Mint account for SPL Token-2022.
-    /// We validate this in the handler by comparing `mint.key().to_bytes()` == `token_info.mint.to_bytes()`
-    /// and by using it in the token_2022 CPI.
+    /// Built-in liquidity pool, required once the token has graduated to it
+    #[account(
+        mut,
+        seeds = [LIQUIDITY_POOL_SEED, token_id.to_le_bytes().as_ref()],
+        bump = liquidity_pool.bump
+    )]
+    pub liquidity_pool: Option<Box<Account<'info, LiquidityPool>>>,
+
+    /// CHECK: Pool token vault, validated against `liquidity_pool.token_vault` in the handler.
     #[account(mut)]
+    pub pool_token_vault: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Mint account for SPL Token-2022, must match `token_info.mint`.
+    #[account(mut, address = token_info.mint @ ErrorCode::TokenNotFound)]
     pub mint: AccountInfo<'info>,
 
     /// CHECK: Buyer's associated token account (ATA) for this mint.
@@ -291,17 +515,36 @@ pub struct BuyTokenCtx<'info> {
     #[account(mut)]
     pub buyer_token_account: AccountInfo<'info>,
 
-    /// CHECK: Token creator address for fee distribution.
-    /// Verified at runtime / via constraint with token_info.creator.
+    /// Vault collecting platform fees
+    #[account(
+        mut,
+        seeds = [PLATFORM_FEE_VAULT_SEED],
+        bump = platform_fee_vault.bump
+    )]
+    pub platform_fee_vault: Box<Account<'info, PlatformFeeVault>>,
+
+    /// Vault accruing the token's creator fees
     #[account(
         mut,
-        constraint = token_creator.key().to_bytes() == token_info.creator.to_bytes() @ ErrorCode::InvalidCreator
+        seeds = [CREATOR_VAULT_SEED, token_id.to_le_bytes().as_ref()],
+        bump = creator_vault.bump
     )]
-    pub token_creator: AccountInfo<'info>,
+    pub creator_vault: Box<Account<'info, CreatorVault>>,
 
     #[account(mut)]
     pub buyer: Signer<'info>,
 
+    /// Holder tracker program, required for curve trades of tokens with holder tracking
+    pub holder_tracker_program: Option<Program<'info, HolderTracker>>,
+
+    /// CHECK: Holder stats for the mint, validated by the holder tracker.
+    #[account(mut)]
+    pub holder_stats: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Holder tracker position of the buyer's token account, validated by the holder tracker.
+    #[account(mut)]
+    pub holder_position: Option<UncheckedAccount<'info>>,
+
     pub token_program: Program<'info, Token2022>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
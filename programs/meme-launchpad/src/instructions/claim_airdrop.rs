@@ -0,0 +1,136 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_2022::{Token2022, TransferChecked};
+use anchor_spl::token_interface::{Mint, TokenAccount};
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::states::{Airdrop, AirdropClaim, TokenInfo};
+use crate::utils::transfer_checked_with_hook;
+
+/// Pays a Merkle-proven airdrop claim from the airdrop escrow, which is minted at launch
+pub fn claim_airdrop<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ClaimAirdropCtx<'info>>,
+    token_id: u64,
+    amount: u64,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    let token_info = &mut ctx.accounts.token_info;
+    let airdrop = &mut ctx.accounts.airdrop;
+    let claimant = &ctx.accounts.claimant;
+
+    if amount == 0 || proof.len() > MAX_AIRDROP_PROOF_LEN {
+        return Err(ErrorCode::InvalidAirdropProof.into());
+    }
+    if !airdrop.verify_claim(claimant.key, amount, &proof) {
+        return Err(ErrorCode::InvalidAirdropProof.into());
+    }
+    if amount > airdrop.remaining_amount() {
+        return Err(ErrorCode::AirdropExhausted.into());
+    }
+
+    // Claims open at launch, so airdropped tokens cannot be sold into the bonding curve
+    if !token_info.launched_to_dex {
+        return Err(ErrorCode::TokenNotLaunched.into());
+    }
+
+    let airdrop_token_account = ctx
+        .accounts
+        .airdrop_token_account
+        .as_ref()
+        .ok_or(ErrorCode::AirdropAccountsMissing)?;
+    if Some(airdrop_token_account.key()) != airdrop.token_account {
+        return Err(ErrorCode::InvalidTokenAccount.into());
+    }
+
+    // Transfer from the airdrop escrow (airdrop PDA is signer)
+    let binding = token_id.to_le_bytes();
+    let airdrop_seeds: &[&[u8]] = &[AIRDROP_SEED, binding.as_ref(), &[airdrop.bump]];
+    transfer_checked_with_hook(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: airdrop_token_account.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.claimant_token_account.to_account_info(),
+                authority: airdrop.to_account_info(),
+            },
+            &[airdrop_seeds],
+        )
+        .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+        amount,
+        ctx.accounts.mint.decimals,
+    )?;
+
+    airdrop.claimed_amount = airdrop
+        .claimed_amount
+        .checked_add(amount)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    token_info.circulating_supply = token_info
+        .circulating_supply
+        .checked_add(amount)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    let airdrop_claim = &mut ctx.accounts.airdrop_claim;
+    airdrop_claim.token_id = token_id;
+    airdrop_claim.claimant = claimant.key();
+    airdrop_claim.amount = amount;
+    airdrop_claim.claimed_at = Clock::get()?.unix_timestamp;
+    airdrop_claim.bump = ctx.bumps.airdrop_claim;
+
+    msg!("Airdrop claimed: {} by {}", amount, claimant.key());
+    msg!("Airdrop remaining: {}", airdrop.remaining_amount());
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(token_id: u64)]
+pub struct ClaimAirdropCtx<'info> {
+    #[account(
+        mut,
+        seeds = [TOKEN_INFO_SEED, token_id.to_le_bytes().as_ref()],
+        bump = token_info.bump
+    )]
+    pub token_info: Box<Account<'info, TokenInfo>>,
+
+    #[account(
+        mut,
+        seeds = [AIRDROP_SEED, token_id.to_le_bytes().as_ref()],
+        bump = airdrop.bump
+    )]
+    pub airdrop: Box<Account<'info, Airdrop>>,
+
+    /// One claim per wallet
+    #[account(
+        init,
+        payer = claimant,
+        space = ANCHOR_DISCRIMINATOR_SIZE + AirdropClaim::INIT_SPACE,
+        seeds = [AIRDROP_CLAIM_SEED, token_id.to_le_bytes().as_ref(), claimant.key().as_ref()],
+        bump
+    )]
+    pub airdrop_claim: Box<Account<'info, AirdropClaim>>,
+
+    #[account(mut, address = token_info.mint)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Airdrop escrow token account, created at launch
+    #[account(mut)]
+    pub airdrop_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(mut)]
+    pub claimant: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = claimant,
+        associated_token::mint = mint,
+        associated_token::authority = claimant,
+        associated_token::token_program = token_program
+    )]
+    pub claimant_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::states::{CreatorVault, ProgramState, TokenInfo};
+
+/// Pays the creator every lamport the creator vault holds above its rent reserve
+pub fn claim_creator_fees(ctx: Context<ClaimCreatorFeesCtx>, token_id: u64) -> Result<()> {
+    if ctx
+        .accounts
+        .program_state
+        .active_pause_flags(Clock::get()?.unix_timestamp)
+        .withdraw
+    {
+        return Err(ErrorCode::ActionPaused.into());
+    }
+
+    let creator_vault = &mut ctx.accounts.creator_vault;
+    let vault_info = creator_vault.to_account_info();
+
+    let rent_reserve = Rent::get()?.minimum_balance(vault_info.data_len());
+    let claimable = vault_info.lamports().saturating_sub(rent_reserve);
+    if claimable == 0 {
+        return Err(ErrorCode::NoFeesToClaim.into());
+    }
+
+    // Transfer from the vault (owned by this program)
+    **vault_info.try_borrow_mut_lamports()? = vault_info
+        .lamports()
+        .checked_sub(claimable)
+        .ok_or(ErrorCode::ArithmeticUnderflow)?;
+    **ctx.accounts.creator.to_account_info().try_borrow_mut_lamports()? = ctx
+        .accounts
+        .creator
+        .to_account_info()
+        .lamports()
+        .checked_add(claimable)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    creator_vault.fees_claimed = creator_vault
+        .fees_claimed
+        .checked_add(claimable)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    msg!("Creator fees claimed for token {}: {}", token_id, claimable);
+    msg!(
+        "Fees collected: {}, claimed: {}",
+        ctx.accounts.token_info.creator_fees_collected,
+        creator_vault.fees_claimed
+    );
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(token_id: u64)]
+pub struct ClaimCreatorFeesCtx<'info> {
+    #[account(
+        seeds = [PROGRAM_STATE_SEED],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+        seeds = [TOKEN_INFO_SEED, token_id.to_le_bytes().as_ref()],
+        bump = token_info.bump
+    )]
+    pub token_info: Account<'info, TokenInfo>,
+
+    #[account(
+        mut,
+        seeds = [CREATOR_VAULT_SEED, token_id.to_le_bytes().as_ref()],
+        bump = creator_vault.bump,
+        has_one = creator @ ErrorCode::InvalidCreator
+    )]
+    pub creator_vault: Account<'info, CreatorVault>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+}
@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_2022::{self, Token2022, TransferChecked};
+use anchor_spl::token_interface::{Mint, TokenAccount};
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::states::{LiquidityLock, TokenInfo};
+
+pub fn claim_locked_lp(ctx: Context<ClaimLockedLpCtx>, token_id: u64) -> Result<()> {
+    let token_info = &mut ctx.accounts.token_info;
+
+    if token_info.token_id != token_id {
+        return Err(ErrorCode::TokenNotFound.into());
+    }
+
+    if token_info.lp_token_account.is_none() {
+        return Err(ErrorCode::NoLockedLiquidity.into());
+    }
+
+    // Only time-locked LP can be released; `unlock_at: None` locks it forever.
+    // Whatever the creator chose, the lock lasts at least MIN_LP_LOCK_DURATION past launch
+    let current_time = Clock::get()?.unix_timestamp;
+    let unlock_at = match token_info.liquidity_lock {
+        LiquidityLock::Lock { unlock_at: Some(unlock_at) } => unlock_at,
+        _ => return Err(ErrorCode::LiquidityLocked.into()),
+    };
+    let minimum_unlock_at = token_info
+        .launched_at
+        .ok_or(ErrorCode::LiquidityLocked)?
+        .checked_add(MIN_LP_LOCK_DURATION)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    if current_time < unlock_at.max(minimum_unlock_at) {
+        return Err(ErrorCode::LiquidityLocked.into());
+    }
+
+    let amount = ctx.accounts.escrow_lp_account.amount;
+
+    // Transfer LP tokens from the launch escrow to the creator (escrow PDA is signer)
+    let token_id_bytes = token_id.to_le_bytes();
+    let escrow_seeds: &[&[u8]] = &[
+        LAUNCH_ESCROW_SEED,
+        &token_id_bytes,
+        &[ctx.bumps.launch_escrow],
+    ];
+    let signer_seeds: &[&[&[u8]]] = &[escrow_seeds];
+
+    let transfer_accounts = TransferChecked {
+        from: ctx.accounts.escrow_lp_account.to_account_info(),
+        mint: ctx.accounts.lp_mint.to_account_info(),
+        to: ctx.accounts.creator_lp_account.to_account_info(),
+        authority: ctx.accounts.launch_escrow.to_account_info(),
+    };
+
+    token_2022::transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            transfer_accounts,
+            signer_seeds,
+        ),
+        amount,
+        ctx.accounts.lp_mint.decimals,
+    )?;
+
+    token_info.lp_token_account = None;
+
+    msg!("Locked LP claimed!");
+    msg!("Token ID: {}", token_id);
+    msg!("LP amount: {}", amount);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(token_id: u64)]
+pub struct ClaimLockedLpCtx<'info> {
+    #[account(
+        mut,
+        seeds = [TOKEN_INFO_SEED, token_id.to_le_bytes().as_ref()],
+        bump = token_info.bump
+    )]
+    pub token_info: Box<Account<'info, TokenInfo>>,
+
+    /// Launch escrow holding the locked LP tokens
+    #[account(
+        seeds = [LAUNCH_ESCROW_SEED, token_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub launch_escrow: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        constraint = Some(escrow_lp_account.key()) == token_info.lp_token_account @ ErrorCode::NoLockedLiquidity
+    )]
+    pub escrow_lp_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        constraint = Some(lp_mint.key()) == token_info.lp_mint @ ErrorCode::InvalidAccount
+    )]
+    pub lp_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        payer = creator,
+        associated_token::mint = lp_mint,
+        associated_token::authority = creator,
+        associated_token::token_program = token_program
+    )]
+    pub creator_lp_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = creator.key() == token_info.creator @ ErrorCode::Unauthorized
+    )]
+    pub creator: Signer<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
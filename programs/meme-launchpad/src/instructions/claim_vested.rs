@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_2022::{Token2022, TransferChecked};
+use anchor_spl::token_interface::{Mint, TokenAccount};
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::states::{TokenInfo, VestingEscrow};
+use crate::utils::transfer_checked_with_hook;
+
+/// Transfers the creator's vested, unclaimed allocation out of the vesting escrow.
+/// Claims open at launch, so the allocation cannot be sold into the bonding curve.
+pub fn claim_vested<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ClaimVestedCtx<'info>>,
+    token_id: u64,
+) -> Result<()> {
+    let token_info = &mut ctx.accounts.token_info;
+    let vesting_escrow = &mut ctx.accounts.vesting_escrow;
+
+    if !token_info.launched_to_dex {
+        return Err(ErrorCode::TokenNotLaunched.into());
+    }
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let claimable = vesting_escrow.claimable_amount(current_time)?;
+    if claimable == 0 {
+        return Err(ErrorCode::NothingVested.into());
+    }
+
+    // Transfer from the escrow (vesting_escrow PDA is signer)
+    let binding = token_id.to_le_bytes();
+    let escrow_seeds: &[&[u8]] = &[VESTING_ESCROW_SEED, binding.as_ref(), &[vesting_escrow.bump]];
+
+    transfer_checked_with_hook(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.vesting_token_account.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.creator_token_account.to_account_info(),
+                authority: vesting_escrow.to_account_info(),
+            },
+            &[escrow_seeds],
+        )
+        .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+        claimable,
+        ctx.accounts.mint.decimals,
+    )?;
+
+    vesting_escrow.claimed_amount = vesting_escrow
+        .claimed_amount
+        .checked_add(claimable)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    token_info.circulating_supply = token_info
+        .circulating_supply
+        .checked_add(claimable)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    msg!("Vested tokens claimed: {}", claimable);
+    msg!(
+        "Claimed so far: {} / {}",
+        vesting_escrow.claimed_amount,
+        vesting_escrow.total_amount
+    );
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(token_id: u64)]
+pub struct ClaimVestedCtx<'info> {
+    #[account(
+        mut,
+        seeds = [TOKEN_INFO_SEED, token_id.to_le_bytes().as_ref()],
+        bump = token_info.bump
+    )]
+    pub token_info: Box<Account<'info, TokenInfo>>,
+
+    #[account(
+        mut,
+        seeds = [VESTING_ESCROW_SEED, token_id.to_le_bytes().as_ref()],
+        bump = vesting_escrow.bump,
+        has_one = beneficiary @ ErrorCode::Unauthorized
+    )]
+    pub vesting_escrow: Box<Account<'info, VestingEscrow>>,
+
+    #[account(
+        mut,
+        address = vesting_escrow.token_account @ ErrorCode::InvalidTokenAccount
+    )]
+    pub vesting_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(address = token_info.mint)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut)]
+    pub beneficiary: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = beneficiary,
+        associated_token::mint = mint,
+        associated_token::authority = beneficiary,
+        associated_token::token_program = token_program
+    )]
+    pub creator_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
@@ -1,9 +1,83 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token_2022::{self, InitializeMint2, Token2022};
+use anchor_lang::system_program::{self, CreateAccount};
+use anchor_spl::token_2022::spl_token_2022::extension::ExtensionType;
+use anchor_spl::token_2022::spl_token_2022::state::{AccountState, Mint as SplMint};
+use anchor_spl::associated_token::{self, AssociatedToken, Create};
+use anchor_spl::token_2022::{self, InitializeMint2, MintTo, Token2022};
+use anchor_spl::token_2022_extensions::spl_pod::optional_keys::OptionalNonZeroPubkey;
+use anchor_spl::token_2022_extensions::spl_token_metadata_interface::state::TokenMetadata;
+use anchor_spl::token_2022_extensions::{
+    self, DefaultAccountStateInitialize, MetadataPointerInitialize, TokenMetadataInitialize,
+    TransferFeeInitialize, TransferHookInitialize,
+};
+use holder_tracker::cpi::accounts::SyncPositionCtx;
+use holder_tracker::program::HolderTracker;
 
 use crate::constants::*;
 use crate::errors::ErrorCode;
-use crate::states::{ProgramState, TokenInfo, BondingCurve};
+use crate::instructions::set_token_account_frozen::{is_frozen, set_frozen};
+use crate::states::{
+    Airdrop, BondingCurve, CreatorVault, FreezeAuthorityPolicy, GraduationTarget, LiquidityLock,
+    MintAuthorityOutcome, ProgramState, TokenInfo, VestingEscrow,
+};
+use crate::utils::holder_count;
+
+/// Creator allocation minted into a vesting escrow at creation
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CreatorVesting {
+    /// Share of the initial supply allocated to the creator, in basis points
+    pub allocation_bps: u16,
+
+    /// Seconds after creation before anything can be claimed
+    pub cliff_duration: i64,
+
+    /// Seconds after creation until the full allocation has vested
+    pub vesting_duration: i64,
+}
+
+/// Supply set aside for claimants of a Merkle airdrop
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct AirdropAllocation {
+    /// Share of the initial supply allocated to the airdrop, in basis points
+    pub allocation_bps: u16,
+
+    /// Merkle root over `sha256(claimant || amount_le)` leaves
+    pub merkle_root: [u8; 32],
+}
+
+/// Per-token launch configuration chosen at creation
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct LaunchOptions {
+    /// Where liquidity goes at launch
+    pub graduation_target: GraduationTarget,
+
+    /// AMM program receiving liquidity (this program for the built-in pool)
+    pub graduation_program: Pubkey,
+
+    /// What happens to the LP tokens received at launch
+    pub liquidity_lock: LiquidityLock,
+
+    /// Who holds the mint authority after launch (revoked if `None`)
+    pub mint_authority: Option<MintAuthorityOutcome>,
+
+    /// Who holds the freeze authority (none if `None`)
+    pub freeze_authority: Option<FreezeAuthorityPolicy>,
+
+    /// Token-2022 transfer fee in basis points paid to the creator (disabled if `None`)
+    pub transfer_fee_bps: Option<u16>,
+
+    /// Freeze holder accounts by default so tokens only move through curve trades until launch
+    pub transfer_locked_until_launch: bool,
+
+    /// Route transfers through the holder tracker hook so `holder_count` stays accurate
+    pub holder_tracking: bool,
+
+    /// Creator allocation with cliff and linear vesting (no allocation if `None`)
+    pub creator_vesting: Option<CreatorVesting>,
+
+    /// Merkle airdrop carved out of the supply, claimable via `claim_airdrop` (none if `None`)
+    pub airdrop: Option<AirdropAllocation>,
+}
 
 pub fn create_token(
     ctx: Context<CreateTokenCtx>,
@@ -12,6 +86,7 @@ pub fn create_token(
     uri: String,
     decimals: u8,
     initial_supply: u64,
+    options: LaunchOptions,
 ) -> Result<()> {
     let program_state = &mut ctx.accounts.program_state;
     let token_info = &mut ctx.accounts.token_info;
@@ -35,13 +110,221 @@ pub fn create_token(
         return Err(ErrorCode::InvalidInitialSupply.into());
     }
 
-    if program_state.is_paused {
-        return Err(ErrorCode::TradingNotActive.into());
+    if program_state.active_pause_flags(Clock::get()?.unix_timestamp).create {
+        return Err(ErrorCode::ActionPaused.into());
+    }
+
+    if !program_state
+        .is_graduation_program_allowed(options.graduation_target, &options.graduation_program)
+    {
+        return Err(ErrorCode::GraduationProgramNotAllowed.into());
+    }
+    if !options
+        .graduation_target
+        .supports_liquidity_lock(options.liquidity_lock)
+    {
+        return Err(ErrorCode::UnsupportedLiquidityLock.into());
+    }
+
+    let mint_authority_outcome = options.mint_authority.unwrap_or_default();
+    if let MintAuthorityOutcome::Dao { authority } = mint_authority_outcome {
+        if authority == Pubkey::default() {
+            return Err(ErrorCode::InvalidMintAuthority.into());
+        }
+    }
+
+    let freeze_authority_policy = options.freeze_authority.unwrap_or_default();
+    if options.transfer_locked_until_launch
+        && freeze_authority_policy == FreezeAuthorityPolicy::Compliance
+    {
+        return Err(ErrorCode::InvalidTransferLock.into());
+    }
+    let freeze_authority = match freeze_authority_policy {
+        // Transfer-locked tokens need the bonding curve to freeze and thaw holders
+        FreezeAuthorityPolicy::Disabled if options.transfer_locked_until_launch => {
+            Some(bonding_curve.key())
+        }
+        FreezeAuthorityPolicy::Disabled => None,
+        FreezeAuthorityPolicy::BondingCurveUntilLaunch => Some(bonding_curve.key()),
+        FreezeAuthorityPolicy::Compliance => {
+            if program_state.compliance_authority == Pubkey::default() {
+                return Err(ErrorCode::ComplianceAuthorityNotSet.into());
+            }
+            Some(program_state.compliance_authority)
+        }
+    };
+
+    if let Some(transfer_fee_bps) = options.transfer_fee_bps {
+        if transfer_fee_bps == 0 || transfer_fee_bps > MAX_TRANSFER_FEE_BPS {
+            return Err(ErrorCode::InvalidTransferFee.into());
+        }
+    }
+
+    if let Some(vesting) = &options.creator_vesting {
+        if vesting.allocation_bps == 0
+            || vesting.allocation_bps > MAX_CREATOR_ALLOCATION_BPS
+            || vesting.cliff_duration < 0
+            || vesting.vesting_duration <= 0
+            || vesting.cliff_duration > vesting.vesting_duration
+            || vesting.vesting_duration > MAX_VESTING_DURATION
+        {
+            return Err(ErrorCode::InvalidVestingSchedule.into());
+        }
+    }
+
+    if let Some(airdrop) = &options.airdrop {
+        if airdrop.allocation_bps == 0
+            || airdrop.allocation_bps > MAX_AIRDROP_ALLOCATION_BPS
+            || airdrop.merkle_root == [0u8; 32]
+        {
+            return Err(ErrorCode::InvalidAirdrop.into());
+        }
     }
 
+    let current_time = Clock::get()?.unix_timestamp;
+    if let LiquidityLock::Lock { unlock_at: Some(unlock_at) } = options.liquidity_lock {
+        if unlock_at <= current_time {
+            return Err(ErrorCode::InvalidUnlockTimestamp.into());
+        }
+    }
+
+    // Reserve part of the supply for DEX liquidity at launch
+    let liquidity_token_reserve = initial_supply
+        .checked_mul(LIQUIDITY_RESERVE_BPS)
+        .ok_or(ErrorCode::ArithmeticOverflow)?
+        .checked_div(10000)
+        .ok_or(ErrorCode::DivisionByZero)?;
+    let creator_allocation = match &options.creator_vesting {
+        Some(vesting) => initial_supply
+            .checked_mul(vesting.allocation_bps as u64)
+            .ok_or(ErrorCode::ArithmeticOverflow)?
+            .checked_div(10000)
+            .ok_or(ErrorCode::DivisionByZero)?,
+        None => 0,
+    };
+    let airdrop_allocation = match &options.airdrop {
+        Some(airdrop) => initial_supply
+            .checked_mul(airdrop.allocation_bps as u64)
+            .ok_or(ErrorCode::ArithmeticOverflow)?
+            .checked_div(10000)
+            .ok_or(ErrorCode::DivisionByZero)?,
+        None => 0,
+    };
+    let curve_token_supply = initial_supply
+        .checked_sub(liquidity_token_reserve)
+        .ok_or(ErrorCode::ArithmeticUnderflow)?
+        .checked_sub(creator_allocation)
+        .ok_or(ErrorCode::ArithmeticUnderflow)?
+        .checked_sub(airdrop_allocation)
+        .ok_or(ErrorCode::ArithmeticUnderflow)?;
+
     program_state.token_count += 1;
     let token_id = program_state.token_count;
 
+    // Create the mint account sized for its extensions, pre-funded for the
+    // variable-length TokenMetadata the token program appends afterwards
+    let bonding_curve_key = bonding_curve.key();
+    let mint_key = ctx.accounts.mint.key();
+    let mut mint_extensions = vec![ExtensionType::MetadataPointer];
+    if options.transfer_fee_bps.is_some() {
+        mint_extensions.push(ExtensionType::TransferFeeConfig);
+    }
+    if options.transfer_locked_until_launch {
+        mint_extensions.push(ExtensionType::DefaultAccountState);
+    }
+    if options.holder_tracking {
+        mint_extensions.push(ExtensionType::TransferHook);
+    }
+    let mint_space = ExtensionType::try_calculate_account_len::<SplMint>(&mint_extensions)?;
+    let metadata_space = TokenMetadata {
+        update_authority: OptionalNonZeroPubkey::try_from(Some(bonding_curve_key))?,
+        mint: mint_key,
+        name: name.clone(),
+        symbol: symbol.clone(),
+        uri: uri.clone(),
+        additional_metadata: vec![],
+    }
+    .tlv_size_of()?;
+    let mint_lamports = Rent::get()?.minimum_balance(
+        mint_space
+            .checked_add(metadata_space)
+            .ok_or(ErrorCode::ArithmeticOverflow)?,
+    );
+
+    let mint_seeds: &[&[u8]] = &[MINT_SEED, bonding_curve_key.as_ref(), &[ctx.bumps.mint]];
+    system_program::create_account(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            CreateAccount {
+                from: creator.to_account_info(),
+                to: ctx.accounts.mint.to_account_info(),
+            },
+            &[mint_seeds],
+        ),
+        mint_lamports,
+        mint_space as u64,
+        &ctx.accounts.token_program.key(),
+    )?;
+
+    // Point the mint's metadata at itself (must precede mint initialization)
+    token_2022_extensions::metadata_pointer_initialize(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            MetadataPointerInitialize {
+                token_program_id: ctx.accounts.token_program.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+            },
+        ),
+        Some(bonding_curve_key),
+        Some(mint_key),
+    )?;
+
+    // Fixed-rate transfer fee, withdrawable only by the bonding_curve PDA on the creator's behalf
+    if let Some(transfer_fee_bps) = options.transfer_fee_bps {
+        token_2022_extensions::transfer_fee_initialize(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                TransferFeeInitialize {
+                    token_program_id: ctx.accounts.token_program.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                },
+            ),
+            None,
+            Some(&bonding_curve_key),
+            transfer_fee_bps,
+            u64::MAX,
+        )?;
+    }
+
+    // New holder accounts start frozen until launch
+    if options.transfer_locked_until_launch {
+        token_2022_extensions::default_account_state_initialize(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                DefaultAccountStateInitialize {
+                    token_program_id: ctx.accounts.token_program.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                },
+            ),
+            &AccountState::Frozen,
+        )?;
+    }
+
+    // Every transfer calls the holder tracker; the hook program can never be changed
+    if options.holder_tracking {
+        token_2022_extensions::transfer_hook_initialize(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                TransferHookInitialize {
+                    token_program_id: ctx.accounts.token_program.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                },
+            ),
+            None,
+            Some(holder_tracker::ID),
+        )?;
+    }
+
     // Initialize mint (Token-2022 CPI)
     let cpi_accounts = InitializeMint2 {
         mint: ctx.accounts.mint.to_account_info(),
@@ -53,9 +336,191 @@ pub fn create_token(
         cpi_ctx,
         decimals,
         &bonding_curve.key(),
-        None,
+        freeze_authority.as_ref(),
     )?;
 
+    // Write name/symbol/uri on the mint (bonding_curve PDA is mint and update authority)
+    let binding = token_id.to_le_bytes();
+    let bonding_curve_seeds: &[&[u8]] = &[BONDING_CURVE_SEED, binding.as_ref(), &[ctx.bumps.bonding_curve]];
+    token_2022_extensions::token_metadata_initialize(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TokenMetadataInitialize {
+                program_id: ctx.accounts.token_program.to_account_info(),
+                metadata: ctx.accounts.mint.to_account_info(),
+                update_authority: bonding_curve.to_account_info(),
+                mint_authority: bonding_curve.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+            },
+            &[bonding_curve_seeds],
+        ),
+        name.clone(),
+        symbol.clone(),
+        uri.clone(),
+    )?;
+
+    // Register the hook's extra accounts and holder stats for the new mint
+    if options.holder_tracking {
+        let holder_tracker_program = ctx
+            .accounts
+            .holder_tracker_program
+            .as_ref()
+            .ok_or(ErrorCode::HolderTrackerAccountsMissing)?;
+        let extra_account_meta_list = ctx
+            .accounts
+            .extra_account_meta_list
+            .as_ref()
+            .ok_or(ErrorCode::HolderTrackerAccountsMissing)?;
+        let holder_stats = ctx
+            .accounts
+            .holder_stats
+            .as_ref()
+            .ok_or(ErrorCode::HolderTrackerAccountsMissing)?;
+
+        holder_tracker::cpi::initialize_extra_account_meta_list(CpiContext::new(
+            holder_tracker_program.to_account_info(),
+            holder_tracker::cpi::accounts::InitializeExtraAccountMetaListCtx {
+                payer: creator.to_account_info(),
+                extra_account_meta_list: extra_account_meta_list.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                holder_stats: holder_stats.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+            },
+        ))?;
+
+        // Seed the rent reserve the hook opens positions of new holders from
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: creator.to_account_info(),
+                    to: holder_stats.to_account_info(),
+                },
+            ),
+            HOLDER_POSITION_RESERVE,
+        )?;
+    }
+
+    // Mint the creator allocation into the vesting escrow's token account
+    if let Some(vesting) = &options.creator_vesting {
+        let vesting_escrow = ctx
+            .accounts
+            .vesting_escrow
+            .as_mut()
+            .ok_or(ErrorCode::VestingAccountsMissing)?;
+        let vesting_token_account = ctx
+            .accounts
+            .vesting_token_account
+            .as_ref()
+            .ok_or(ErrorCode::VestingAccountsMissing)?;
+
+        associated_token::create(CpiContext::new(
+            ctx.accounts.associated_token_program.to_account_info(),
+            Create {
+                payer: creator.to_account_info(),
+                associated_token: vesting_token_account.to_account_info(),
+                authority: vesting_escrow.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+            },
+        ))?;
+
+        // Transfer-locked mints create the escrow account frozen
+        if is_frozen(vesting_token_account)? {
+            set_frozen(
+                ctx.accounts.token_program.to_account_info(),
+                vesting_token_account.to_account_info(),
+                ctx.accounts.mint.to_account_info(),
+                bonding_curve.to_account_info(),
+                &[bonding_curve_seeds],
+                false,
+            )?;
+        }
+
+        token_2022::mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                MintTo {
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to: vesting_token_account.to_account_info(),
+                    authority: bonding_curve.to_account_info(),
+                },
+                &[bonding_curve_seeds],
+            ),
+            creator_allocation,
+        )?;
+
+        // Mints bypass the transfer hook, so sync the escrow's position directly
+        if options.holder_tracking {
+            let holder_tracker_program = ctx
+                .accounts
+                .holder_tracker_program
+                .as_ref()
+                .ok_or(ErrorCode::HolderTrackerAccountsMissing)?;
+            let holder_stats = ctx
+                .accounts
+                .holder_stats
+                .as_ref()
+                .ok_or(ErrorCode::HolderTrackerAccountsMissing)?;
+            let vesting_position = ctx
+                .accounts
+                .vesting_position
+                .as_ref()
+                .ok_or(ErrorCode::HolderTrackerAccountsMissing)?;
+
+            holder_tracker::cpi::sync_position(CpiContext::new(
+                holder_tracker_program.to_account_info(),
+                SyncPositionCtx {
+                    mint: ctx.accounts.mint.to_account_info(),
+                    token_account: vesting_token_account.to_account_info(),
+                    holder_stats: holder_stats.to_account_info(),
+                    position: vesting_position.to_account_info(),
+                    payer: creator.to_account_info(),
+                    system_program: ctx.accounts.system_program.to_account_info(),
+                },
+            ))?;
+        }
+
+        vesting_escrow.token_id = token_id;
+        vesting_escrow.mint = mint_key;
+        vesting_escrow.beneficiary = creator.key();
+        vesting_escrow.token_account = vesting_token_account.key();
+        vesting_escrow.total_amount = creator_allocation;
+        vesting_escrow.claimed_amount = 0;
+        vesting_escrow.start_time = current_time;
+        vesting_escrow.cliff_time = current_time
+            .checked_add(vesting.cliff_duration)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        vesting_escrow.end_time = current_time
+            .checked_add(vesting.vesting_duration)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        vesting_escrow.bump = ctx
+            .bumps
+            .vesting_escrow
+            .ok_or(ErrorCode::VestingAccountsMissing)?;
+
+        msg!("Creator allocation vesting: {}", creator_allocation);
+    }
+
+    // Commit the airdrop; the allocation is minted into its escrow at launch
+    if let Some(airdrop_options) = &options.airdrop {
+        let airdrop = ctx
+            .accounts
+            .airdrop
+            .as_mut()
+            .ok_or(ErrorCode::AirdropAccountsMissing)?;
+        airdrop.token_id = token_id;
+        airdrop.mint = mint_key;
+        airdrop.merkle_root = airdrop_options.merkle_root;
+        airdrop.total_amount = airdrop_allocation;
+        airdrop.claimed_amount = 0;
+        airdrop.token_account = None;
+        airdrop.bump = ctx.bumps.airdrop.ok_or(ErrorCode::AirdropAccountsMissing)?;
+
+        msg!("Airdrop allocation: {}", airdrop_allocation);
+    }
+
     // Populate TokenInfo
     token_info.token_id = token_id;
     token_info.mint = ctx.accounts.mint.key();
@@ -68,12 +533,41 @@ pub fn create_token(
     token_info.circulating_supply = 0;
     token_info.launched_to_dex = false;
     token_info.launched_at = None;
+    token_info.liquidity_token_reserve = liquidity_token_reserve;
+    token_info.creator_allocation = creator_allocation;
+    token_info.airdrop_allocation = airdrop_allocation;
+    token_info.graduation_target = options.graduation_target;
+    token_info.graduation_program = options.graduation_program;
+    token_info.liquidity_lock = options.liquidity_lock;
+    token_info.lp_mint = None;
+    token_info.lp_token_amount = 0;
+    token_info.lp_token_account = None;
+    token_info.mint_authority_outcome = mint_authority_outcome;
+    token_info.freeze_authority_policy = freeze_authority_policy;
+    token_info.metadata_locked = false;
+    token_info.transfer_fee_bps = options.transfer_fee_bps;
+    token_info.transfer_locked_until_launch = options.transfer_locked_until_launch;
+    token_info.frozen_holder_accounts = 0;
+    token_info.holder_tracking = options.holder_tracking;
+    token_info.dex_pool = None;
     token_info.total_sol_raised = 0;
-    token_info.holder_count = 0;
+    // The vesting escrow may already be counted as a holder
+    token_info.holder_count = match &ctx.accounts.holder_stats {
+        Some(holder_stats) if options.holder_tracking => holder_count(holder_stats, &mint_key)?,
+        _ => 0,
+    };
     token_info.transaction_count = 0;
-    token_info.created_at = Clock::get()?.unix_timestamp;
+    token_info.created_at = current_time;
     token_info.trading_active = true;
+    token_info.trading_halt = None;
     token_info.creator_fees_collected = 0;
+
+    // Creator fees accrue here until claimed
+    let creator_vault = &mut ctx.accounts.creator_vault;
+    creator_vault.token_id = token_id;
+    creator_vault.creator = creator.key();
+    creator_vault.fees_claimed = 0;
+    creator_vault.bump = ctx.bumps.creator_vault;
     token_info.bump = ctx.bumps.token_info;
 
     // Initialize BondingCurve
@@ -81,13 +575,15 @@ pub fn create_token(
     bonding_curve.virtual_sol_reserves = INITIAL_VIRTUAL_SOL_RESERVES;
     bonding_curve.virtual_token_reserves = INITIAL_VIRTUAL_TOKEN_RESERVES;
     bonding_curve.real_sol_reserves = 0;
-    bonding_curve.real_token_reserves = initial_supply;
+    bonding_curve.real_token_reserves = curve_token_supply;
     bonding_curve.total_sol_volume = 0;
     bonding_curve.total_token_volume = 0;
     bonding_curve.current_price = 0;
     bonding_curve.market_cap = 0;
     bonding_curve.active = true;
-    bonding_curve.last_updated = Clock::get()?.unix_timestamp;
+    bonding_curve.complete = false;
+    bonding_curve.completed_at = None;
+    bonding_curve.last_updated = current_time;
     bonding_curve.bump = ctx.bumps.bonding_curve;
 
     msg!("✅ Token created successfully!");
@@ -98,7 +594,7 @@ pub fn create_token(
 }
 
 #[derive(Accounts)]
-#[instruction(name: String, symbol: String, uri: String, decimals: u8, initial_supply: u64)]
+#[instruction(name: String, symbol: String, uri: String, decimals: u8, initial_supply: u64, options: LaunchOptions)]
 pub struct CreateTokenCtx<'info> {
     #[account(mut)]
     pub program_state: Account<'info, ProgramState>,
@@ -127,22 +623,78 @@ pub struct CreateTokenCtx<'info> {
     )]
     pub bonding_curve: Account<'info, BondingCurve>,
 
-    /// The new Mint (SPL Token-2022 compatible)
-    /// CHECK: This is a Token-2022 mint account, validated via CPI in the instruction.
-    #[account(init,
+    #[account(
+        init,
         payer = creator,
-        space = 82, // Mint size for Token-2022
-        seeds = [b"mint", bonding_curve.key().as_ref()],
-        bump,
-        owner = token_program.key()
+        space = ANCHOR_DISCRIMINATOR_SIZE + CreatorVault::INIT_SPACE,
+        seeds = [
+            CREATOR_VAULT_SEED,
+            (program_state.token_count + 1).to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub creator_vault: Box<Account<'info, CreatorVault>>,
+
+    /// The new Mint (SPL Token-2022 compatible)
+    /// CHECK: Created in the instruction with its extensions, then initialized via Token-2022 CPI.
+    #[account(
+        mut,
+        seeds = [MINT_SEED, bonding_curve.key().as_ref()],
+        bump
     )]
     pub mint: AccountInfo<'info>,
 
     #[account(mut)]
     pub creator: Signer<'info>,
 
+    /// Holder tracker program, required when `options.holder_tracking` is set
+    pub holder_tracker_program: Option<Program<'info, HolderTracker>>,
+
+    /// CHECK: Hook extra account meta list, created and validated by the holder tracker.
+    #[account(mut)]
+    pub extra_account_meta_list: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Holder stats for the mint, created and validated by the holder tracker.
+    #[account(mut)]
+    pub holder_stats: Option<UncheckedAccount<'info>>,
+
+    /// Vesting escrow for the creator allocation, required when `options.creator_vesting` is set
+    #[account(
+        init,
+        payer = creator,
+        space = ANCHOR_DISCRIMINATOR_SIZE + VestingEscrow::INIT_SPACE,
+        seeds = [
+            VESTING_ESCROW_SEED,
+            (program_state.token_count + 1).to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub vesting_escrow: Option<Box<Account<'info, VestingEscrow>>>,
+
+    /// CHECK: Vesting escrow's associated token account, created in the handler.
+    #[account(mut)]
+    pub vesting_token_account: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Holder tracker position of the vesting token account, validated by the holder tracker.
+    #[account(mut)]
+    pub vesting_position: Option<UncheckedAccount<'info>>,
+
+    /// Airdrop commitment, required when `options.airdrop` is set
+    #[account(
+        init,
+        payer = creator,
+        space = ANCHOR_DISCRIMINATOR_SIZE + Airdrop::INIT_SPACE,
+        seeds = [
+            AIRDROP_SEED,
+            (program_state.token_count + 1).to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub airdrop: Option<Box<Account<'info, Airdrop>>>,
+
     /// Token-2022 program
     pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
 }
\ No newline at end of file
@@ -1,11 +1,12 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token_2022::{self, Burn, CloseAccount, Token2022};
+use anchor_spl::token_interface::{Mint, TokenAccount};
 use crate::constants::*;
 use crate::errors::ErrorCode;
-use crate::states::{ProgramState, TokenInfo, BondingCurve};
+use crate::states::{Airdrop, BondingCurve, CreatorVault, TokenInfo, VestingEscrow};
 
 pub fn delete_token(ctx: Context<DeleteTokenCtx>, token_id: u64) -> Result<()> {
     let token_info = &ctx.accounts.token_info;
-    let bonding_curve = &ctx.accounts.bonding_curve;
     let creator = &ctx.accounts.creator;
 
     // Only creator can delete token
@@ -18,16 +19,81 @@ pub fn delete_token(ctx: Context<DeleteTokenCtx>, token_id: u64) -> Result<()> {
         return Err(ErrorCode::TokenAlreadyLaunched.into());
     }
 
-    if token_info.circulating_supply > 0 {
+    // Every minted token other than the unclaimed creator allocation is in someone's hands
+    let escrowed_supply = match (&ctx.accounts.vesting_escrow, &ctx.accounts.vesting_token_account) {
+        (Some(vesting_escrow), Some(vesting_token_account)) if token_info.creator_allocation > 0 => {
+            if vesting_token_account.key() != vesting_escrow.token_account {
+                return Err(ErrorCode::InvalidTokenAccount.into());
+            }
+            vesting_token_account.amount
+        }
+        _ => 0,
+    };
+    let held_supply = ctx
+        .accounts
+        .mint
+        .supply
+        .checked_sub(escrowed_supply)
+        .ok_or(ErrorCode::ArithmeticUnderflow)?;
+    if held_supply > 0 {
         return Err(ErrorCode::TradingNotActive.into());
     }
 
-    // Return any SOL in bonding curve to creator
-    if bonding_curve.real_sol_reserves > 0 {
-        **ctx.accounts.bonding_curve.to_account_info().try_borrow_mut_lamports()? -= bonding_curve.real_sol_reserves;
-        **ctx.accounts.creator.to_account_info().try_borrow_mut_lamports()? += bonding_curve.real_sol_reserves;
+    // Burn the unclaimed creator allocation and close the escrow's token account
+    if token_info.creator_allocation > 0 {
+        let vesting_escrow = ctx
+            .accounts
+            .vesting_escrow
+            .as_ref()
+            .ok_or(ErrorCode::VestingAccountsMissing)?;
+        let vesting_token_account = ctx
+            .accounts
+            .vesting_token_account
+            .as_ref()
+            .ok_or(ErrorCode::VestingAccountsMissing)?;
+        let mint = &ctx.accounts.mint;
+        if vesting_token_account.key() != vesting_escrow.token_account {
+            return Err(ErrorCode::InvalidTokenAccount.into());
+        }
+
+        let binding = token_id.to_le_bytes();
+        let escrow_seeds: &[&[u8]] = &[VESTING_ESCROW_SEED, binding.as_ref(), &[vesting_escrow.bump]];
+
+        if vesting_token_account.amount > 0 {
+            token_2022::burn(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Burn {
+                        mint: mint.to_account_info(),
+                        from: vesting_token_account.to_account_info(),
+                        authority: vesting_escrow.to_account_info(),
+                    },
+                    &[escrow_seeds],
+                ),
+                vesting_token_account.amount,
+            )?;
+        }
+
+        token_2022::close_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            CloseAccount {
+                account: vesting_token_account.to_account_info(),
+                destination: creator.to_account_info(),
+                authority: vesting_escrow.to_account_info(),
+            },
+            &[escrow_seeds],
+        ))?;
+
+        msg!("Unclaimed creator allocation burned: {}", vesting_token_account.amount);
     }
 
+    // Airdrop claims only open at launch, so the commitment is still untouched here
+    if token_info.airdrop_allocation > 0 && ctx.accounts.airdrop.is_none() {
+        return Err(ErrorCode::AirdropAccountsMissing.into());
+    }
+
+    // Token info, bonding curve, creator vault and escrows are closed to the creator,
+    // returning their rent and any remaining lamports (curve dust, unclaimed creator fees)
     msg!("Token {} deleted successfully", token_id);
 
     Ok(())
@@ -42,21 +108,55 @@ pub struct DeleteTokenCtx<'info> {
         seeds = [TOKEN_INFO_SEED, token_id.to_le_bytes().as_ref()],
         bump = token_info.bump
     )]
-    pub token_info: Account<'info, TokenInfo>,
-    
+    pub token_info: Box<Account<'info, TokenInfo>>,
+
     #[account(
         mut,
         close = creator,
         seeds = [BONDING_CURVE_SEED, token_id.to_le_bytes().as_ref()],
         bump = bonding_curve.bump
     )]
-    pub bonding_curve: Account<'info, BondingCurve>,
-    
+    pub bonding_curve: Box<Account<'info, BondingCurve>>,
+
+    #[account(
+        mut,
+        close = creator,
+        seeds = [CREATOR_VAULT_SEED, token_id.to_le_bytes().as_ref()],
+        bump = creator_vault.bump
+    )]
+    pub creator_vault: Box<Account<'info, CreatorVault>>,
+
+    /// Vesting escrow, required when the token has a creator allocation
+    #[account(
+        mut,
+        close = creator,
+        seeds = [VESTING_ESCROW_SEED, token_id.to_le_bytes().as_ref()],
+        bump = vesting_escrow.bump
+    )]
+    pub vesting_escrow: Option<Box<Account<'info, VestingEscrow>>>,
+
+    /// Vesting escrow's token account, required when the token has a creator allocation
+    #[account(mut)]
+    pub vesting_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(mut, address = token_info.mint)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Airdrop commitment, required when the token has an airdrop allocation
+    #[account(
+        mut,
+        close = creator,
+        seeds = [AIRDROP_SEED, token_id.to_le_bytes().as_ref()],
+        bump = airdrop.bump
+    )]
+    pub airdrop: Option<Box<Account<'info, Airdrop>>>,
+
     #[account(
         mut,
         constraint = creator.key() == token_info.creator @ ErrorCode::Unauthorized
     )]
     pub creator: Signer<'info>,
-    
+
+    pub token_program: Program<'info, Token2022>,
     pub system_program: Program<'info, System>,
-}
\ No newline at end of file
+}
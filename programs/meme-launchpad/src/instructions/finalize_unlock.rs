@@ -0,0 +1,123 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022::extension::StateWithExtensions;
+use anchor_spl::token_2022::spl_token_2022::instruction::AuthorityType;
+use anchor_spl::token_2022::spl_token_2022::state::Account as SplAccount;
+use anchor_spl::token_2022::{self, SetAuthority, Token2022};
+use anchor_spl::token_interface::Mint;
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::instructions::set_token_account_frozen::{holds_frozen_balance, is_frozen, set_frozen};
+use crate::states::{BondingCurve, FreezeAuthorityPolicy, ProgramState, TokenInfo};
+
+/// Revokes the bonding curve's freeze authority over a launched transfer-locked token.
+/// Frozen accounts of the mint passed in `remaining_accounts` are thawed first, since
+/// accounts left frozen (including empty ones created before launch) could never be thawed after.
+pub fn finalize_unlock<'info>(
+    ctx: Context<'_, '_, 'info, 'info, FinalizeUnlockCtx<'info>>,
+    token_id: u64,
+) -> Result<()> {
+    let token_info = &mut ctx.accounts.token_info;
+    let bonding_curve = &ctx.accounts.bonding_curve;
+
+    if !token_info.transfer_locked_until_launch || !token_info.launched_to_dex {
+        return Err(ErrorCode::TransferLockActive.into());
+    }
+
+    // Compliance tokens keep their freeze authority for good
+    if token_info.freeze_authority_policy == FreezeAuthorityPolicy::Compliance {
+        return Err(ErrorCode::FreezeAuthorityNotHeld.into());
+    }
+
+    if ctx.accounts.mint.freeze_authority.is_none() {
+        msg!("Freeze authority already revoked for token {}", token_id);
+        return Ok(());
+    }
+
+    let binding = token_id.to_le_bytes();
+    let bonding_curve_seeds = &[BONDING_CURVE_SEED, binding.as_ref(), &[bonding_curve.bump]];
+    let signer_seeds = &[&bonding_curve_seeds[..]];
+
+    let mint_key = ctx.accounts.mint.key();
+    for token_account in ctx.remaining_accounts.iter() {
+        if *token_account.owner != ctx.accounts.token_program.key() {
+            return Err(ErrorCode::InvalidTokenAccount.into());
+        }
+        {
+            let data = token_account.try_borrow_data()?;
+            let account = StateWithExtensions::<SplAccount>::unpack(&data)?;
+            if account.base.mint != mint_key {
+                return Err(ErrorCode::InvalidTokenAccount.into());
+            }
+        }
+        if !is_frozen(token_account)? {
+            continue;
+        }
+
+        let was_frozen_holder = holds_frozen_balance(token_account)?;
+        set_frozen(
+            ctx.accounts.token_program.to_account_info(),
+            token_account.clone(),
+            ctx.accounts.mint.to_account_info(),
+            bonding_curve.to_account_info(),
+            signer_seeds,
+            false,
+        )?;
+        token_info.track_frozen_holder(was_frozen_holder, false)?;
+        msg!("Token account {} thawed", token_account.key());
+    }
+
+    if token_info.frozen_holder_accounts > 0 {
+        return Err(ErrorCode::HoldersStillFrozen.into());
+    }
+
+    token_2022::set_authority(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            SetAuthority {
+                current_authority: bonding_curve.to_account_info(),
+                account_or_mint: ctx.accounts.mint.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        AuthorityType::FreezeAccount,
+        None,
+    )?;
+
+    msg!("Freeze authority revoked for token {}", token_id);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(token_id: u64)]
+pub struct FinalizeUnlockCtx<'info> {
+    #[account(
+        seeds = [PROGRAM_STATE_SEED],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+        mut,
+        seeds = [TOKEN_INFO_SEED, token_id.to_le_bytes().as_ref()],
+        bump = token_info.bump
+    )]
+    pub token_info: Account<'info, TokenInfo>,
+
+    #[account(
+        seeds = [BONDING_CURVE_SEED, token_id.to_le_bytes().as_ref()],
+        bump = bonding_curve.bump
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+
+    #[account(mut, address = token_info.mint)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        constraint = moderator.key() == program_state.moderator @ ErrorCode::Unauthorized
+    )]
+    pub moderator: Signer<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+}
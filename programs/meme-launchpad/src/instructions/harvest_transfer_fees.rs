@@ -0,0 +1,97 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_2022_extensions::{
+    self, HarvestWithheldTokensToMint, WithdrawWithheldTokensFromMint,
+};
+use anchor_spl::token_interface::{Mint, TokenAccount};
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::states::{BondingCurve, TokenInfo};
+
+/// Sweeps withheld transfer fees from the token accounts passed in
+/// `remaining_accounts` into the mint, then withdraws them to the creator
+pub fn harvest_transfer_fees<'info>(
+    ctx: Context<'_, '_, 'info, 'info, HarvestTransferFeesCtx<'info>>,
+    token_id: u64,
+) -> Result<()> {
+    let bonding_curve = &ctx.accounts.bonding_curve;
+
+    if ctx.accounts.token_info.transfer_fee_bps.is_none() {
+        return Err(ErrorCode::TransferFeeNotEnabled.into());
+    }
+
+    // Harvesting into the mint is permissionless at the token program level
+    if !ctx.remaining_accounts.is_empty() {
+        token_2022_extensions::harvest_withheld_tokens_to_mint(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                HarvestWithheldTokensToMint {
+                    token_program_id: ctx.accounts.token_program.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                },
+            ),
+            ctx.remaining_accounts.to_vec(),
+        )?;
+    }
+
+    // Withdraw from the mint (bonding_curve PDA is the withdraw authority)
+    let binding = token_id.to_le_bytes();
+    let bonding_curve_seeds: &[&[u8]] = &[BONDING_CURVE_SEED, binding.as_ref(), &[bonding_curve.bump]];
+    token_2022_extensions::withdraw_withheld_tokens_from_mint(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        WithdrawWithheldTokensFromMint {
+            token_program_id: ctx.accounts.token_program.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            destination: ctx.accounts.creator_token_account.to_account_info(),
+            authority: bonding_curve.to_account_info(),
+        },
+        &[bonding_curve_seeds],
+    ))?;
+
+    msg!("Transfer fees harvested for token {}", token_id);
+    msg!("Source accounts: {}", ctx.remaining_accounts.len());
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(token_id: u64)]
+pub struct HarvestTransferFeesCtx<'info> {
+    #[account(
+        seeds = [TOKEN_INFO_SEED, token_id.to_le_bytes().as_ref()],
+        bump = token_info.bump
+    )]
+    pub token_info: Box<Account<'info, TokenInfo>>,
+
+    #[account(
+        seeds = [BONDING_CURVE_SEED, token_id.to_le_bytes().as_ref()],
+        bump = bonding_curve.bump
+    )]
+    pub bonding_curve: Box<Account<'info, BondingCurve>>,
+
+    #[account(mut, address = token_info.mint)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: Fee recipient, must be the token creator.
+    #[account(address = token_info.creator @ ErrorCode::InvalidCreator)]
+    pub creator: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = creator,
+        associated_token::token_program = token_program
+    )]
+    pub creator_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Anyone may crank the harvest; fees always go to the creator
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
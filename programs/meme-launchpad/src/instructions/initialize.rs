@@ -1,9 +1,18 @@
 use anchor_lang::prelude::*;
-use crate::constants::{ANCHOR_DISCRIMINATOR_SIZE, PLATFORM_FEE_RATE, LAUNCH_THRESHOLD, PROGRAM_STATE_SEED};
+use crate::constants::{
+    ANCHOR_DISCRIMINATOR_SIZE, GOVERNANCE_DELAY, LAUNCHER_REWARD_BPS, LAUNCH_GRACE_PERIOD,
+    PLATFORM_FEE_RATE, PLATFORM_FEE_VAULT_SEED, PROGRAM_STATE_SEED,
+};
 use crate::errors::ErrorCode;
-use crate::states::ProgramState;
+use crate::states::{PauseFlags, PlatformFeeVault, PlatformLimits, ProgramState};
 
-pub fn initialize(ctx: Context<InitializeCtx>) -> Result<()> {
+/// Initialize the platform with a deploy-time launch threshold and limits
+/// (`LAUNCH_THRESHOLD` and `PlatformLimits::default()` for mainnet)
+pub fn initialize(
+    ctx: Context<InitializeCtx>,
+    launch_threshold: u64,
+    limits: PlatformLimits,
+) -> Result<()> {
     let state = &mut ctx.accounts.program_state;
     let deployer = &ctx.accounts.deployer;
 
@@ -12,18 +21,46 @@ pub fn initialize(ctx: Context<InitializeCtx>) -> Result<()> {
         return Err(ErrorCode::AlreadyInitialized.into());
     }
 
+    // Starting configuration must satisfy the same bounds as later governance changes
+    limits.validate()?;
+    if PLATFORM_FEE_RATE > limits.max_platform_fee_rate {
+        return Err(ErrorCode::InvalidFeeRate.into());
+    }
+    if launch_threshold < limits.min_launch_threshold {
+        return Err(ErrorCode::InvalidLaunchThreshold.into());
+    }
+
     // Initialize program state
     state.initialized = true;
     state.token_count = 0;
     state.platform_fee_rate = PLATFORM_FEE_RATE;
-    state.launch_threshold = LAUNCH_THRESHOLD;
+    state.launch_threshold = launch_threshold;
+    state.launcher_reward_bps = LAUNCHER_REWARD_BPS;
+    state.launch_grace_period = LAUNCH_GRACE_PERIOD;
     state.platform_authority = deployer.key();
+    state.pending_platform_authority = None;
+    state.fee_manager = deployer.key(); // Roles start with the deployer until delegated
+    state.pauser = deployer.key();
+    state.moderator = deployer.key();
+    state.treasurer = deployer.key();
     state.platform_treasury = deployer.key(); // Initially set to deployer
+    state.governance_delay = GOVERNANCE_DELAY;
+    state.pending_settings = None;
+    state.pending_treasury = None;
+    state.limits = limits;
+    state.pending_limits = None;
+    state.compliance_authority = Pubkey::default(); // Unset until configured
     state.total_fees_collected = 0;
-    state.is_paused = false;
+    state.total_fees_withdrawn = 0;
+    state.graduation_programs = Vec::new();
+    state.pause_flags = PauseFlags::default();
+    state.pause_reason = 0;
+    state.paused_until = None;
     state.initialized_at = Clock::get()?.unix_timestamp;
     state.bump = ctx.bumps.program_state;
 
+    ctx.accounts.platform_fee_vault.bump = ctx.bumps.platform_fee_vault;
+
     msg!("Meme Token Launchpad initialized successfully");
     msg!("Platform authority: {}", state.platform_authority);
     msg!("Platform fee rate: {} basis points", state.platform_fee_rate);
@@ -43,6 +80,15 @@ pub struct InitializeCtx<'info> {
     )]
     pub program_state: Account<'info, ProgramState>,
     
+    #[account(
+        init,
+        payer = deployer,
+        space = ANCHOR_DISCRIMINATOR_SIZE + PlatformFeeVault::INIT_SPACE,
+        seeds = [PLATFORM_FEE_VAULT_SEED],
+        bump
+    )]
+    pub platform_fee_vault: Account<'info, PlatformFeeVault>,
+    
     #[account(mut)]
     pub deployer: Signer<'info>,
     
@@ -1,20 +1,47 @@
+use crate::adapters::{
+    self, BuiltinPoolAdapter, ConcentratedLiquidityAdapter, ConstantProductAdapter,
+    LiquidityDeposit,
+};
 use crate::constants::*;
 use crate::errors::ErrorCode;
-use crate::states::{BondingCurve, ProgramState, TokenInfo, Transaction, TransactionType};
+use crate::instructions::set_token_account_frozen::{is_frozen, set_frozen};
+use crate::states::{
+    Airdrop, BondingCurve, FreezeAuthorityPolicy, GraduationTarget, LiquidityLock, LiquidityPool,
+    MintAuthorityOutcome, PlatformFeeVault, ProgramState, TokenInfo, Transaction,
+    TransactionType,
+};
+use crate::utils::holder_count;
 use anchor_lang::prelude::*;
-// use anchor_lang::solana_program::{program::invoke_signed, system_instruction};
+use anchor_spl::associated_token::AssociatedToken;
 use anchor_spl::token_2022::spl_token_2022::instruction::AuthorityType;
-use anchor_spl::token_2022::{self, SetAuthority, Token2022};
+use anchor_spl::token_2022::spl_token_2022::state::AccountState;
+use anchor_spl::token_2022_extensions::{self, DefaultAccountStateUpdate};
+use anchor_spl::token_2022::{self, Burn, MintTo, SetAuthority, Token2022};
+use anchor_spl::token_interface::{Mint, TokenAccount};
+use holder_tracker::cpi::accounts::SyncPositionCtx;
+use holder_tracker::program::HolderTracker;
 
-pub fn launch_to_dex(ctx: Context<LaunchToDexCtx>, token_id: u64, next_tx_id: u64) -> Result<()> {
+/// Unwraps an optional account required by the token's graduation target
+fn required_account<'info, T: ToAccountInfo<'info>>(account: &Option<T>) -> Result<AccountInfo<'info>> {
+    account
+        .as_ref()
+        .map(|account| account.to_account_info())
+        .ok_or(ErrorCode::InvalidPoolAccount.into())
+}
+
+pub fn launch_to_dex<'info>(
+    ctx: Context<'_, '_, 'info, 'info, LaunchToDexCtx<'info>>,
+    token_id: u64,
+    next_tx_id: u64,
+) -> Result<()> {
     let program_state = &mut ctx.accounts.program_state;
     let token_info = &mut ctx.accounts.token_info;
     let bonding_curve = &mut ctx.accounts.bonding_curve;
     let transaction = &mut ctx.accounts.transaction;
 
     // Validation checks
-    if program_state.is_paused {
-        return Err(ErrorCode::TradingNotActive.into());
+    if program_state.active_pause_flags(Clock::get()?.unix_timestamp).launch {
+        return Err(ErrorCode::ActionPaused.into());
     }
 
     if token_info.token_id != token_id {
@@ -25,17 +52,36 @@ pub fn launch_to_dex(ctx: Context<LaunchToDexCtx>, token_id: u64, next_tx_id: u6
         return Err(ErrorCode::TokenAlreadyLaunched.into());
     }
 
-    // Check if launch threshold is met
-    if bonding_curve.real_sol_reserves < program_state.launch_threshold {
+    if token_info.trading_halt.is_some() {
+        return Err(ErrorCode::TradingHalted.into());
+    }
+
+    // Check if the curve completed or the launch threshold is met
+    if !bonding_curve.complete && bonding_curve.real_sol_reserves < program_state.launch_threshold {
         return Err(ErrorCode::LaunchThresholdNotMet.into());
     }
 
     // Check minimum trading time has passed
     let current_time = Clock::get()?.unix_timestamp;
-    if current_time - token_info.created_at < MIN_TRADING_TIME {
+    if current_time - token_info.created_at < program_state.limits.min_trading_time {
         return Err(ErrorCode::LaunchCooldownActive.into());
     }
 
+    // Within the grace period only the creator or platform authority may launch;
+    // afterwards anyone can crank the launch and collect the reward
+    let launcher_key = ctx.accounts.launcher.key();
+    let privileged_launcher =
+        launcher_key == token_info.creator || launcher_key == program_state.platform_authority;
+    let completed_at = bonding_curve
+        .completed_at
+        .unwrap_or(bonding_curve.last_updated);
+    let grace_period_ends = completed_at
+        .checked_add(program_state.launch_grace_period)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    if !privileged_launcher && current_time < grace_period_ends {
+        return Err(ErrorCode::LaunchGracePeriodActive.into());
+    }
+
     // Ensure next_tx_id is correct and then advance the counter
     if next_tx_id
         != token_info
@@ -46,15 +92,294 @@ pub fn launch_to_dex(ctx: Context<LaunchToDexCtx>, token_id: u64, next_tx_id: u6
         return Err(ErrorCode::ArithmeticOverflow.into());
     }
 
+    // Calculate amounts
+    let total_reserves = bonding_curve.real_sol_reserves;
+
+    let sol_for_liquidity = total_reserves
+        .checked_mul(program_state.limits.launch_liquidity_bps) // 80% for liquidity by default
+        .ok_or(ErrorCode::ArithmeticOverflow)?
+        .checked_div(10000)
+        .ok_or(ErrorCode::DivisionByZero)?;
+
+    let platform_launch_fee = total_reserves
+        .checked_sub(sol_for_liquidity)
+        .ok_or(ErrorCode::ArithmeticOverflow)?; // remainder is the launch fee
+
+    // Launcher reward comes out of the platform's share
+    let launcher_reward = platform_launch_fee
+        .checked_mul(program_state.launcher_reward_bps)
+        .ok_or(ErrorCode::ArithmeticOverflow)?
+        .checked_div(10000)
+        .ok_or(ErrorCode::DivisionByZero)?;
+    let platform_launch_fee = platform_launch_fee
+        .checked_sub(launcher_reward)
+        .ok_or(ErrorCode::ArithmeticUnderflow)?;
+
+    let tokens_for_liquidity = token_info.liquidity_token_reserve;
+    if sol_for_liquidity == 0 || tokens_for_liquidity == 0 {
+        return Err(ErrorCode::InsufficientReserves.into());
+    }
+
     // PDA seeds for bonding curve (used to sign CPIs / invokes)
+    let token_id_bytes = token_id.to_le_bytes();
     let bonding_curve_seeds: &[&[u8]] = &[
         BONDING_CURVE_SEED,
-        &token_id.to_le_bytes(),
+        &token_id_bytes,
         &[bonding_curve.bump],
     ];
-    let signer_seeds: &[&[&[u8]]] = &[&bonding_curve_seeds[..]];
+    let signer_seeds: &[&[&[u8]]] = &[bonding_curve_seeds];
 
-    // Transfer mint authority from bonding curve (PDA) to creator
+    // Liquidity may only go to the AMM program chosen at creation, if still allowlisted
+    if ctx.accounts.amm_program.key() != token_info.graduation_program {
+        return Err(ErrorCode::InvalidGraduationTarget.into());
+    }
+    if !program_state
+        .is_graduation_program_allowed(token_info.graduation_target, &token_info.graduation_program)
+    {
+        return Err(ErrorCode::GraduationProgramNotAllowed.into());
+    }
+
+    // Transfer-locked tokens become freely transferable at launch: new accounts start
+    // unfrozen, and the liquidity accounts created for this launch are thawed
+    if token_info.transfer_locked_until_launch {
+        token_2022_extensions::default_account_state_update(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                DefaultAccountStateUpdate {
+                    token_program_id: ctx.accounts.token_program.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    freeze_authority: bonding_curve.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            &AccountState::Initialized,
+        )?;
+
+        let liquidity_accounts = [
+            ctx.accounts.escrow_token_account.as_ref().map(|account| account.to_account_info()),
+            ctx.accounts.pool_token_vault.as_ref().map(|account| account.to_account_info()),
+            ctx.accounts.airdrop_token_account.as_ref().map(|account| account.to_account_info()),
+        ];
+        for account in liquidity_accounts.into_iter().flatten() {
+            if is_frozen(&account)? {
+                set_frozen(
+                    ctx.accounts.token_program.to_account_info(),
+                    account,
+                    ctx.accounts.mint.to_account_info(),
+                    bonding_curve.to_account_info(),
+                    signer_seeds,
+                    false,
+                )?;
+            }
+        }
+    }
+
+    // Launch mints bypass the transfer hook, so their destinations are synced with the holder tracker
+    let holder_tracking = if token_info.holder_tracking {
+        let holder_tracker_program = ctx
+            .accounts
+            .holder_tracker_program
+            .as_ref()
+            .ok_or(ErrorCode::HolderTrackerAccountsMissing)?;
+        let holder_stats = ctx
+            .accounts
+            .holder_stats
+            .as_ref()
+            .ok_or(ErrorCode::HolderTrackerAccountsMissing)?;
+        let liquidity_position = ctx
+            .accounts
+            .liquidity_position
+            .as_ref()
+            .ok_or(ErrorCode::HolderTrackerAccountsMissing)?;
+        Some((
+            holder_tracker_program.to_account_info(),
+            holder_stats.to_account_info(),
+            liquidity_position.to_account_info(),
+        ))
+    } else {
+        None
+    };
+
+    let deposit = LiquidityDeposit {
+        token_id,
+        sol_amount: sol_for_liquidity,
+        token_amount: tokens_for_liquidity,
+        mint: ctx.accounts.mint.to_account_info(),
+        bonding_curve: bonding_curve.to_account_info(),
+        bonding_curve_bump: bonding_curve.bump,
+        payer: ctx.accounts.launcher.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+        associated_token_program: ctx.accounts.associated_token_program.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+        holder_tracking: holder_tracking.clone(),
+    };
+
+    // Route liquidity through the adapter for the token's graduation target
+    let seeded_pool = match token_info.graduation_target {
+        GraduationTarget::BuiltinPool => {
+            let pool_token_vault = ctx
+                .accounts
+                .pool_token_vault
+                .as_ref()
+                .ok_or(ErrorCode::InvalidPoolAccount)?;
+            let liquidity_pool = ctx
+                .accounts
+                .liquidity_pool
+                .as_mut()
+                .ok_or(ErrorCode::InvalidPoolAccount)?;
+
+            let mut adapter = BuiltinPoolAdapter {
+                liquidity_pool,
+                pool_token_vault: pool_token_vault.to_account_info(),
+                pool_bump: ctx
+                    .bumps
+                    .liquidity_pool
+                    .ok_or(ErrorCode::InvalidPoolAccount)?,
+            };
+            adapters::seed_liquidity(&mut adapter, &deposit)?
+        }
+        GraduationTarget::ConstantProductAmm => {
+            let mut adapter = ConstantProductAdapter {
+                launch_escrow: required_account(&ctx.accounts.launch_escrow)?,
+                escrow_bump: ctx
+                    .bumps
+                    .launch_escrow
+                    .ok_or(ErrorCode::InvalidPoolAccount)?,
+                escrow_token_account: ctx
+                    .accounts
+                    .escrow_token_account
+                    .as_ref()
+                    .ok_or(ErrorCode::InvalidPoolAccount)?
+                    .to_account_info(),
+                escrow_lp_account: required_account(&ctx.accounts.escrow_lp_account)?,
+                amm_pool: required_account(&ctx.accounts.amm_pool)?,
+                amm_lp_mint: required_account(&ctx.accounts.amm_lp_mint)?,
+                amm_token_vault: required_account(&ctx.accounts.amm_token_vault)?,
+                amm_program: ctx.accounts.amm_program.to_account_info(),
+                hook_accounts: ctx.remaining_accounts,
+            };
+            adapters::seed_liquidity(&mut adapter, &deposit)?
+        }
+        GraduationTarget::ConcentratedLiquidity => {
+            let mut adapter = ConcentratedLiquidityAdapter {
+                launch_escrow: required_account(&ctx.accounts.launch_escrow)?,
+                escrow_bump: ctx
+                    .bumps
+                    .launch_escrow
+                    .ok_or(ErrorCode::InvalidPoolAccount)?,
+                escrow_token_account: ctx
+                    .accounts
+                    .escrow_token_account
+                    .as_ref()
+                    .ok_or(ErrorCode::InvalidPoolAccount)?
+                    .to_account_info(),
+                amm_program: ctx.accounts.amm_program.to_account_info(),
+                pool_accounts: ctx.remaining_accounts,
+            };
+            adapters::seed_liquidity(&mut adapter, &deposit)?
+        }
+    };
+
+    let dex_pool = seeded_pool.pool;
+
+    // Burn or lock the LP tokens the pool minted to the launch escrow
+    if let Some(lp_token_account) = seeded_pool.lp_token_account {
+        let lp_account = TokenAccount::try_deserialize(&mut &lp_token_account.try_borrow_data()?[..])?;
+        token_info.lp_mint = Some(lp_account.mint);
+        token_info.lp_token_amount = lp_account.amount;
+
+        match token_info.liquidity_lock {
+            LiquidityLock::Burn => {
+                let escrow_bump = ctx
+                    .bumps
+                    .launch_escrow
+                    .ok_or(ErrorCode::InvalidPoolAccount)?;
+                let escrow_seeds: &[&[u8]] = &[LAUNCH_ESCROW_SEED, &token_id_bytes, &[escrow_bump]];
+                let escrow_signer_seeds: &[&[&[u8]]] = &[escrow_seeds];
+
+                let burn_accounts = Burn {
+                    mint: required_account(&ctx.accounts.amm_lp_mint)?,
+                    from: lp_token_account.clone(),
+                    authority: required_account(&ctx.accounts.launch_escrow)?,
+                };
+
+                token_2022::burn(
+                    CpiContext::new_with_signer(
+                        ctx.accounts.token_program.to_account_info(),
+                        burn_accounts,
+                        escrow_signer_seeds,
+                    ),
+                    lp_account.amount,
+                )?;
+
+                token_info.lp_token_account = None;
+                msg!("LP tokens burned: {}", lp_account.amount);
+            }
+            LiquidityLock::Lock { unlock_at } => {
+                token_info.lp_token_account = Some(lp_token_account.key());
+                msg!("LP tokens locked: {} (unlock at: {:?})", lp_account.amount, unlock_at);
+            }
+        }
+    }
+
+    // Mint the unclaimed airdrop into its escrow while the bonding curve can still mint
+    if token_info.airdrop_allocation > 0 {
+        let airdrop = ctx
+            .accounts
+            .airdrop
+            .as_mut()
+            .ok_or(ErrorCode::AirdropAccountsMissing)?;
+        let airdrop_token_account = ctx
+            .accounts
+            .airdrop_token_account
+            .as_ref()
+            .ok_or(ErrorCode::AirdropAccountsMissing)?;
+
+        let unclaimed_airdrop = airdrop.remaining_amount();
+        if unclaimed_airdrop > 0 {
+            token_2022::mint_to(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    MintTo {
+                        mint: ctx.accounts.mint.to_account_info(),
+                        to: airdrop_token_account.to_account_info(),
+                        authority: bonding_curve.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                unclaimed_airdrop,
+            )?;
+
+            if let Some((holder_tracker_program, holder_stats, _)) = &holder_tracking {
+                let airdrop_position = ctx
+                    .accounts
+                    .airdrop_position
+                    .as_ref()
+                    .ok_or(ErrorCode::HolderTrackerAccountsMissing)?;
+
+                holder_tracker::cpi::sync_position(CpiContext::new(
+                    holder_tracker_program.clone(),
+                    SyncPositionCtx {
+                        mint: ctx.accounts.mint.to_account_info(),
+                        token_account: airdrop_token_account.to_account_info(),
+                        holder_stats: holder_stats.clone(),
+                        position: airdrop_position.to_account_info(),
+                        payer: ctx.accounts.launcher.to_account_info(),
+                        system_program: ctx.accounts.system_program.to_account_info(),
+                    },
+                ))?;
+            }
+        }
+        airdrop.token_account = Some(airdrop_token_account.key());
+        msg!("Unclaimed airdrop escrowed: {}", unclaimed_airdrop);
+    }
+
+    // Hand off or revoke the bonding curve's mint authority as chosen at creation
+    let new_mint_authority = match token_info.mint_authority_outcome {
+        MintAuthorityOutcome::Revoke => None,
+        MintAuthorityOutcome::Creator => Some(token_info.creator),
+        MintAuthorityOutcome::Dao { authority } => Some(authority),
+    };
     let set_authority_accounts = SetAuthority {
         current_authority: bonding_curve.to_account_info(),
         account_or_mint: ctx.accounts.mint.to_account_info(),
@@ -70,61 +395,79 @@ pub fn launch_to_dex(ctx: Context<LaunchToDexCtx>, token_id: u64, next_tx_id: u6
     token_2022::set_authority(
         set_authority_ctx,
         AuthorityType::MintTokens,
-        Some(token_info.creator),
+        new_mint_authority,
     )?;
 
-    // Calculate amounts
-    let total_reserves = bonding_curve.real_sol_reserves;
-
-    let sol_for_liquidity = total_reserves
-        .checked_mul(8000) // 80% for liquidity
-        .ok_or(ErrorCode::ArithmeticOverflow)?
-        .checked_div(10000)
-        .ok_or(ErrorCode::DivisionByZero)?;
-
-    let platform_launch_fee = total_reserves
-        .checked_sub(sol_for_liquidity)
-        .ok_or(ErrorCode::ArithmeticOverflow)?; // remaining 20%
-
-    // Perform lamport transfers using system_instruction + invoke_signed
-    // Transfer liquidity SOL to creator (from bonding_curve PDA)
-    **bonding_curve.to_account_info().try_borrow_mut_lamports()? = bonding_curve
-        .to_account_info()
-        .lamports()
-        .checked_sub(sol_for_liquidity)
-        .ok_or(ErrorCode::ArithmeticUnderflow)?;
-    **ctx
-        .accounts
-        .token_creator
-        .to_account_info()
-        .try_borrow_mut_lamports()? = ctx
-        .accounts
-        .token_creator
-        .to_account_info()
-        .lamports()
-        .checked_add(sol_for_liquidity)
-        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    // The bonding curve only holds the freeze authority until launch, unless
+    // transfer-locked holders still need thawing (then `finalize_unlock` revokes it)
+    if token_info.freeze_authority_policy == FreezeAuthorityPolicy::BondingCurveUntilLaunch
+        && !token_info.transfer_locked_until_launch
+    {
+        token_2022::set_authority(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                SetAuthority {
+                    current_authority: bonding_curve.to_account_info(),
+                    account_or_mint: ctx.accounts.mint.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            AuthorityType::FreezeAccount,
+            None,
+        )?;
+        msg!("Freeze authority revoked");
+    }
 
-    // Transfer platform fee to program_state (from bonding_curve PDA)
+    // Transfer platform fee to the platform fee vault (from bonding_curve PDA)
     **bonding_curve.to_account_info().try_borrow_mut_lamports()? = bonding_curve
         .to_account_info()
         .lamports()
         .checked_sub(platform_launch_fee)
         .ok_or(ErrorCode::ArithmeticUnderflow)?;
-    **program_state.to_account_info().try_borrow_mut_lamports()? = program_state
+    **ctx.accounts.platform_fee_vault.to_account_info().try_borrow_mut_lamports()? = ctx
+        .accounts
+        .platform_fee_vault
         .to_account_info()
         .lamports()
         .checked_add(platform_launch_fee)
         .ok_or(ErrorCode::ArithmeticOverflow)?;
 
+    // Pay the launcher reward (from bonding_curve PDA)
+    if launcher_reward > 0 {
+        **bonding_curve.to_account_info().try_borrow_mut_lamports()? = bonding_curve
+            .to_account_info()
+            .lamports()
+            .checked_sub(launcher_reward)
+            .ok_or(ErrorCode::ArithmeticUnderflow)?;
+        **ctx.accounts.launcher.to_account_info().try_borrow_mut_lamports()? = ctx
+            .accounts
+            .launcher
+            .to_account_info()
+            .lamports()
+            .checked_add(launcher_reward)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+    }
+
     // Update token info
     token_info.launched_to_dex = true;
     token_info.launched_at = Some(current_time);
-    token_info.trading_active = false; // Disable bonding curve trading
+    token_info.dex_pool = Some(dex_pool);
+    if let Some((_, holder_stats, _)) = &holder_tracking {
+        token_info.holder_count = holder_count(holder_stats, &ctx.accounts.mint.key())?;
+    }
+    // Bonding curve trading stops; only the built-in pool keeps trading here
+    token_info.trading_active = token_info.graduation_target == GraduationTarget::BuiltinPool;
     token_info.transaction_count = next_tx_id;
+    token_info.circulating_supply = token_info
+        .circulating_supply
+        .checked_add(tokens_for_liquidity)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
 
     // Deactivate bonding curve
+    bonding_curve.real_sol_reserves = 0;
     bonding_curve.active = false;
+    bonding_curve.complete = true;
+    bonding_curve.completed_at.get_or_insert(current_time);
     bonding_curve.last_updated = current_time;
 
     // Update program state fees
@@ -136,10 +479,10 @@ pub fn launch_to_dex(ctx: Context<LaunchToDexCtx>, token_id: u64, next_tx_id: u6
     // Record launch transaction
     transaction.transaction_id = next_tx_id;
     transaction.token_id = token_id;
-    transaction.user = ctx.accounts.launcher.key();
+    transaction.user = launcher_key;
     transaction.transaction_type = TransactionType::Launch;
     transaction.sol_amount = total_reserves;
-    transaction.token_amount = 0; // No tokens involved in launch
+    transaction.token_amount = tokens_for_liquidity;
     transaction.price = 0;
     transaction.platform_fee = platform_launch_fee;
     transaction.creator_fee = 0;
@@ -149,12 +492,20 @@ pub fn launch_to_dex(ctx: Context<LaunchToDexCtx>, token_id: u64, next_tx_id: u6
 
     msg!("Token successfully launched to DEX!");
     msg!("Token ID: {}", token_id);
+    msg!("Pool: {}", dex_pool);
     msg!("SOL for liquidity: {}", sol_for_liquidity);
+    msg!("Tokens for liquidity: {}", tokens_for_liquidity);
     msg!("Platform launch fee: {}", platform_launch_fee);
-    msg!(
-        "Mint authority transferred to creator: {}",
-        token_info.creator
-    );
+    msg!("Launcher reward: {}", launcher_reward);
+    match token_info.mint_authority_outcome {
+        MintAuthorityOutcome::Revoke => msg!("Mint authority revoked"),
+        MintAuthorityOutcome::Creator => {
+            msg!("Mint authority transferred to creator: {}", token_info.creator)
+        }
+        MintAuthorityOutcome::Dao { authority } => {
+            msg!("Mint authority transferred to DAO: {}", authority)
+        }
+    }
 
     Ok(())
 }
@@ -167,21 +518,21 @@ pub struct LaunchToDexCtx<'info> {
         seeds = [PROGRAM_STATE_SEED],
         bump = program_state.bump
     )]
-    pub program_state: Account<'info, ProgramState>,
+    pub program_state: Box<Account<'info, ProgramState>>,
 
     #[account(
         mut,
         seeds = [TOKEN_INFO_SEED, token_id.to_le_bytes().as_ref()],
         bump = token_info.bump
     )]
-    pub token_info: Account<'info, TokenInfo>,
+    pub token_info: Box<Account<'info, TokenInfo>>,
 
     #[account(
         mut,
         seeds = [BONDING_CURVE_SEED, token_id.to_le_bytes().as_ref()],
         bump = bonding_curve.bump
     )]
-    pub bonding_curve: Account<'info, BondingCurve>,
+    pub bonding_curve: Box<Account<'info, BondingCurve>>,
 
     #[account(
         init,
@@ -195,22 +546,119 @@ pub struct LaunchToDexCtx<'info> {
         ],
         bump
     )]
-    pub transaction: Account<'info, Transaction>,
+    pub transaction: Box<Account<'info, Transaction>>,
+
+    /// Vault collecting platform fees
+    #[account(
+        mut,
+        seeds = [PLATFORM_FEE_VAULT_SEED],
+        bump = platform_fee_vault.bump
+    )]
+    pub platform_fee_vault: Box<Account<'info, PlatformFeeVault>>,
+
+    #[account(
+        mut,
+        address = token_info.mint @ ErrorCode::TokenNotFound
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Program-controlled escrow that deposits liquidity into external AMMs
+    #[account(
+        mut,
+        seeds = [LAUNCH_ESCROW_SEED, token_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub launch_escrow: Option<SystemAccount<'info>>,
+
+    #[account(
+        init_if_needed,
+        payer = launcher,
+        associated_token::mint = mint,
+        associated_token::authority = launch_escrow,
+        associated_token::token_program = token_program
+    )]
+    pub escrow_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// CHECK: Escrow LP token account, created and validated by the AMM program
+    #[account(mut)]
+    pub escrow_lp_account: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: AMM pool PDA, initialized and seed-checked by the AMM program
+    #[account(mut)]
+    pub amm_pool: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: AMM LP mint PDA, initialized and seed-checked by the AMM program
+    #[account(mut)]
+    pub amm_lp_mint: Option<UncheckedAccount<'info>>,
 
-    /// CHECK: Mint account that will be verified through CPI calls
+    /// CHECK: AMM token vault PDA, initialized and seed-checked by the AMM program
     #[account(mut)]
-    pub mint: AccountInfo<'info>,
+    pub amm_token_vault: Option<UncheckedAccount<'info>>,
+
+    /// Built-in liquidity pool that keeps the token trading in this program
+    #[account(
+        init,
+        payer = launcher,
+        space = ANCHOR_DISCRIMINATOR_SIZE + LiquidityPool::INIT_SPACE,
+        seeds = [LIQUIDITY_POOL_SEED, token_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub liquidity_pool: Option<Box<Account<'info, LiquidityPool>>>,
+
+    #[account(
+        init,
+        payer = launcher,
+        seeds = [POOL_VAULT_SEED, token_id.to_le_bytes().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = liquidity_pool,
+        token::token_program = token_program
+    )]
+    pub pool_token_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
 
-    /// CHECK: Token creator address for receiving liquidity SOL
+    /// Airdrop commitment, required for tokens with an airdrop allocation
     #[account(
         mut,
-        address = token_info.creator
+        seeds = [AIRDROP_SEED, token_id.to_le_bytes().as_ref()],
+        bump = airdrop.bump
+    )]
+    pub airdrop: Option<Box<Account<'info, Airdrop>>>,
+
+    /// Escrow for the unclaimed airdrop, paid out by `claim_airdrop` after launch
+    #[account(
+        init_if_needed,
+        payer = launcher,
+        associated_token::mint = mint,
+        associated_token::authority = airdrop,
+        associated_token::token_program = token_program
     )]
-    pub token_creator: AccountInfo<'info>,
+    pub airdrop_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// Holder tracker program, required for tokens with holder tracking
+    pub holder_tracker_program: Option<Program<'info, HolderTracker>>,
+
+    /// CHECK: Holder stats for the mint, validated by the holder tracker.
+    #[account(mut)]
+    pub holder_stats: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Holder tracker position of the token account liquidity is minted into
+    /// (pool vault or escrow token account), validated by the holder tracker.
+    #[account(mut)]
+    pub liquidity_position: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Holder tracker position of the airdrop token account, validated by the holder tracker.
+    #[account(mut)]
+    pub airdrop_position: Option<UncheckedAccount<'info>>,
 
     #[account(mut)]
     pub launcher: Signer<'info>,
 
+    /// CHECK: AMM program receiving liquidity, checked against `token_info.graduation_program`
+    /// and the `ProgramState` allowlist in the handler. This program for the built-in pool.
+    #[account(executable)]
+    pub amm_program: UncheckedAccount<'info>,
+
     pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
@@ -4,10 +4,42 @@ pub mod buy_token;
 pub mod sell_token;
 pub mod launch_to_dex;
 pub mod update_platform_settings;
+pub mod update_graduation_programs;
+pub mod claim_locked_lp;
+pub mod update_launch_crank;
+pub mod set_token_account_frozen;
+pub mod update_token_metadata;
+pub mod harvest_transfer_fees;
+pub mod thaw_token_account;
+pub mod finalize_unlock;
+pub mod refresh_holder_count;
+pub mod claim_vested;
+pub mod claim_airdrop;
+pub mod claim_creator_fees;
+pub mod withdraw_fees;
+pub mod delete_token;
+pub mod update_platform_role;
+pub mod moderate_token;
 
 pub use initialize::*;
 pub use create_token::*;
 pub use buy_token::*;
 pub use sell_token::*;
 pub use launch_to_dex::*;
-pub use update_platform_settings::*;
\ No newline at end of file
+pub use update_platform_settings::*;
+pub use update_graduation_programs::*;
+pub use claim_locked_lp::*;
+pub use update_launch_crank::*;
+pub use set_token_account_frozen::*;
+pub use update_token_metadata::*;
+pub use harvest_transfer_fees::*;
+pub use thaw_token_account::*;
+pub use finalize_unlock::*;
+pub use refresh_holder_count::*;
+pub use claim_vested::*;
+pub use claim_airdrop::*;
+pub use claim_creator_fees::*;
+pub use withdraw_fees::*;
+pub use delete_token::*;
+pub use update_platform_role::*;
+pub use moderate_token::*;
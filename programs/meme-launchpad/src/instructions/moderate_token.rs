@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+use crate::constants::{PROGRAM_STATE_SEED, TOKEN_INFO_SEED};
+use crate::errors::ErrorCode;
+use crate::states::{GraduationTarget, ProgramState, TokenInfo, TradingHalt};
+
+/// Halts trading on a single token; buys and launch are refused, sells only if `allow_sells`
+pub fn halt_token_trading(
+    ctx: Context<ModerateTokenCtx>,
+    token_id: u64,
+    reason_code: u16,
+    allow_sells: bool,
+) -> Result<()> {
+    let token_info = &mut ctx.accounts.token_info;
+
+    if token_info.trading_halt.is_some() {
+        return Err(ErrorCode::TradingHalted.into());
+    }
+
+    // Tokens graduated to an external AMM no longer trade here
+    if !token_info.trading_active {
+        return Err(ErrorCode::TradingNotActive.into());
+    }
+
+    let halted_at = Clock::get()?.unix_timestamp;
+    token_info.trading_active = false;
+    token_info.trading_halt = Some(TradingHalt {
+        reason_code,
+        halted_at,
+        allow_sells,
+    });
+
+    msg!("Trading halted for token {}", token_id);
+    msg!("Reason code: {}, sells allowed: {}", reason_code, allow_sells);
+
+    Ok(())
+}
+
+/// Lifts a moderation halt and restores trading
+pub fn resume_token_trading(ctx: Context<ModerateTokenCtx>, token_id: u64) -> Result<()> {
+    let token_info = &mut ctx.accounts.token_info;
+
+    let halt = token_info
+        .trading_halt
+        .take()
+        .ok_or(ErrorCode::TradingNotHalted)?;
+
+    // Only tokens still on the curve or in the built-in pool could have been halted
+    token_info.trading_active = !token_info.launched_to_dex
+        || token_info.graduation_target == GraduationTarget::BuiltinPool;
+
+    msg!("Trading resumed for token {}", token_id);
+    msg!("Halt reason code: {}, halted at: {}", halt.reason_code, halt.halted_at);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(token_id: u64)]
+pub struct ModerateTokenCtx<'info> {
+    #[account(
+        seeds = [PROGRAM_STATE_SEED],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+        mut,
+        seeds = [TOKEN_INFO_SEED, token_id.to_le_bytes().as_ref()],
+        bump = token_info.bump
+    )]
+    pub token_info: Box<Account<'info, TokenInfo>>,
+
+    #[account(
+        constraint = moderator.key() == program_state.moderator @ ErrorCode::Unauthorized
+    )]
+    pub moderator: Signer<'info>,
+}
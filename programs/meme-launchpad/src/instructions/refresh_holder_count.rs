@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+use holder_tracker::constants::HOLDER_STATS_SEED;
+use holder_tracker::states::HolderStats;
+
+use crate::constants::*;
+use crate::states::TokenInfo;
+
+/// Permissionlessly copies the holder tracker's count into `TokenInfo.holder_count`,
+/// which only refreshes on launchpad instructions while transfers happen elsewhere
+pub fn refresh_holder_count(ctx: Context<RefreshHolderCountCtx>, token_id: u64) -> Result<()> {
+    let token_info = &mut ctx.accounts.token_info;
+    token_info.holder_count = ctx.accounts.holder_stats.holder_count;
+
+    msg!("Holder count for token {}: {}", token_id, token_info.holder_count);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(token_id: u64)]
+pub struct RefreshHolderCountCtx<'info> {
+    #[account(
+        mut,
+        seeds = [TOKEN_INFO_SEED, token_id.to_le_bytes().as_ref()],
+        bump = token_info.bump
+    )]
+    pub token_info: Account<'info, TokenInfo>,
+
+    /// Holder stats kept by the holder tracker, only created for tokens with holder tracking
+    #[account(
+        seeds = [HOLDER_STATS_SEED, token_info.mint.as_ref()],
+        seeds::program = holder_tracker::ID,
+        bump = holder_stats.bump
+    )]
+    pub holder_stats: Account<'info, HolderStats>,
+}
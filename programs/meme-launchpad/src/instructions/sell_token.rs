@@ -1,45 +1,91 @@
 use crate::constants::*;
 use crate::errors::ErrorCode;
-use crate::states::{BondingCurve, ProgramState, TokenInfo, Transaction, TransactionType};
+use crate::instructions::set_token_account_frozen::{
+    holds_frozen_balance, is_frozen, set_frozen, token_balance,
+};
+use crate::states::{
+    BondingCurve, CreatorVault, GraduationTarget, LiquidityPool, PlatformFeeVault, ProgramState,
+    TokenInfo, Transaction, TransactionType,
+};
+use crate::utils::{holder_count, transfer_checked_with_hook};
 use anchor_lang::prelude::*;
 // use anchor_lang::system_program;
 use anchor_spl::associated_token::AssociatedToken;
-use anchor_spl::token_2022::{self, Burn, Token2022};
+use holder_tracker::cpi::accounts::SyncPositionCtx;
+use holder_tracker::program::HolderTracker;
+use anchor_spl::token_2022::{self, Burn, Token2022, TransferChecked};
 
-pub fn sell_token(ctx: Context<SellTokenCtx>, token_id: u64, token_amount: u64) -> Result<()> {
+pub fn sell_token<'info>(
+    ctx: Context<'_, '_, 'info, 'info, SellTokenCtx<'info>>,
+    token_id: u64,
+    token_amount: u64,
+) -> Result<()> {
     let program_state = &ctx.accounts.program_state;
     let token_info = &mut ctx.accounts.token_info;
     let bonding_curve = &mut ctx.accounts.bonding_curve;
     let transaction = &mut ctx.accounts.transaction;
     let seller = &ctx.accounts.seller;
 
-    // === VALIDATIONS (same as yours) ===
-    if program_state.is_paused {
-        return Err(ErrorCode::TradingNotActive.into());
+    // Validations
+    if program_state.active_pause_flags(Clock::get()?.unix_timestamp).sell {
+        return Err(ErrorCode::ActionPaused.into());
     }
     if token_info.token_id != token_id {
         return Err(ErrorCode::TokenNotFound.into());
     }
-    if token_info.launched_to_dex {
+    // Graduated tokens keep trading only through the built-in pool
+    let use_pool = token_info.launched_to_dex;
+    if use_pool && token_info.graduation_target != GraduationTarget::BuiltinPool {
         return Err(ErrorCode::TokenAlreadyLaunched.into());
     }
-    if !token_info.trading_active {
+    // A moderation halt may still let holders exit
+    if !token_info.sells_allowed() {
+        if token_info.trading_halt.is_some() {
+            return Err(ErrorCode::TradingHalted.into());
+        }
         return Err(ErrorCode::TradingNotActive.into());
     }
+    if !use_pool && bonding_curve.complete {
+        return Err(ErrorCode::BondingCurveComplete.into());
+    }
     if token_amount == 0 {
         return Err(ErrorCode::InvalidPurchaseAmount.into());
     }
 
-    // Calculate SOL output using bonding curve
-    let sol_output = bonding_curve.calculate_sol_output(token_amount)?;
+    // Tokens with a transfer fee deliver less than `token_amount` to the pool vault
+    let pool_token_input = if use_pool {
+        token_amount
+            .checked_sub(token_info.transfer_fee(&ctx.accounts.mint, token_amount)?)
+            .ok_or(ErrorCode::ArithmeticUnderflow)?
+    } else {
+        token_amount
+    };
+
+    // Calculate SOL output using the bonding curve, or the pool once graduated
+    let (sol_output, available_sol) = if use_pool {
+        let liquidity_pool = ctx
+            .accounts
+            .liquidity_pool
+            .as_ref()
+            .ok_or(ErrorCode::InvalidPoolAccount)?;
+        (
+            liquidity_pool.calculate_sol_output(pool_token_input)?,
+            liquidity_pool.sol_reserves,
+        )
+    } else {
+        (
+            bonding_curve.calculate_sol_output(token_amount)?,
+            bonding_curve.real_sol_reserves,
+        )
+    };
     if sol_output == 0 {
         return Err(ErrorCode::InvalidPurchaseAmount.into());
     }
-    if sol_output > bonding_curve.real_sol_reserves {
+    if sol_output > available_sol {
         return Err(ErrorCode::InsufficientReserves.into());
     }
 
-    // Calculate fees (same as you had)
+    // Calculate fees
     let platform_fee = sol_output
         .checked_mul(program_state.platform_fee_rate)
         .ok_or(ErrorCode::ArithmeticOverflow)?
@@ -58,23 +104,132 @@ pub fn sell_token(ctx: Context<SellTokenCtx>, token_id: u64, token_amount: u64)
         .checked_sub(creator_fee)
         .ok_or(ErrorCode::ArithmeticUnderflow)?;
 
-    // Burn tokens from seller (CPI expects AccountInfo for token_2022 — OK)
-    let burn_accounts = Burn {
-        mint: ctx.accounts.mint.to_account_info(),
-        from: ctx.accounts.seller_token_account.to_account_info(),
-        authority: ctx.accounts.seller.to_account_info(),
-    };
+    // Transfer-locked holder accounts are only thawed for the duration of a curve trade
+    let binding = token_id.to_le_bytes();
+    let bonding_curve_seeds = &[BONDING_CURVE_SEED, binding.as_ref(), &[bonding_curve.bump]];
+    let signer_seeds = &[&bonding_curve_seeds[..]];
+    let transfer_locked = token_info.transfer_locked_until_launch;
+    let was_frozen_holder =
+        transfer_locked && holds_frozen_balance(&ctx.accounts.seller_token_account)?;
+    if transfer_locked && is_frozen(&ctx.accounts.seller_token_account)? {
+        set_frozen(
+            ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.seller_token_account.to_account_info(),
+            ctx.accounts.mint.to_account_info(),
+            bonding_curve.to_account_info(),
+            signer_seeds,
+            false,
+        )?;
+    }
+
+    if use_pool {
+        let liquidity_pool = ctx
+            .accounts
+            .liquidity_pool
+            .as_ref()
+            .ok_or(ErrorCode::InvalidPoolAccount)?;
+        let pool_token_vault = ctx
+            .accounts
+            .pool_token_vault
+            .as_ref()
+            .ok_or(ErrorCode::InvalidPoolAccount)?;
+        if pool_token_vault.key() != liquidity_pool.token_vault {
+            return Err(ErrorCode::InvalidPoolAccount.into());
+        }
+
+        // Transfer tokens from seller into the pool vault
+        let transfer_accounts = TransferChecked {
+            from: ctx.accounts.seller_token_account.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            to: pool_token_vault.to_account_info(),
+            authority: ctx.accounts.seller.to_account_info(),
+        };
+
+        transfer_checked_with_hook(
+            CpiContext::new(ctx.accounts.token_program.to_account_info(), transfer_accounts)
+                .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+            token_amount,
+            token_info.decimals,
+        )?;
+    } else {
+        // Burn tokens from seller (CPI expects AccountInfo for token_2022 — OK)
+        let burn_accounts = Burn {
+            mint: ctx.accounts.mint.to_account_info(),
+            from: ctx.accounts.seller_token_account.to_account_info(),
+            authority: ctx.accounts.seller.to_account_info(),
+        };
+
+        let burn_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), burn_accounts);
 
-    let burn_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), burn_accounts);
+        token_2022::burn(burn_ctx, token_amount)?;
 
-    token_2022::burn(burn_ctx, token_amount)?;
+        // Emptied accounts stay thawed: nothing can move into them before launch,
+        // and they must stay usable once the freeze authority is revoked
+        if transfer_locked && token_balance(&ctx.accounts.seller_token_account)? > 0 {
+            set_frozen(
+                ctx.accounts.token_program.to_account_info(),
+                ctx.accounts.seller_token_account.to_account_info(),
+                ctx.accounts.mint.to_account_info(),
+                bonding_curve.to_account_info(),
+                signer_seeds,
+                true,
+            )?;
+        }
+    }
+
+    if transfer_locked {
+        token_info.track_frozen_holder(
+            was_frozen_holder,
+            holds_frozen_balance(&ctx.accounts.seller_token_account)?,
+        )?;
+    }
+
+    if token_info.holder_tracking {
+        let holder_stats = ctx
+            .accounts
+            .holder_stats
+            .as_ref()
+            .ok_or(ErrorCode::HolderTrackerAccountsMissing)?;
+
+        // Curve burns bypass the transfer hook, so sync the seller's position directly
+        if !use_pool {
+            let holder_tracker_program = ctx
+                .accounts
+                .holder_tracker_program
+                .as_ref()
+                .ok_or(ErrorCode::HolderTrackerAccountsMissing)?;
+            let holder_position = ctx
+                .accounts
+                .holder_position
+                .as_ref()
+                .ok_or(ErrorCode::HolderTrackerAccountsMissing)?;
+
+            holder_tracker::cpi::sync_position(CpiContext::new(
+                holder_tracker_program.to_account_info(),
+                SyncPositionCtx {
+                    mint: ctx.accounts.mint.to_account_info(),
+                    token_account: ctx.accounts.seller_token_account.to_account_info(),
+                    holder_stats: holder_stats.to_account_info(),
+                    position: holder_position.to_account_info(),
+                    payer: ctx.accounts.seller.to_account_info(),
+                    system_program: ctx.accounts.system_program.to_account_info(),
+                },
+            ))?;
+        }
+
+        token_info.holder_count = holder_count(holder_stats, &ctx.accounts.mint.key())?;
+    }
+
+    // SOL reserves live on the bonding curve, or on the pool once graduated
+    let reserve_account = match ctx.accounts.liquidity_pool.as_ref() {
+        Some(liquidity_pool) if use_pool => liquidity_pool.to_account_info(),
+        _ => bonding_curve.to_account_info(),
+    };
 
-    // --- rest of your SOL transfers and updates (unchanged) ---
     // Transfer SOL from bonding curve to seller, fees, etc. directly via lamport modification
-    // (Since bonding_curve is a PDA owned by this program, we can't use system_program::transfer FROM it)
+    // (Since the reserves are PDAs owned by this program, we can't use system_program::transfer FROM them)
 
-    **bonding_curve.to_account_info().try_borrow_mut_lamports()? = bonding_curve
-        .to_account_info()
+    **reserve_account.try_borrow_mut_lamports()? = reserve_account
         .lamports()
         .checked_sub(net_sol_output)
         .ok_or(ErrorCode::ArithmeticUnderflow)?;
@@ -91,18 +246,17 @@ pub fn sell_token(ctx: Context<SellTokenCtx>, token_id: u64, token_amount: u64)
         .ok_or(ErrorCode::ArithmeticOverflow)?;
 
     if platform_fee > 0 {
-        **bonding_curve.to_account_info().try_borrow_mut_lamports()? = bonding_curve
-            .to_account_info()
+        **reserve_account.try_borrow_mut_lamports()? = reserve_account
             .lamports()
             .checked_sub(platform_fee)
             .ok_or(ErrorCode::ArithmeticUnderflow)?;
         **ctx
             .accounts
-            .program_state
+            .platform_fee_vault
             .to_account_info()
             .try_borrow_mut_lamports()? = ctx
             .accounts
-            .program_state
+            .platform_fee_vault
             .to_account_info()
             .lamports()
             .checked_add(platform_fee)
@@ -110,47 +264,69 @@ pub fn sell_token(ctx: Context<SellTokenCtx>, token_id: u64, token_amount: u64)
     }
 
     if creator_fee > 0 {
-        **bonding_curve.to_account_info().try_borrow_mut_lamports()? = bonding_curve
-            .to_account_info()
+        **reserve_account.try_borrow_mut_lamports()? = reserve_account
             .lamports()
             .checked_sub(creator_fee)
             .ok_or(ErrorCode::ArithmeticUnderflow)?;
         **ctx
             .accounts
-            .token_creator
+            .creator_vault
             .to_account_info()
             .try_borrow_mut_lamports()? = ctx
             .accounts
-            .token_creator
+            .creator_vault
             .to_account_info()
             .lamports()
             .checked_add(creator_fee)
             .ok_or(ErrorCode::ArithmeticOverflow)?;
     }
 
-    // Update bonding curve reserves and token_info (unchanged)
-    bonding_curve.update_reserves_sell(token_amount, sol_output)?;
-    bonding_curve.total_sol_volume = bonding_curve
-        .total_sol_volume
-        .checked_add(sol_output)
-        .ok_or(ErrorCode::ArithmeticOverflow)?;
-    bonding_curve.total_token_volume = bonding_curve
-        .total_token_volume
-        .checked_add(token_amount)
-        .ok_or(ErrorCode::ArithmeticOverflow)?;
-    bonding_curve.last_updated = Clock::get()?.unix_timestamp;
+    // Update bonding curve reserves and token_info
+    if use_pool {
+        let liquidity_pool = ctx
+            .accounts
+            .liquidity_pool
+            .as_mut()
+            .ok_or(ErrorCode::InvalidPoolAccount)?;
+        liquidity_pool.update_reserves_sell(pool_token_input, sol_output)?;
+        liquidity_pool.total_sol_volume = liquidity_pool
+            .total_sol_volume
+            .checked_add(sol_output)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        liquidity_pool.total_token_volume = liquidity_pool
+            .total_token_volume
+            .checked_add(token_amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        liquidity_pool.last_updated = Clock::get()?.unix_timestamp;
+    } else {
+        bonding_curve.update_reserves_sell(token_amount, sol_output)?;
+        bonding_curve.total_sol_volume = bonding_curve
+            .total_sol_volume
+            .checked_add(sol_output)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        bonding_curve.total_token_volume = bonding_curve
+            .total_token_volume
+            .checked_add(token_amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        bonding_curve.last_updated = Clock::get()?.unix_timestamp;
 
-    token_info.circulating_supply = token_info
-        .circulating_supply
-        .checked_sub(token_amount)
-        .ok_or(ErrorCode::ArithmeticUnderflow)?;
+        token_info.circulating_supply = token_info
+            .circulating_supply
+            .checked_sub(token_amount)
+            .ok_or(ErrorCode::ArithmeticUnderflow)?;
+    }
     token_info.transaction_count += 1;
     token_info.creator_fees_collected = token_info
         .creator_fees_collected
         .checked_add(creator_fee)
         .ok_or(ErrorCode::ArithmeticOverflow)?;
+    let program_state = &mut ctx.accounts.program_state;
+    program_state.total_fees_collected = program_state
+        .total_fees_collected
+        .checked_add(platform_fee)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
 
-    // Record transaction (unchanged)
+    // Record transaction
     transaction.transaction_id = token_info.transaction_count;
     transaction.token_id = token_id;
     transaction.user = *seller.key;
@@ -182,6 +358,7 @@ pub fn sell_token(ctx: Context<SellTokenCtx>, token_id: u64, token_amount: u64)
 #[instruction(token_id: u64, token_amount: u64)]
 pub struct SellTokenCtx<'info> {
     #[account(
+        mut,
         seeds = [PROGRAM_STATE_SEED],
         bump = program_state.bump
     )]
@@ -215,6 +392,18 @@ pub struct SellTokenCtx<'info> {
     )]
     pub transaction: Account<'info, Transaction>,
 
+    /// Built-in liquidity pool, required once the token has graduated to it
+    #[account(
+        mut,
+        seeds = [LIQUIDITY_POOL_SEED, token_id.to_le_bytes().as_ref()],
+        bump = liquidity_pool.bump
+    )]
+    pub liquidity_pool: Option<Box<Account<'info, LiquidityPool>>>,
+
+    /// CHECK: Pool token vault, validated against `liquidity_pool.token_vault` in the handler.
+    #[account(mut)]
+    pub pool_token_vault: Option<UncheckedAccount<'info>>,
+
     /// CHECK: This is the Token-2022 mint account. We validate it manually in the handler.
     #[account(
         mut,
@@ -226,16 +415,36 @@ pub struct SellTokenCtx<'info> {
     #[account(mut)]
     pub seller_token_account: AccountInfo<'info>,
 
-    /// CHECK: Token creator account for fee distribution, validated through constraint
+    /// Vault collecting platform fees
     #[account(
         mut,
-        constraint = token_creator.key().to_bytes() == token_info.creator.to_bytes() @ ErrorCode::InvalidCreator
+        seeds = [PLATFORM_FEE_VAULT_SEED],
+        bump = platform_fee_vault.bump
     )]
-    pub token_creator: AccountInfo<'info>,
+    pub platform_fee_vault: Box<Account<'info, PlatformFeeVault>>,
+
+    /// Vault accruing the token's creator fees
+    #[account(
+        mut,
+        seeds = [CREATOR_VAULT_SEED, token_id.to_le_bytes().as_ref()],
+        bump = creator_vault.bump
+    )]
+    pub creator_vault: Box<Account<'info, CreatorVault>>,
 
     #[account(mut)]
     pub seller: Signer<'info>,
 
+    /// Holder tracker program, required for curve trades of tokens with holder tracking
+    pub holder_tracker_program: Option<Program<'info, HolderTracker>>,
+
+    /// CHECK: Holder stats for the mint, validated by the holder tracker.
+    #[account(mut)]
+    pub holder_stats: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Holder tracker position of the seller's token account, validated by the holder tracker.
+    #[account(mut)]
+    pub holder_position: Option<UncheckedAccount<'info>>,
+
     pub token_program: Program<'info, Token2022>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
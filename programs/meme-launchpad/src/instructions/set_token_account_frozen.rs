@@ -0,0 +1,140 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022::extension::StateWithExtensions;
+use anchor_spl::token_2022::spl_token_2022::state::{Account as SplAccount, AccountState};
+use anchor_spl::token_2022::{self, FreezeAccount, ThawAccount, Token2022};
+use anchor_spl::token_interface::{Mint, TokenAccount};
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::states::{BondingCurve, FreezeAuthorityPolicy, ProgramState, TokenInfo};
+
+/// Whether a Token-2022 token account is frozen
+pub(crate) fn is_frozen(token_account: &AccountInfo) -> Result<bool> {
+    let data = token_account.try_borrow_data()?;
+    let account = StateWithExtensions::<SplAccount>::unpack(&data)?;
+    Ok(account.base.state == AccountState::Frozen)
+}
+
+/// Balance of a Token-2022 token account
+pub(crate) fn token_balance(token_account: &AccountInfo) -> Result<u64> {
+    let data = token_account.try_borrow_data()?;
+    let account = StateWithExtensions::<SplAccount>::unpack(&data)?;
+    Ok(account.base.amount)
+}
+
+/// Whether a Token-2022 token account is frozen while holding tokens, i.e. a
+/// transfer-locked holder still waiting to be thawed. Missing accounts never are.
+pub(crate) fn holds_frozen_balance(token_account: &AccountInfo) -> Result<bool> {
+    if token_account.data_is_empty() {
+        return Ok(false);
+    }
+    let data = token_account.try_borrow_data()?;
+    let account = StateWithExtensions::<SplAccount>::unpack(&data)?;
+    Ok(account.base.state == AccountState::Frozen && account.base.amount > 0)
+}
+
+/// Freezes or thaws `token_account` with the bonding curve PDA as freeze authority
+pub(crate) fn set_frozen<'info>(
+    token_program: AccountInfo<'info>,
+    token_account: AccountInfo<'info>,
+    mint: AccountInfo<'info>,
+    bonding_curve: AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+    frozen: bool,
+) -> Result<()> {
+    if frozen {
+        token_2022::freeze_account(CpiContext::new_with_signer(
+            token_program,
+            FreezeAccount {
+                account: token_account,
+                mint,
+                authority: bonding_curve,
+            },
+            signer_seeds,
+        ))
+    } else {
+        token_2022::thaw_account(CpiContext::new_with_signer(
+            token_program,
+            ThawAccount {
+                account: token_account,
+                mint,
+                authority: bonding_curve,
+            },
+            signer_seeds,
+        ))
+    }
+}
+
+/// Freezes or thaws a holder's token account while the bonding curve holds the freeze authority
+pub fn set_token_account_frozen(
+    ctx: Context<SetTokenAccountFrozenCtx>,
+    token_id: u64,
+    frozen: bool,
+) -> Result<()> {
+    let token_info = &ctx.accounts.token_info;
+    let bonding_curve = &ctx.accounts.bonding_curve;
+
+    // Transfer-locked tokens are frozen by default and only move through curve trades
+    if token_info.freeze_authority_policy != FreezeAuthorityPolicy::BondingCurveUntilLaunch
+        || token_info.launched_to_dex
+        || token_info.transfer_locked_until_launch
+    {
+        return Err(ErrorCode::FreezeAuthorityNotHeld.into());
+    }
+
+    let binding = token_id.to_le_bytes();
+    let bonding_curve_seeds = &[BONDING_CURVE_SEED, binding.as_ref(), &[bonding_curve.bump]];
+    let signer_seeds = &[&bonding_curve_seeds[..]];
+
+    set_frozen(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.token_account.to_account_info(),
+        ctx.accounts.mint.to_account_info(),
+        bonding_curve.to_account_info(),
+        signer_seeds,
+        frozen,
+    )?;
+
+    msg!("Token account {} frozen: {}", ctx.accounts.token_account.key(), frozen);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(token_id: u64)]
+pub struct SetTokenAccountFrozenCtx<'info> {
+    #[account(
+        seeds = [PROGRAM_STATE_SEED],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+        seeds = [TOKEN_INFO_SEED, token_id.to_le_bytes().as_ref()],
+        bump = token_info.bump
+    )]
+    pub token_info: Account<'info, TokenInfo>,
+
+    #[account(
+        seeds = [BONDING_CURVE_SEED, token_id.to_le_bytes().as_ref()],
+        bump = bonding_curve.bump
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+
+    #[account(address = token_info.mint)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program
+    )]
+    pub token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        constraint = compliance_authority.key() == program_state.compliance_authority @ ErrorCode::Unauthorized
+    )]
+    pub compliance_authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+}
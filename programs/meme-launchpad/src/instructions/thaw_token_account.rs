@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::instructions::set_token_account_frozen::{holds_frozen_balance, is_frozen, set_frozen};
+use crate::states::{BondingCurve, TokenInfo};
+
+/// Permissionlessly thaws a holder account of a transfer-locked token after launch
+pub fn thaw_token_account(ctx: Context<ThawTokenAccountCtx>, token_id: u64) -> Result<()> {
+    let token_info = &mut ctx.accounts.token_info;
+    let bonding_curve = &ctx.accounts.bonding_curve;
+
+    if !token_info.transfer_locked_until_launch || !token_info.launched_to_dex {
+        return Err(ErrorCode::TransferLockActive.into());
+    }
+
+    let token_account = ctx.accounts.token_account.to_account_info();
+    if !is_frozen(&token_account)? {
+        msg!("Token account {} already thawed", token_account.key());
+        return Ok(());
+    }
+
+    let was_frozen_holder = holds_frozen_balance(&token_account)?;
+    let binding = token_id.to_le_bytes();
+    let bonding_curve_seeds = &[BONDING_CURVE_SEED, binding.as_ref(), &[bonding_curve.bump]];
+    let signer_seeds = &[&bonding_curve_seeds[..]];
+
+    set_frozen(
+        ctx.accounts.token_program.to_account_info(),
+        token_account,
+        ctx.accounts.mint.to_account_info(),
+        bonding_curve.to_account_info(),
+        signer_seeds,
+        false,
+    )?;
+    token_info.track_frozen_holder(was_frozen_holder, false)?;
+
+    msg!("Token account {} thawed", ctx.accounts.token_account.key());
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(token_id: u64)]
+pub struct ThawTokenAccountCtx<'info> {
+    #[account(
+        mut,
+        seeds = [TOKEN_INFO_SEED, token_id.to_le_bytes().as_ref()],
+        bump = token_info.bump
+    )]
+    pub token_info: Account<'info, TokenInfo>,
+
+    #[account(
+        seeds = [BONDING_CURVE_SEED, token_id.to_le_bytes().as_ref()],
+        bump = bonding_curve.bump
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+
+    #[account(address = token_info.mint)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program
+    )]
+    pub token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token2022>,
+}
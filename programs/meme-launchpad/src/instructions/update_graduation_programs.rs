@@ -0,0 +1,134 @@
+use anchor_lang::prelude::*;
+use crate::constants::{MAX_GRADUATION_PROGRAMS, PROGRAM_STATE_SEED, TOKEN_INFO_SEED};
+use crate::errors::ErrorCode;
+use crate::states::{GraduationProgram, GraduationTarget, ProgramState, TokenInfo};
+
+pub fn add_graduation_program(
+    ctx: Context<UpdateGraduationProgramsCtx>,
+    target: GraduationTarget,
+    program_id: Pubkey,
+) -> Result<()> {
+    let program_state = &mut ctx.accounts.program_state;
+
+    // The built-in pool is served by this program and needs no allowlist entry
+    if target == GraduationTarget::BuiltinPool {
+        return Err(ErrorCode::InvalidGraduationTarget.into());
+    }
+
+    if program_state.is_graduation_program_allowed(target, &program_id) {
+        msg!("Graduation program already allowlisted: {}", program_id);
+        return Ok(());
+    }
+
+    if program_state.graduation_programs.len() >= MAX_GRADUATION_PROGRAMS {
+        return Err(ErrorCode::GraduationProgramListFull.into());
+    }
+
+    program_state
+        .graduation_programs
+        .push(GraduationProgram { target, program_id });
+
+    msg!("Graduation program allowlisted: {}", program_id);
+
+    Ok(())
+}
+
+pub fn remove_graduation_program(
+    ctx: Context<UpdateGraduationProgramsCtx>,
+    program_id: Pubkey,
+) -> Result<()> {
+    let program_state = &mut ctx.accounts.program_state;
+
+    let count_before = program_state.graduation_programs.len();
+    program_state
+        .graduation_programs
+        .retain(|entry| entry.program_id != program_id);
+
+    if program_state.graduation_programs.len() == count_before {
+        return Err(ErrorCode::GraduationProgramNotAllowed.into());
+    }
+
+    msg!("Graduation program removed: {}", program_id);
+
+    Ok(())
+}
+
+/// Point an unlaunched token whose graduation program was delisted at an
+/// allowlisted one, so its curve can still launch
+pub fn retarget_graduation(
+    ctx: Context<RetargetGraduationCtx>,
+    token_id: u64,
+    target: GraduationTarget,
+    program_id: Pubkey,
+) -> Result<()> {
+    let program_state = &ctx.accounts.program_state;
+    let token_info = &mut ctx.accounts.token_info;
+
+    if token_info.token_id != token_id {
+        return Err(ErrorCode::TokenNotFound.into());
+    }
+
+    if token_info.launched_to_dex {
+        return Err(ErrorCode::TokenAlreadyLaunched.into());
+    }
+
+    // Holders bought into the original target; it only changes once that is gone
+    if program_state
+        .is_graduation_program_allowed(token_info.graduation_target, &token_info.graduation_program)
+    {
+        return Err(ErrorCode::GraduationProgramStillAllowed.into());
+    }
+
+    if !program_state.is_graduation_program_allowed(target, &program_id) {
+        return Err(ErrorCode::GraduationProgramNotAllowed.into());
+    }
+    if !target.supports_liquidity_lock(token_info.liquidity_lock) {
+        return Err(ErrorCode::UnsupportedLiquidityLock.into());
+    }
+
+    token_info.graduation_target = target;
+    token_info.graduation_program = program_id;
+
+    msg!("Token {} graduation retargeted to: {}", token_id, program_id);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateGraduationProgramsCtx<'info> {
+    #[account(
+        mut,
+        seeds = [PROGRAM_STATE_SEED],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+        constraint = authority.key() == program_state.platform_authority @ ErrorCode::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(token_id: u64)]
+pub struct RetargetGraduationCtx<'info> {
+    #[account(
+        seeds = [PROGRAM_STATE_SEED],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+        mut,
+        seeds = [TOKEN_INFO_SEED, token_id.to_le_bytes().as_ref()],
+        bump = token_info.bump
+    )]
+    pub token_info: Account<'info, TokenInfo>,
+
+    /// Token creator or platform authority
+    #[account(
+        constraint = authority.key() == token_info.creator
+            || authority.key() == program_state.platform_authority @ ErrorCode::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use crate::constants::{MAX_LAUNCHER_REWARD_BPS, MAX_LAUNCH_GRACE_PERIOD, PROGRAM_STATE_SEED};
+use crate::errors::ErrorCode;
+use crate::states::ProgramState;
+
+pub fn update_launch_crank(
+    ctx: Context<UpdateLaunchCrankCtx>,
+    launcher_reward_bps: u64,
+    launch_grace_period: i64,
+) -> Result<()> {
+    let program_state = &mut ctx.accounts.program_state;
+
+    if launcher_reward_bps > MAX_LAUNCHER_REWARD_BPS {
+        return Err(ErrorCode::InvalidLauncherReward.into());
+    }
+
+    if !(0..=MAX_LAUNCH_GRACE_PERIOD).contains(&launch_grace_period) {
+        return Err(ErrorCode::InvalidLaunchGracePeriod.into());
+    }
+
+    let old_reward_bps = program_state.launcher_reward_bps;
+    let old_grace_period = program_state.launch_grace_period;
+
+    program_state.launcher_reward_bps = launcher_reward_bps;
+    program_state.launch_grace_period = launch_grace_period;
+
+    msg!("Launch crank settings updated!");
+    msg!("Launcher reward: {} -> {} basis points", old_reward_bps, launcher_reward_bps);
+    msg!("Grace period: {} -> {} seconds", old_grace_period, launch_grace_period);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateLaunchCrankCtx<'info> {
+    #[account(
+        mut,
+        seeds = [PROGRAM_STATE_SEED],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+        constraint = fee_manager.key() == program_state.fee_manager @ ErrorCode::Unauthorized
+    )]
+    pub fee_manager: Signer<'info>,
+}
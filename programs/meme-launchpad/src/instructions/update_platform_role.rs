@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use crate::constants::PROGRAM_STATE_SEED;
+use crate::errors::ErrorCode;
+use crate::states::{PlatformRole, ProgramState};
+
+pub fn update_platform_role(
+    ctx: Context<UpdatePlatformRoleCtx>,
+    role: PlatformRole,
+    new_holder: Pubkey,
+) -> Result<()> {
+    let program_state = &mut ctx.accounts.program_state;
+
+    if new_holder == Pubkey::default() {
+        return Err(ErrorCode::InvalidAccount.into());
+    }
+
+    let old_holder = program_state.role_holder(role);
+    match role {
+        PlatformRole::FeeManager => program_state.fee_manager = new_holder,
+        PlatformRole::Pauser => program_state.pauser = new_holder,
+        PlatformRole::Moderator => program_state.moderator = new_holder,
+        PlatformRole::Treasurer => program_state.treasurer = new_holder,
+    }
+
+    msg!("Platform role updated!");
+    msg!("Old holder: {}", old_holder);
+    msg!("New holder: {}", new_holder);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdatePlatformRoleCtx<'info> {
+    #[account(
+        mut,
+        seeds = [PROGRAM_STATE_SEED],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+        constraint = authority.key() == program_state.platform_authority @ ErrorCode::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
@@ -1,48 +1,201 @@
 use anchor_lang::prelude::*;
-use crate::constants::{PROGRAM_STATE_SEED};
+use crate::constants::{MAX_GOVERNANCE_DELAY, MIN_GOVERNANCE_DELAY, PROGRAM_STATE_SEED};
 use crate::errors::ErrorCode;
-use crate::states::ProgramState;
+use crate::states::{
+    PauseFlags, PendingPlatformLimits, PendingPlatformSettings, PendingTreasury, PlatformLimits,
+    ProgramState,
+};
 
-pub fn update_platform_settings(
+pub fn schedule_platform_settings(
     ctx: Context<UpdatePlatformSettingsCtx>,
     new_fee_rate: u64,
     new_launch_threshold: u64,
+    new_governance_delay: i64,
 ) -> Result<()> {
     let program_state = &mut ctx.accounts.program_state;
-    let authority = &ctx.accounts.authority;
+    let fee_manager = &ctx.accounts.fee_manager;
 
-    // Check if caller is platform authority
-    if program_state.platform_authority != authority.key() {
+    // Check if caller is the fee manager
+    if program_state.fee_manager != fee_manager.key() {
         return Err(ErrorCode::Unauthorized.into());
     }
 
-    // Validate new fee rate (max 10% = 1000 basis points)
-    if new_fee_rate > 1000 {
+    // Validate new fee rate (max 10% = 1000 basis points by default)
+    if new_fee_rate > program_state.limits.max_platform_fee_rate {
         return Err(ErrorCode::InvalidFeeRate.into());
     }
 
-    // Validate new launch threshold (minimum 100 SOL)
-    if new_launch_threshold < 100_000_000_000 { // 100 SOL in lamports
+    // Validate new launch threshold (minimum 100 SOL by default)
+    if new_launch_threshold < program_state.limits.min_launch_threshold {
+        return Err(ErrorCode::InvalidLaunchThreshold.into());
+    }
+
+    if !(MIN_GOVERNANCE_DELAY..=MAX_GOVERNANCE_DELAY).contains(&new_governance_delay) {
+        return Err(ErrorCode::InvalidGovernanceDelay.into());
+    }
+
+    // The change waits out the current delay; scheduling again restarts the clock
+    let executable_at = Clock::get()?
+        .unix_timestamp
+        .checked_add(program_state.governance_delay)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    program_state.pending_settings = Some(PendingPlatformSettings {
+        fee_rate: new_fee_rate,
+        launch_threshold: new_launch_threshold,
+        governance_delay: new_governance_delay,
+        executable_at,
+    });
+
+    msg!("Platform settings change scheduled!");
+    msg!("Fee rate: {} -> {} basis points", program_state.platform_fee_rate, new_fee_rate);
+    msg!("Launch threshold: {} -> {} lamports", program_state.launch_threshold, new_launch_threshold);
+    msg!("Governance delay: {} -> {} seconds", program_state.governance_delay, new_governance_delay);
+    msg!("Executable at: {}", executable_at);
+
+    Ok(())
+}
+
+pub fn execute_platform_settings(
+    ctx: Context<UpdatePlatformSettingsCtx>,
+) -> Result<()> {
+    let program_state = &mut ctx.accounts.program_state;
+
+    let pending = program_state
+        .pending_settings
+        .ok_or(ErrorCode::NoPendingChange)?;
+    if Clock::get()?.unix_timestamp < pending.executable_at {
+        return Err(ErrorCode::TimelockNotElapsed.into());
+    }
+
+    // Limits may have tightened since the change was scheduled
+    if pending.fee_rate > program_state.limits.max_platform_fee_rate {
+        return Err(ErrorCode::InvalidFeeRate.into());
+    }
+    if pending.launch_threshold < program_state.limits.min_launch_threshold {
         return Err(ErrorCode::InvalidLaunchThreshold.into());
     }
 
     // Store old values for logging
     let old_fee_rate = program_state.platform_fee_rate;
     let old_launch_threshold = program_state.launch_threshold;
+    let old_governance_delay = program_state.governance_delay;
 
     // Update settings
-    program_state.platform_fee_rate = new_fee_rate;
-    program_state.launch_threshold = new_launch_threshold;
+    program_state.platform_fee_rate = pending.fee_rate;
+    program_state.launch_threshold = pending.launch_threshold;
+    program_state.governance_delay = pending.governance_delay;
+    program_state.pending_settings = None;
 
     msg!("Platform settings updated successfully!");
-    msg!("Fee rate: {} -> {} basis points", old_fee_rate, new_fee_rate);
-    msg!("Launch threshold: {} -> {} lamports", old_launch_threshold, new_launch_threshold);
+    msg!("Fee rate: {} -> {} basis points", old_fee_rate, pending.fee_rate);
+    msg!("Launch threshold: {} -> {} lamports", old_launch_threshold, pending.launch_threshold);
+    msg!("Governance delay: {} -> {} seconds", old_governance_delay, pending.governance_delay);
+
+    Ok(())
+}
+
+pub fn cancel_platform_settings(
+    ctx: Context<UpdatePlatformSettingsCtx>,
+) -> Result<()> {
+    let program_state = &mut ctx.accounts.program_state;
+
+    program_state
+        .pending_settings
+        .take()
+        .ok_or(ErrorCode::NoPendingChange)?;
+
+    msg!("Scheduled platform settings change cancelled");
+
+    Ok(())
+}
+
+pub fn schedule_platform_limits(
+    ctx: Context<UpdatePlatformLimitsCtx>,
+    new_limits: PlatformLimits,
+) -> Result<()> {
+    let program_state = &mut ctx.accounts.program_state;
+    let authority = &ctx.accounts.authority;
+
+    // Check if caller is platform authority
+    if program_state.platform_authority != authority.key() {
+        return Err(ErrorCode::Unauthorized.into());
+    }
+
+    new_limits.validate()?;
+
+    let executable_at = Clock::get()?
+        .unix_timestamp
+        .checked_add(program_state.governance_delay)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    program_state.pending_limits = Some(PendingPlatformLimits {
+        limits: new_limits,
+        executable_at,
+    });
+
+    msg!("Platform limits change scheduled!");
+    msg!(
+        "Purchase: {}..={} lamports, min trading time: {} seconds",
+        new_limits.min_token_purchase,
+        new_limits.max_token_purchase,
+        new_limits.min_trading_time
+    );
+    msg!(
+        "Launch liquidity: {} bps, max fee rate: {} bps, min launch threshold: {} lamports",
+        new_limits.launch_liquidity_bps,
+        new_limits.max_platform_fee_rate,
+        new_limits.min_launch_threshold
+    );
+    msg!("Executable at: {}", executable_at);
 
     Ok(())
 }
 
-pub fn update_platform_authority(
-    ctx: Context<UpdatePlatformAuthorityCtx>,
+pub fn execute_platform_limits(
+    ctx: Context<UpdatePlatformLimitsCtx>,
+) -> Result<()> {
+    let program_state = &mut ctx.accounts.program_state;
+
+    let pending = program_state
+        .pending_limits
+        .ok_or(ErrorCode::NoPendingChange)?;
+    if Clock::get()?.unix_timestamp < pending.executable_at {
+        return Err(ErrorCode::TimelockNotElapsed.into());
+    }
+
+    // The current fee rate and launch threshold must stay within the new limits
+    if program_state.platform_fee_rate > pending.limits.max_platform_fee_rate
+        || program_state.launch_threshold < pending.limits.min_launch_threshold
+    {
+        return Err(ErrorCode::InvalidPlatformLimits.into());
+    }
+
+    program_state.limits = pending.limits;
+    program_state.pending_limits = None;
+
+    msg!("Platform limits updated successfully!");
+
+    Ok(())
+}
+
+pub fn cancel_platform_limits(
+    ctx: Context<UpdatePlatformLimitsCtx>,
+) -> Result<()> {
+    let program_state = &mut ctx.accounts.program_state;
+
+    program_state
+        .pending_limits
+        .take()
+        .ok_or(ErrorCode::NoPendingChange)?;
+
+    msg!("Scheduled platform limits change cancelled");
+
+    Ok(())
+}
+
+pub fn propose_platform_authority(
+    ctx: Context<ProposePlatformAuthorityCtx>,
     new_authority: Pubkey,
 ) -> Result<()> {
     let program_state = &mut ctx.accounts.program_state;
@@ -53,52 +206,181 @@ pub fn update_platform_authority(
         return Err(ErrorCode::Unauthorized.into());
     }
 
+    if new_authority == Pubkey::default() || new_authority == program_state.platform_authority {
+        return Err(ErrorCode::InvalidAuthority.into());
+    }
+
+    // Control only moves once the proposed authority signs `accept_platform_authority`
+    program_state.pending_platform_authority = Some(new_authority);
+
+    msg!("Platform authority transfer proposed!");
+    msg!("Current authority: {}", program_state.platform_authority);
+    msg!("Pending authority: {}", new_authority);
+
+    Ok(())
+}
+
+pub fn accept_platform_authority(
+    ctx: Context<AcceptPlatformAuthorityCtx>,
+) -> Result<()> {
+    let program_state = &mut ctx.accounts.program_state;
+    let new_authority = &ctx.accounts.new_authority;
+
+    let pending_authority = program_state
+        .pending_platform_authority
+        .ok_or(ErrorCode::NoPendingAuthorityTransfer)?;
+    if pending_authority != new_authority.key() {
+        return Err(ErrorCode::Unauthorized.into());
+    }
+
     let old_authority = program_state.platform_authority;
-    program_state.platform_authority = new_authority;
+    program_state.platform_authority = pending_authority;
+    program_state.pending_platform_authority = None;
 
     msg!("Platform authority updated!");
     msg!("Old authority: {}", old_authority);
-    msg!("New authority: {}", new_authority);
+    msg!("New authority: {}", pending_authority);
 
     Ok(())
 }
 
-pub fn update_platform_treasury(
+pub fn cancel_platform_authority_transfer(
+    ctx: Context<CancelPlatformAuthorityTransferCtx>,
+) -> Result<()> {
+    let program_state = &mut ctx.accounts.program_state;
+
+    let pending_authority = program_state
+        .pending_platform_authority
+        .take()
+        .ok_or(ErrorCode::NoPendingAuthorityTransfer)?;
+
+    msg!("Platform authority transfer to {} cancelled", pending_authority);
+
+    Ok(())
+}
+
+pub fn schedule_platform_treasury(
     ctx: Context<UpdatePlatformTreasuryCtx>,
     new_treasury: Pubkey,
 ) -> Result<()> {
     let program_state = &mut ctx.accounts.program_state;
-    let authority = &ctx.accounts.authority;
+    let treasurer = &ctx.accounts.treasurer;
 
-    // Check if caller is platform authority
-    if program_state.platform_authority != authority.key() {
+    // Check if caller is the treasurer
+    if program_state.treasurer != treasurer.key() {
         return Err(ErrorCode::Unauthorized.into());
     }
 
+    if new_treasury == Pubkey::default() {
+        return Err(ErrorCode::InvalidAccount.into());
+    }
+
+    let executable_at = Clock::get()?
+        .unix_timestamp
+        .checked_add(program_state.governance_delay)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    program_state.pending_treasury = Some(PendingTreasury {
+        treasury: new_treasury,
+        executable_at,
+    });
+
+    msg!("Platform treasury change scheduled!");
+    msg!("Current treasury: {}", program_state.platform_treasury);
+    msg!("New treasury: {}", new_treasury);
+    msg!("Executable at: {}", executable_at);
+
+    Ok(())
+}
+
+pub fn execute_platform_treasury(
+    ctx: Context<UpdatePlatformTreasuryCtx>,
+) -> Result<()> {
+    let program_state = &mut ctx.accounts.program_state;
+
+    let pending = program_state
+        .pending_treasury
+        .ok_or(ErrorCode::NoPendingChange)?;
+    if Clock::get()?.unix_timestamp < pending.executable_at {
+        return Err(ErrorCode::TimelockNotElapsed.into());
+    }
+
     let old_treasury = program_state.platform_treasury;
-    program_state.platform_treasury = new_treasury;
+    program_state.platform_treasury = pending.treasury;
+    program_state.pending_treasury = None;
 
     msg!("Platform treasury updated!");
     msg!("Old treasury: {}", old_treasury);
-    msg!("New treasury: {}", new_treasury);
+    msg!("New treasury: {}", pending.treasury);
 
     Ok(())
 }
 
-pub fn toggle_emergency_pause(
-    ctx: Context<ToggleEmergencyPauseCtx>,
+pub fn cancel_platform_treasury(
+    ctx: Context<UpdatePlatformTreasuryCtx>,
 ) -> Result<()> {
     let program_state = &mut ctx.accounts.program_state;
-    let authority = &ctx.accounts.authority;
 
-    // Check if caller is platform authority
-    if program_state.platform_authority != authority.key() {
+    program_state
+        .pending_treasury
+        .take()
+        .ok_or(ErrorCode::NoPendingChange)?;
+
+    msg!("Scheduled platform treasury change cancelled");
+
+    Ok(())
+}
+
+pub fn update_compliance_authority(
+    ctx: Context<UpdateComplianceAuthorityCtx>,
+    new_compliance_authority: Pubkey,
+) -> Result<()> {
+    let program_state = &mut ctx.accounts.program_state;
+
+    let old_compliance_authority = program_state.compliance_authority;
+    program_state.compliance_authority = new_compliance_authority;
+
+    msg!("Compliance authority updated!");
+    msg!("Old compliance authority: {}", old_compliance_authority);
+    msg!("New compliance authority: {}", new_compliance_authority);
+
+    Ok(())
+}
+
+pub fn set_pause_flags(
+    ctx: Context<SetPauseFlagsCtx>,
+    pause_flags: PauseFlags,
+    reason_code: u16,
+    paused_until: Option<i64>,
+) -> Result<()> {
+    let program_state = &mut ctx.accounts.program_state;
+    let pauser = &ctx.accounts.pauser;
+
+    // Check if caller is the pauser
+    if program_state.pauser != pauser.key() {
         return Err(ErrorCode::Unauthorized.into());
     }
 
-    program_state.is_paused = !program_state.is_paused;
+    // A scheduled auto-unpause must lie in the future
+    if let Some(paused_until) = paused_until {
+        if paused_until <= Clock::get()?.unix_timestamp {
+            return Err(ErrorCode::InvalidPauseSchedule.into());
+        }
+    }
+
+    program_state.pause_flags = pause_flags;
+    program_state.pause_reason = reason_code;
+    program_state.paused_until = paused_until;
 
-    msg!("Emergency pause toggled: {}", program_state.is_paused);
+    msg!(
+        "Pause flags set: create={}, buy={}, sell={}, launch={}, withdraw={}",
+        pause_flags.create,
+        pause_flags.buy,
+        pause_flags.sell,
+        pause_flags.launch,
+        pause_flags.withdraw
+    );
+    msg!("Reason code: {}, paused until: {:?}", reason_code, paused_until);
 
     Ok(())
 }
@@ -112,6 +394,21 @@ pub struct UpdatePlatformSettingsCtx<'info> {
     )]
     pub program_state: Account<'info, ProgramState>,
     
+    #[account(
+        constraint = fee_manager.key() == program_state.fee_manager @ ErrorCode::Unauthorized
+    )]
+    pub fee_manager: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct UpdatePlatformLimitsCtx<'info> {
+    #[account(
+        mut,
+        seeds = [PROGRAM_STATE_SEED],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
     #[account(
         constraint = authority.key() == program_state.platform_authority @ ErrorCode::Unauthorized
     )]
@@ -119,7 +416,7 @@ pub struct UpdatePlatformSettingsCtx<'info> {
 }
 
 #[derive(Accounts)]
-pub struct UpdatePlatformAuthorityCtx<'info> {
+pub struct ProposePlatformAuthorityCtx<'info> {
     #[account(
         mut,
         seeds = [PROGRAM_STATE_SEED],
@@ -134,7 +431,22 @@ pub struct UpdatePlatformAuthorityCtx<'info> {
 }
 
 #[derive(Accounts)]
-pub struct UpdatePlatformTreasuryCtx<'info> {
+pub struct AcceptPlatformAuthorityCtx<'info> {
+    #[account(
+        mut,
+        seeds = [PROGRAM_STATE_SEED],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        constraint = Some(new_authority.key()) == program_state.pending_platform_authority @ ErrorCode::Unauthorized
+    )]
+    pub new_authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CancelPlatformAuthorityTransferCtx<'info> {
     #[account(
         mut,
         seeds = [PROGRAM_STATE_SEED],
@@ -149,7 +461,22 @@ pub struct UpdatePlatformTreasuryCtx<'info> {
 }
 
 #[derive(Accounts)]
-pub struct ToggleEmergencyPauseCtx<'info> {
+pub struct UpdatePlatformTreasuryCtx<'info> {
+    #[account(
+        mut,
+        seeds = [PROGRAM_STATE_SEED],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        constraint = treasurer.key() == program_state.treasurer @ ErrorCode::Unauthorized
+    )]
+    pub treasurer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateComplianceAuthorityCtx<'info> {
     #[account(
         mut,
         seeds = [PROGRAM_STATE_SEED],
@@ -161,4 +488,19 @@ pub struct ToggleEmergencyPauseCtx<'info> {
         constraint = authority.key() == program_state.platform_authority @ ErrorCode::Unauthorized
     )]
     pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetPauseFlagsCtx<'info> {
+    #[account(
+        mut,
+        seeds = [PROGRAM_STATE_SEED],
+        bump = program_state.bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+    
+    #[account(
+        constraint = pauser.key() == program_state.pauser @ ErrorCode::Unauthorized
+    )]
+    pub pauser: Signer<'info>,
 }
\ No newline at end of file
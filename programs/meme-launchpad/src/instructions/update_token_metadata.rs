@@ -0,0 +1,128 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token_2022::spl_token_2022::extension::{BaseStateWithExtensions, StateWithExtensions};
+use anchor_spl::token_2022::spl_token_2022::state::Mint as SplMint;
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_2022_extensions::spl_token_metadata_interface::state::{Field, TokenMetadata};
+use anchor_spl::token_2022_extensions::{self, TokenMetadataUpdateField};
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::states::{BondingCurve, TokenInfo};
+
+/// Lets the creator fix the metadata URI while the token is on the curve,
+/// optionally locking metadata for good
+pub fn update_token_metadata(
+    ctx: Context<UpdateTokenMetadataCtx>,
+    token_id: u64,
+    uri: Option<String>,
+    lock_metadata: bool,
+) -> Result<()> {
+    let token_info = &mut ctx.accounts.token_info;
+    let bonding_curve = &ctx.accounts.bonding_curve;
+    let mint = &ctx.accounts.mint;
+
+    if token_info.launched_to_dex {
+        return Err(ErrorCode::TokenAlreadyLaunched.into());
+    }
+
+    if token_info.metadata_locked {
+        return Err(ErrorCode::MetadataLocked.into());
+    }
+
+    if let Some(uri) = uri {
+        if uri.len() > TOKEN_URI_MAX_LEN {
+            return Err(ErrorCode::TokenUriTooLong.into());
+        }
+
+        // Mints created before on-chain metadata only carry the URI in TokenInfo
+        let current_metadata = {
+            let mint_data = mint.try_borrow_data()?;
+            let mint_state = StateWithExtensions::<SplMint>::unpack(&mint_data)?;
+            mint_state.get_variable_len_extension::<TokenMetadata>().ok()
+        };
+
+        if let Some(mut metadata) = current_metadata {
+            // Fund any growth of the mint account before the token program reallocs it
+            let old_metadata_size = metadata.tlv_size_of()?;
+            metadata.uri = uri.clone();
+            let new_metadata_size = metadata.tlv_size_of()?;
+            let new_mint_len = mint
+                .data_len()
+                .checked_add(new_metadata_size)
+                .ok_or(ErrorCode::ArithmeticOverflow)?
+                .saturating_sub(old_metadata_size);
+            let required_lamports = Rent::get()?.minimum_balance(new_mint_len);
+            if required_lamports > mint.lamports() {
+                system_program::transfer(
+                    CpiContext::new(
+                        ctx.accounts.system_program.to_account_info(),
+                        system_program::Transfer {
+                            from: ctx.accounts.creator.to_account_info(),
+                            to: mint.to_account_info(),
+                        },
+                    ),
+                    required_lamports - mint.lamports(),
+                )?;
+            }
+
+            // bonding_curve PDA is the metadata update authority
+            let binding = token_id.to_le_bytes();
+            let bonding_curve_seeds: &[&[u8]] =
+                &[BONDING_CURVE_SEED, binding.as_ref(), &[bonding_curve.bump]];
+            token_2022_extensions::token_metadata_update_field(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    TokenMetadataUpdateField {
+                        program_id: ctx.accounts.token_program.to_account_info(),
+                        metadata: mint.to_account_info(),
+                        update_authority: bonding_curve.to_account_info(),
+                    },
+                    &[bonding_curve_seeds],
+                ),
+                Field::Uri,
+                uri.clone(),
+            )?;
+        }
+
+        msg!("Token URI updated: {}", uri);
+        token_info.uri = uri;
+    }
+
+    if lock_metadata {
+        token_info.metadata_locked = true;
+        msg!("Token metadata locked");
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(token_id: u64)]
+pub struct UpdateTokenMetadataCtx<'info> {
+    #[account(
+        mut,
+        seeds = [TOKEN_INFO_SEED, token_id.to_le_bytes().as_ref()],
+        bump = token_info.bump
+    )]
+    pub token_info: Account<'info, TokenInfo>,
+
+    #[account(
+        seeds = [BONDING_CURVE_SEED, token_id.to_le_bytes().as_ref()],
+        bump = bonding_curve.bump
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+
+    /// CHECK: Token-2022 mint, validated against `token_info.mint`.
+    #[account(mut, address = token_info.mint)]
+    pub mint: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        constraint = creator.key() == token_info.creator @ ErrorCode::Unauthorized
+    )]
+    pub creator: Signer<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
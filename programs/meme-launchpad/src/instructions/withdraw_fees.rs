@@ -1,32 +1,55 @@
 use anchor_lang::prelude::*;
-use crate::constants::PROGRAM_STATE_SEED;
+use crate::constants::{PLATFORM_FEE_VAULT_SEED, PROGRAM_STATE_SEED};
 use crate::errors::ErrorCode;
-use crate::states::ProgramState;
+use crate::states::{PlatformFeeVault, ProgramState};
 
 pub fn withdraw_platform_fees(
     ctx: Context<WithdrawPlatformFeesCtx>,
     amount: u64,
 ) -> Result<()> {
     let program_state = &mut ctx.accounts.program_state;
-    let authority = &ctx.accounts.authority;
+    let treasurer = &ctx.accounts.treasurer;
+    let vault_info = ctx.accounts.platform_fee_vault.to_account_info();
     let treasury = &ctx.accounts.treasury;
 
     // Check authorization
-    if program_state.platform_authority != authority.key() {
+    if program_state.treasurer != treasurer.key() {
         return Err(ErrorCode::Unauthorized.into());
     }
 
-    // Check available balance
-    let available_balance = **program_state.to_account_info().lamports.borrow();
+    if program_state.active_pause_flags(Clock::get()?.unix_timestamp).withdraw {
+        return Err(ErrorCode::ActionPaused.into());
+    }
+
+    // Check available balance (the vault keeps its rent-exempt reserve)
+    let rent_reserve = Rent::get()?.minimum_balance(vault_info.data_len());
+    let available_balance = vault_info.lamports().saturating_sub(rent_reserve);
     if amount > available_balance {
         return Err(ErrorCode::InsufficientSolBalance.into());
     }
 
-    // Transfer fees to treasury
-    **program_state.to_account_info().try_borrow_mut_lamports()? -= amount;
-    **treasury.to_account_info().try_borrow_mut_lamports()? += amount;
+    // Transfer fees to treasury (vault is owned by this program)
+    **vault_info.try_borrow_mut_lamports()? = vault_info
+        .lamports()
+        .checked_sub(amount)
+        .ok_or(ErrorCode::ArithmeticUnderflow)?;
+    **treasury.to_account_info().try_borrow_mut_lamports()? = treasury
+        .to_account_info()
+        .lamports()
+        .checked_add(amount)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    program_state.total_fees_withdrawn = program_state
+        .total_fees_withdrawn
+        .checked_add(amount)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
 
     msg!("Platform fees withdrawn: {} lamports", amount);
+    msg!(
+        "Fees collected: {}, withdrawn: {}",
+        program_state.total_fees_collected,
+        program_state.total_fees_withdrawn
+    );
 
     Ok(())
 }
@@ -41,9 +64,16 @@ pub struct WithdrawPlatformFeesCtx<'info> {
     pub program_state: Account<'info, ProgramState>,
     
     #[account(
-        constraint = authority.key() == program_state.platform_authority @ ErrorCode::Unauthorized
+        mut,
+        seeds = [PLATFORM_FEE_VAULT_SEED],
+        bump = platform_fee_vault.bump
+    )]
+    pub platform_fee_vault: Account<'info, PlatformFeeVault>,
+    
+    #[account(
+        constraint = treasurer.key() == program_state.treasurer @ ErrorCode::Unauthorized
     )]
-    pub authority: Signer<'info>,
+    pub treasurer: Signer<'info>,
     
     /// CHECK: Treasury account to receive fees
     #[account(
@@ -53,4 +83,4 @@ pub struct WithdrawPlatformFeesCtx<'info> {
     pub treasury: AccountInfo<'info>,
     
     pub system_program: Program<'info, System>,
-}
\ No newline at end of file
+}
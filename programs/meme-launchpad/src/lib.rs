@@ -2,12 +2,15 @@
 #![allow(deprecated)]
 use anchor_lang::prelude::*;
 
+pub mod adapters;
 pub mod constants;
 pub mod errors;
 pub mod instructions;
 pub mod states;
+pub mod utils;
 
 use instructions::*;
+use states::{GraduationTarget, PauseFlags, PlatformLimits, PlatformRole};
 
 declare_id!("aDkpBt58QzTwsFcThnKLfsb82WPDZrZmA5ERRdBu3ho");
 
@@ -15,8 +18,12 @@ declare_id!("aDkpBt58QzTwsFcThnKLfsb82WPDZrZmA5ERRdBu3ho");
 pub mod meme_launchpad {
     use super::*;
 
-    pub fn initialize(ctx: Context<InitializeCtx>) -> Result<()> {
-        instructions::initialize(ctx)
+    pub fn initialize(
+        ctx: Context<InitializeCtx>,
+        launch_threshold: u64,
+        limits: PlatformLimits,
+    ) -> Result<()> {
+        instructions::initialize(ctx, launch_threshold, limits)
     }
 
     pub fn create_token(
@@ -26,31 +33,241 @@ pub mod meme_launchpad {
         uri: String,
         decimals: u8,
         initial_supply: u64,
+        options: LaunchOptions,
     ) -> Result<()> {
-        instructions::create_token(ctx, name, symbol, uri, decimals, initial_supply)
+        instructions::create_token(ctx, name, symbol, uri, decimals, initial_supply, options)
     }
 
-    pub fn buy_token(ctx: Context<BuyTokenCtx>, token_id: u64, sol_amount: u64) -> Result<()> {
+    pub fn buy_token<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BuyTokenCtx<'info>>,
+        token_id: u64,
+        sol_amount: u64,
+    ) -> Result<()> {
         instructions::buy_token(ctx, token_id, sol_amount)
     }
 
-    pub fn sell_token(ctx: Context<SellTokenCtx>, token_id: u64, token_amount: u64) -> Result<()> {
+    pub fn sell_token<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SellTokenCtx<'info>>,
+        token_id: u64,
+        token_amount: u64,
+    ) -> Result<()> {
         instructions::sell_token(ctx, token_id, token_amount)
     }
 
-    pub fn launch_to_dex(
-        ctx: Context<LaunchToDexCtx>,
+    pub fn launch_to_dex<'info>(
+        ctx: Context<'_, '_, 'info, 'info, LaunchToDexCtx<'info>>,
         token_id: u64,
-        liquidity_amount: u64,
+        next_tx_id: u64,
     ) -> Result<()> {
-        instructions::launch_to_dex(ctx, token_id, liquidity_amount)
+        instructions::launch_to_dex(ctx, token_id, next_tx_id)
     }
 
-    pub fn update_platform_settings(
+    pub fn schedule_platform_settings(
         ctx: Context<UpdatePlatformSettingsCtx>,
         new_fee_rate: u64,
         new_launch_threshold: u64,
+        new_governance_delay: i64,
+    ) -> Result<()> {
+        instructions::schedule_platform_settings(
+            ctx,
+            new_fee_rate,
+            new_launch_threshold,
+            new_governance_delay,
+        )
+    }
+
+    pub fn execute_platform_settings(ctx: Context<UpdatePlatformSettingsCtx>) -> Result<()> {
+        instructions::execute_platform_settings(ctx)
+    }
+
+    pub fn cancel_platform_settings(ctx: Context<UpdatePlatformSettingsCtx>) -> Result<()> {
+        instructions::cancel_platform_settings(ctx)
+    }
+
+    pub fn schedule_platform_limits(
+        ctx: Context<UpdatePlatformLimitsCtx>,
+        new_limits: PlatformLimits,
+    ) -> Result<()> {
+        instructions::schedule_platform_limits(ctx, new_limits)
+    }
+
+    pub fn execute_platform_limits(ctx: Context<UpdatePlatformLimitsCtx>) -> Result<()> {
+        instructions::execute_platform_limits(ctx)
+    }
+
+    pub fn cancel_platform_limits(ctx: Context<UpdatePlatformLimitsCtx>) -> Result<()> {
+        instructions::cancel_platform_limits(ctx)
+    }
+
+    pub fn propose_platform_authority(
+        ctx: Context<ProposePlatformAuthorityCtx>,
+        new_authority: Pubkey,
+    ) -> Result<()> {
+        instructions::propose_platform_authority(ctx, new_authority)
+    }
+
+    pub fn accept_platform_authority(ctx: Context<AcceptPlatformAuthorityCtx>) -> Result<()> {
+        instructions::accept_platform_authority(ctx)
+    }
+
+    pub fn cancel_platform_authority_transfer(
+        ctx: Context<CancelPlatformAuthorityTransferCtx>,
     ) -> Result<()> {
-        instructions::update_platform_settings(ctx, new_fee_rate, new_launch_threshold)
+        instructions::cancel_platform_authority_transfer(ctx)
+    }
+
+    pub fn schedule_platform_treasury(
+        ctx: Context<UpdatePlatformTreasuryCtx>,
+        new_treasury: Pubkey,
+    ) -> Result<()> {
+        instructions::schedule_platform_treasury(ctx, new_treasury)
+    }
+
+    pub fn execute_platform_treasury(ctx: Context<UpdatePlatformTreasuryCtx>) -> Result<()> {
+        instructions::execute_platform_treasury(ctx)
+    }
+
+    pub fn cancel_platform_treasury(ctx: Context<UpdatePlatformTreasuryCtx>) -> Result<()> {
+        instructions::cancel_platform_treasury(ctx)
+    }
+
+    pub fn set_pause_flags(
+        ctx: Context<SetPauseFlagsCtx>,
+        pause_flags: PauseFlags,
+        reason_code: u16,
+        paused_until: Option<i64>,
+    ) -> Result<()> {
+        instructions::set_pause_flags(ctx, pause_flags, reason_code, paused_until)
+    }
+
+    pub fn withdraw_platform_fees(ctx: Context<WithdrawPlatformFeesCtx>, amount: u64) -> Result<()> {
+        instructions::withdraw_platform_fees(ctx, amount)
+    }
+
+    pub fn update_platform_role(
+        ctx: Context<UpdatePlatformRoleCtx>,
+        role: PlatformRole,
+        new_holder: Pubkey,
+    ) -> Result<()> {
+        instructions::update_platform_role(ctx, role, new_holder)
+    }
+
+    pub fn add_graduation_program(
+        ctx: Context<UpdateGraduationProgramsCtx>,
+        target: GraduationTarget,
+        program_id: Pubkey,
+    ) -> Result<()> {
+        instructions::add_graduation_program(ctx, target, program_id)
+    }
+
+    pub fn remove_graduation_program(
+        ctx: Context<UpdateGraduationProgramsCtx>,
+        program_id: Pubkey,
+    ) -> Result<()> {
+        instructions::remove_graduation_program(ctx, program_id)
+    }
+
+    pub fn retarget_graduation(
+        ctx: Context<RetargetGraduationCtx>,
+        token_id: u64,
+        target: GraduationTarget,
+        program_id: Pubkey,
+    ) -> Result<()> {
+        instructions::retarget_graduation(ctx, token_id, target, program_id)
+    }
+
+    pub fn update_launch_crank(
+        ctx: Context<UpdateLaunchCrankCtx>,
+        launcher_reward_bps: u64,
+        launch_grace_period: i64,
+    ) -> Result<()> {
+        instructions::update_launch_crank(ctx, launcher_reward_bps, launch_grace_period)
+    }
+
+    pub fn update_compliance_authority(
+        ctx: Context<UpdateComplianceAuthorityCtx>,
+        new_compliance_authority: Pubkey,
+    ) -> Result<()> {
+        instructions::update_compliance_authority(ctx, new_compliance_authority)
+    }
+
+    pub fn set_token_account_frozen(
+        ctx: Context<SetTokenAccountFrozenCtx>,
+        token_id: u64,
+        frozen: bool,
+    ) -> Result<()> {
+        instructions::set_token_account_frozen(ctx, token_id, frozen)
+    }
+
+    pub fn update_token_metadata(
+        ctx: Context<UpdateTokenMetadataCtx>,
+        token_id: u64,
+        uri: Option<String>,
+        lock_metadata: bool,
+    ) -> Result<()> {
+        instructions::update_token_metadata(ctx, token_id, uri, lock_metadata)
+    }
+
+    pub fn harvest_transfer_fees<'info>(
+        ctx: Context<'_, '_, 'info, 'info, HarvestTransferFeesCtx<'info>>,
+        token_id: u64,
+    ) -> Result<()> {
+        instructions::harvest_transfer_fees(ctx, token_id)
+    }
+
+    pub fn thaw_token_account(ctx: Context<ThawTokenAccountCtx>, token_id: u64) -> Result<()> {
+        instructions::thaw_token_account(ctx, token_id)
+    }
+
+    pub fn finalize_unlock<'info>(
+        ctx: Context<'_, '_, 'info, 'info, FinalizeUnlockCtx<'info>>,
+        token_id: u64,
+    ) -> Result<()> {
+        instructions::finalize_unlock(ctx, token_id)
+    }
+
+    pub fn refresh_holder_count(ctx: Context<RefreshHolderCountCtx>, token_id: u64) -> Result<()> {
+        instructions::refresh_holder_count(ctx, token_id)
+    }
+
+    pub fn claim_vested<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClaimVestedCtx<'info>>,
+        token_id: u64,
+    ) -> Result<()> {
+        instructions::claim_vested(ctx, token_id)
+    }
+
+    pub fn claim_airdrop<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClaimAirdropCtx<'info>>,
+        token_id: u64,
+        amount: u64,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::claim_airdrop(ctx, token_id, amount, proof)
+    }
+
+    pub fn claim_creator_fees(ctx: Context<ClaimCreatorFeesCtx>, token_id: u64) -> Result<()> {
+        instructions::claim_creator_fees(ctx, token_id)
+    }
+
+    pub fn halt_token_trading(
+        ctx: Context<ModerateTokenCtx>,
+        token_id: u64,
+        reason_code: u16,
+        allow_sells: bool,
+    ) -> Result<()> {
+        instructions::halt_token_trading(ctx, token_id, reason_code, allow_sells)
+    }
+
+    pub fn resume_token_trading(ctx: Context<ModerateTokenCtx>, token_id: u64) -> Result<()> {
+        instructions::resume_token_trading(ctx, token_id)
+    }
+
+    pub fn delete_token(ctx: Context<DeleteTokenCtx>, token_id: u64) -> Result<()> {
+        instructions::delete_token(ctx, token_id)
+    }
+
+    pub fn claim_locked_lp(ctx: Context<ClaimLockedLpCtx>, token_id: u64) -> Result<()> {
+        instructions::claim_locked_lp(ctx, token_id)
     }
 }
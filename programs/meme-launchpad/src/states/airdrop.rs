@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use solana_sha256_hasher as hash;
+
+#[account]
+#[derive(InitSpace)]
+pub struct Airdrop {
+    /// Associated token ID
+    pub token_id: u64,
+
+    /// Token mint address
+    pub mint: Pubkey,
+
+    /// Merkle root over `sha256(claimant || amount_le)` leaves, with sorted-pair hashing
+    pub merkle_root: [u8; 32],
+
+    /// Tokens allocated to the airdrop
+    pub total_amount: u64,
+
+    /// Tokens already claimed
+    pub claimed_amount: u64,
+
+    /// Escrow token account holding the unclaimed remainder after launch
+    pub token_account: Option<Pubkey>,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl Airdrop {
+    /// Whether `proof` shows `claimant` is owed `amount` under the committed root
+    pub fn verify_claim(&self, claimant: &Pubkey, amount: u64, proof: &[[u8; 32]]) -> bool {
+        let leaf = hash::hashv(&[claimant.as_ref(), &amount.to_le_bytes()]).to_bytes();
+        let root = proof.iter().fold(leaf, |node, sibling| {
+            if node <= *sibling {
+                hash::hashv(&[&node, sibling]).to_bytes()
+            } else {
+                hash::hashv(&[sibling, &node]).to_bytes()
+            }
+        });
+        root == self.merkle_root
+    }
+
+    /// Tokens not yet claimed
+    pub fn remaining_amount(&self) -> u64 {
+        self.total_amount.saturating_sub(self.claimed_amount)
+    }
+}
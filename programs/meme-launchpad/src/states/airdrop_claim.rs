@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+/// Receipt marking a claimant's airdrop as claimed
+#[account]
+#[derive(InitSpace)]
+pub struct AirdropClaim {
+    /// Associated token ID
+    pub token_id: u64,
+
+    /// Wallet that claimed
+    pub claimant: Pubkey,
+
+    /// Tokens claimed
+    pub amount: u64,
+
+    /// Claim timestamp
+    pub claimed_at: i64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
@@ -33,6 +33,12 @@ pub struct BondingCurve {
     /// Whether the curve is active
     pub active: bool,
     
+    /// Whether the curve reached the launch threshold (curve trading halted)
+    pub complete: bool,
+    
+    /// Timestamp at which the curve completed
+    pub completed_at: Option<i64>,
+    
     /// Last update timestamp
     pub last_updated: i64,
     
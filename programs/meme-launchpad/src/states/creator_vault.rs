@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+
+/// Per-token vault accruing creator trading fees as lamports above its rent reserve
+#[account]
+#[derive(InitSpace)]
+pub struct CreatorVault {
+    /// Associated token ID
+    pub token_id: u64,
+
+    /// Token creator, the only wallet that may claim
+    pub creator: Pubkey,
+
+    /// Creator fees claimed so far
+    pub fees_claimed: u64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct LiquidityPool {
+    /// Associated token ID
+    pub token_id: u64,
+
+    /// Token mint address
+    pub mint: Pubkey,
+
+    /// Token vault owned by the pool PDA
+    pub token_vault: Pubkey,
+
+    /// Real SOL reserves (held as lamports on the pool account)
+    pub sol_reserves: u64,
+
+    /// Real token reserves (held in the token vault)
+    pub token_reserves: u64,
+
+    /// Total SOL volume traded
+    pub total_sol_volume: u64,
+
+    /// Total token volume traded
+    pub total_token_volume: u64,
+
+    /// Pool creation timestamp
+    pub created_at: i64,
+
+    /// Last update timestamp
+    pub last_updated: i64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl LiquidityPool {
+    /// Calculate token output for given SOL input using the constant product formula
+    /// Uses the formula: token_out = token_reserves * sol_in / (sol_reserves + sol_in)
+    pub fn calculate_token_output(&self, sol_input: u64) -> Result<u64> {
+        Self::calculate_output(sol_input, self.sol_reserves, self.token_reserves)
+    }
+
+    /// Calculate SOL output for given token input using the constant product formula
+    /// Uses the formula: sol_out = sol_reserves * token_in / (token_reserves + token_in)
+    pub fn calculate_sol_output(&self, token_input: u64) -> Result<u64> {
+        Self::calculate_output(token_input, self.token_reserves, self.sol_reserves)
+    }
+
+    fn calculate_output(amount_in: u64, reserve_in: u64, reserve_out: u64) -> Result<u64> {
+        if amount_in == 0 {
+            return Ok(0);
+        }
+
+        let numerator = (reserve_out as u128)
+            .checked_mul(amount_in as u128)
+            .ok_or(crate::errors::ErrorCode::ArithmeticOverflow)?;
+
+        let denominator = (reserve_in as u128)
+            .checked_add(amount_in as u128)
+            .ok_or(crate::errors::ErrorCode::ArithmeticOverflow)?;
+
+        if denominator == 0 {
+            return Err(crate::errors::ErrorCode::DivisionByZero.into());
+        }
+
+        let amount_out = numerator
+            .checked_div(denominator)
+            .ok_or(crate::errors::ErrorCode::DivisionByZero)?;
+
+        Ok(amount_out as u64)
+    }
+
+    /// Update reserves after a buy transaction
+    pub fn update_reserves_buy(&mut self, sol_input: u64, token_output: u64) -> Result<()> {
+        self.sol_reserves = self.sol_reserves
+            .checked_add(sol_input)
+            .ok_or(crate::errors::ErrorCode::ArithmeticOverflow)?;
+
+        self.token_reserves = self.token_reserves
+            .checked_sub(token_output)
+            .ok_or(crate::errors::ErrorCode::ArithmeticUnderflow)?;
+
+        Ok(())
+    }
+
+    /// Update reserves after a sell transaction
+    pub fn update_reserves_sell(&mut self, token_input: u64, sol_output: u64) -> Result<()> {
+        self.token_reserves = self.token_reserves
+            .checked_add(token_input)
+            .ok_or(crate::errors::ErrorCode::ArithmeticOverflow)?;
+
+        self.sol_reserves = self.sol_reserves
+            .checked_sub(sol_output)
+            .ok_or(crate::errors::ErrorCode::ArithmeticUnderflow)?;
+
+        Ok(())
+    }
+}
@@ -3,8 +3,20 @@ pub mod program_state;
 pub mod token_info;
 pub mod bonding_curve;
 pub mod transaction;
+pub mod liquidity_pool;
+pub mod vesting_escrow;
+pub mod airdrop;
+pub mod airdrop_claim;
+pub mod creator_vault;
+pub mod platform_fee_vault;
 
 pub use program_state::*;
 pub use token_info::*;
 pub use bonding_curve::*;
-pub use transaction::*;
\ No newline at end of file
+pub use transaction::*;
+pub use liquidity_pool::*;
+pub use vesting_escrow::*;
+pub use airdrop::*;
+pub use airdrop_claim::*;
+pub use creator_vault::*;
+pub use platform_fee_vault::*;
\ No newline at end of file
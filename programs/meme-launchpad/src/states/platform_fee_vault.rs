@@ -0,0 +1,9 @@
+use anchor_lang::prelude::*;
+
+/// Singleton vault holding collected platform fees as lamports above its rent reserve
+#[account]
+#[derive(InitSpace)]
+pub struct PlatformFeeVault {
+    /// Bump seed for PDA
+    pub bump: u8,
+}
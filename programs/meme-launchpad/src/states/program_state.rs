@@ -1,5 +1,130 @@
 use anchor_lang::prelude::*;
 
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::states::GraduationTarget;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub struct GraduationProgram {
+    /// Kind of AMM the program implements
+    pub target: GraduationTarget,
+
+    /// Allowlisted AMM program ID
+    pub program_id: Pubkey,
+}
+
+/// Delegated admin roles, each assigned by the platform authority
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum PlatformRole {
+    /// Schedules fee rate / launch threshold changes and tunes the launch crank
+    FeeManager,
+    /// Sets the platform pause flags
+    Pauser,
+    /// Halts and resumes trading on individual tokens
+    Moderator,
+    /// Schedules treasury changes and withdraws platform fees
+    Treasurer,
+}
+
+/// Platform actions that can be paused independently
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Default)]
+pub struct PauseFlags {
+    /// Token creation
+    pub create: bool,
+    /// Buys on the curve and in the built-in pool
+    pub buy: bool,
+    /// Sells on the curve and in the built-in pool
+    pub sell: bool,
+    /// Launches to DEX
+    pub launch: bool,
+    /// Platform fee withdrawals and creator fee claims
+    pub withdraw: bool,
+}
+
+/// Trading and launch limits adjustable without a redeploy
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub struct PlatformLimits {
+    /// Minimum SOL per buy, in lamports
+    pub min_token_purchase: u64,
+    /// Maximum SOL per buy, in lamports
+    pub max_token_purchase: u64,
+    /// Seconds a token must trade before it can launch
+    pub min_trading_time: i64,
+    /// Share of curve reserves seeded as DEX liquidity at launch, in basis points (the rest is the launch fee)
+    pub launch_liquidity_bps: u64,
+    /// Highest platform fee rate the fee manager may set, in basis points
+    pub max_platform_fee_rate: u64,
+    /// Lowest launch threshold the fee manager may set, in lamports
+    pub min_launch_threshold: u64,
+}
+
+impl Default for PlatformLimits {
+    fn default() -> Self {
+        Self {
+            min_token_purchase: MIN_TOKEN_PURCHASE,
+            max_token_purchase: MAX_TOKEN_PURCHASE,
+            min_trading_time: MIN_TRADING_TIME,
+            launch_liquidity_bps: LAUNCH_LIQUIDITY_BPS,
+            max_platform_fee_rate: MAX_PLATFORM_FEE_RATE,
+            min_launch_threshold: MIN_LAUNCH_THRESHOLD,
+        }
+    }
+}
+
+impl PlatformLimits {
+    /// Checks the limits against the program's hard bounds
+    pub fn validate(&self) -> Result<()> {
+        let valid = self.min_token_purchase >= MIN_TOKEN_PURCHASE_FLOOR
+            && self.min_token_purchase <= self.max_token_purchase
+            && self.max_token_purchase <= MAX_TOKEN_PURCHASE_CEILING
+            && (0..=MAX_MIN_TRADING_TIME).contains(&self.min_trading_time)
+            && (MIN_LAUNCH_LIQUIDITY_BPS..=10000).contains(&self.launch_liquidity_bps)
+            && self.max_platform_fee_rate <= PLATFORM_FEE_RATE_CEILING
+            && self.min_launch_threshold >= LAUNCH_THRESHOLD_FLOOR;
+        if !valid {
+            return Err(ErrorCode::InvalidPlatformLimits.into());
+        }
+
+        Ok(())
+    }
+}
+
+/// Platform limits change queued behind the governance delay
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub struct PendingPlatformLimits {
+    /// New platform limits
+    pub limits: PlatformLimits,
+
+    /// Timestamp from which the change can be executed
+    pub executable_at: i64,
+}
+
+/// Platform settings change queued behind the governance delay
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub struct PendingPlatformSettings {
+    /// New platform fee rate in basis points
+    pub fee_rate: u64,
+
+    /// New launch threshold in lamports
+    pub launch_threshold: u64,
+
+    /// New governance delay in seconds
+    pub governance_delay: i64,
+
+    /// Timestamp from which the change can be executed
+    pub executable_at: i64,
+}
+
+/// Treasury change queued behind the governance delay
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub struct PendingTreasury {
+    /// New platform treasury address
+    pub treasury: Pubkey,
+
+    /// Timestamp from which the change can be executed
+    pub executable_at: i64,
+}
+
 #[account]
 #[derive(InitSpace)]
 pub struct ProgramState {
@@ -15,21 +140,105 @@ pub struct ProgramState {
     /// Threshold amount to launch token to DEX (in lamports)
     pub launch_threshold: u64,
     
+    /// Share of the platform launch fee paid to the launcher, in basis points
+    pub launcher_reward_bps: u64,
+    
+    /// Seconds after curve completion during which only the creator or authority may launch
+    pub launch_grace_period: i64,
+    
     /// Platform authority address
     pub platform_authority: Pubkey,
     
+    /// Proposed platform authority awaiting acceptance
+    pub pending_platform_authority: Option<Pubkey>,
+    
+    /// Role allowed to change fee rate, launch threshold and launch crank settings
+    pub fee_manager: Pubkey,
+    
+    /// Role allowed to set the platform pause flags
+    pub pauser: Pubkey,
+    
+    /// Role allowed to halt and resume trading on individual tokens
+    pub moderator: Pubkey,
+    
+    /// Role allowed to change the treasury and withdraw platform fees
+    pub treasurer: Pubkey,
+    
     /// Platform treasury address for collecting fees
     pub platform_treasury: Pubkey,
     
-    /// Total platform fees collected
+    /// Seconds a scheduled settings or treasury change waits before it can be executed
+    pub governance_delay: i64,
+    
+    /// Scheduled fee rate / launch threshold / governance delay change
+    pub pending_settings: Option<PendingPlatformSettings>,
+    
+    /// Scheduled treasury change
+    pub pending_treasury: Option<PendingTreasury>,
+    
+    /// Purchase, launch and settings limits
+    pub limits: PlatformLimits,
+    
+    /// Scheduled limits change
+    pub pending_limits: Option<PendingPlatformLimits>,
+    
+    /// Compliance authority that may freeze accounts of opted-in tokens
+    pub compliance_authority: Pubkey,
+    
+    /// Total platform fees collected into the platform fee vault
     pub total_fees_collected: u64,
     
-    /// Emergency pause flag
-    pub is_paused: bool,
+    /// Total platform fees withdrawn to the treasury
+    pub total_fees_withdrawn: u64,
+    
+    /// AMM programs tokens may graduate to
+    #[max_len(8)]
+    pub graduation_programs: Vec<GraduationProgram>,
+    
+    /// Paused platform actions
+    pub pause_flags: PauseFlags,
+    
+    /// Off-chain defined reason code for the current pause
+    pub pause_reason: u16,
+    
+    /// Timestamp from which the pause flags lapse automatically (`None` = until cleared)
+    pub paused_until: Option<i64>,
     
     /// Timestamp when the program was initialized
     pub initialized_at: i64,
     
     /// Bump seed for PDA
     pub bump: u8,
+}
+
+impl ProgramState {
+    /// Pause flags in effect at `now`, treating a lapsed scheduled pause as cleared
+    pub fn active_pause_flags(&self, now: i64) -> PauseFlags {
+        match self.paused_until {
+            Some(paused_until) if now >= paused_until => PauseFlags::default(),
+            _ => self.pause_flags,
+        }
+    }
+
+    /// Current holder of `role`
+    pub fn role_holder(&self, role: PlatformRole) -> Pubkey {
+        match role {
+            PlatformRole::FeeManager => self.fee_manager,
+            PlatformRole::Pauser => self.pauser,
+            PlatformRole::Moderator => self.moderator,
+            PlatformRole::Treasurer => self.treasurer,
+        }
+    }
+
+    /// Whether `program_id` is allowlisted for the given graduation target.
+    /// The built-in pool is always served by this program.
+    pub fn is_graduation_program_allowed(&self, target: GraduationTarget, program_id: &Pubkey) -> bool {
+        if target == GraduationTarget::BuiltinPool {
+            return *program_id == crate::ID;
+        }
+
+        self.graduation_programs
+            .iter()
+            .any(|entry| entry.target == target && entry.program_id == *program_id)
+    }
 }
\ No newline at end of file
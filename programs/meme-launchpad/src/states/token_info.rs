@@ -1,4 +1,72 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022::extension::transfer_fee::TransferFeeConfig;
+use anchor_spl::token_2022::spl_token_2022::extension::{BaseStateWithExtensions, StateWithExtensions};
+use anchor_spl::token_2022::spl_token_2022::state::Mint as SplMint;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum GraduationTarget {
+    /// Seed an external constant-product AMM pool
+    ConstantProductAmm,
+    /// Keep trading in this program's own liquidity pool
+    BuiltinPool,
+    /// Open a full-range position on an external concentrated-liquidity AMM
+    ConcentratedLiquidity,
+}
+
+impl GraduationTarget {
+    /// Whether the target can honour `lock`. Concentrated-liquidity positions issue no
+    /// LP tokens and stay with the launch escrow, so only a permanent lock applies.
+    pub fn supports_liquidity_lock(&self, lock: LiquidityLock) -> bool {
+        match self {
+            GraduationTarget::ConcentratedLiquidity => {
+                lock == LiquidityLock::Lock { unlock_at: None }
+            }
+            _ => true,
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum LiquidityLock {
+    /// Burn the LP tokens at launch
+    Burn,
+    /// Keep the LP tokens in the launch escrow PDA, claimable by the creator after `unlock_at`
+    /// and at least `MIN_LP_LOCK_DURATION` after launch (locked forever if `None`)
+    Lock { unlock_at: Option<i64> },
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Default)]
+pub enum MintAuthorityOutcome {
+    /// Revoke the mint authority at launch, fixing the supply
+    #[default]
+    Revoke,
+    /// Hand the mint authority to the token creator
+    Creator,
+    /// Hand the mint authority to a DAO or multisig address
+    Dao { authority: Pubkey },
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Default)]
+pub enum FreezeAuthorityPolicy {
+    /// No freeze authority on the mint
+    #[default]
+    Disabled,
+    /// Bonding curve PDA holds the freeze authority until launch, then it is revoked
+    BondingCurveUntilLaunch,
+    /// Platform compliance authority holds the freeze authority permanently
+    Compliance,
+}
+
+/// Moderator-imposed trading halt on a single token
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub struct TradingHalt {
+    /// Off-chain defined reason code (e.g. suspected scam, legal request)
+    pub reason_code: u16,
+    /// Timestamp the halt was imposed
+    pub halted_at: i64,
+    /// Whether holders may still sell while buys are halted
+    pub allow_sells: bool,
+}
 
 #[account]
 #[derive(InitSpace)]
@@ -38,11 +106,65 @@ pub struct TokenInfo {
     
     /// Launch timestamp
     pub launched_at: Option<i64>,
+
+    /// Tokens reserved from total supply for DEX liquidity
+    pub liquidity_token_reserve: u64,
+
+    /// Tokens reserved from total supply for the creator's vesting escrow
+    pub creator_allocation: u64,
+
+    /// Tokens reserved from total supply for the Merkle airdrop
+    pub airdrop_allocation: u64,
+
+    /// Where liquidity goes at launch
+    pub graduation_target: GraduationTarget,
+
+    /// AMM program receiving liquidity (this program for the built-in pool)
+    pub graduation_program: Pubkey,
+
+    /// DEX pool created at launch
+    pub dex_pool: Option<Pubkey>,
+
+    /// What happens to the LP tokens received at launch
+    pub liquidity_lock: LiquidityLock,
+
+    /// LP token mint of the DEX pool
+    pub lp_mint: Option<Pubkey>,
+
+    /// LP tokens received at launch
+    pub lp_token_amount: u64,
+
+    /// Escrow token account holding locked LP tokens (None if burned or claimed)
+    pub lp_token_account: Option<Pubkey>,
+
+    /// Who holds the mint authority after launch
+    pub mint_authority_outcome: MintAuthorityOutcome,
+
+    /// Who holds the freeze authority
+    pub freeze_authority_policy: FreezeAuthorityPolicy,
+
+    /// Whether the creator permanently locked the metadata
+    pub metadata_locked: bool,
+
+    /// Token-2022 transfer fee in basis points, withheld for the creator (None if disabled)
+    pub transfer_fee_bps: Option<u16>,
+
+    /// Whether holder accounts stay frozen (only curve trades move tokens) until launch.
+    /// After launch holders are thawed with `thaw_token_account` or by the moderator's
+    /// `finalize_unlock`, which then revokes the bonding curve's freeze authority unless the policy is `Compliance`
+    pub transfer_locked_until_launch: bool,
+
+    /// Transfer-locked holder accounts that are frozen while holding tokens
+    pub frozen_holder_accounts: u64,
+
+    /// Whether the holder tracker transfer hook keeps `holder_count` up to date
+    pub holder_tracking: bool,
     
     /// Total SOL raised
     pub total_sol_raised: u64,
     
-    /// Number of unique holders
+    /// Token accounts holding the token, as last read from the holder tracker
+    /// (only maintained for tokens with holder tracking, see `refresh_holder_count`)
     pub holder_count: u64,
     
     /// Number of transactions
@@ -54,9 +176,56 @@ pub struct TokenInfo {
     /// Whether trading is active
     pub trading_active: bool,
     
+    /// Moderation halt, if trading was halted by the moderator
+    pub trading_halt: Option<TradingHalt>,
+    
     /// Creator fees collected
     pub creator_fees_collected: u64,
     
     /// Bump seed for PDA
     pub bump: u8,
-}
\ No newline at end of file
+}
+
+impl TokenInfo {
+    /// Whether sells go through: trading is active, or halted with sells allowed
+    pub fn sells_allowed(&self) -> bool {
+        self.trading_active || self.trading_halt.is_some_and(|halt| halt.allow_sells)
+    }
+
+    /// Count a transfer-locked holder account entering or leaving the frozen-with-balance state
+    pub fn track_frozen_holder(&mut self, was_frozen: bool, is_frozen: bool) -> Result<()> {
+        match (was_frozen, is_frozen) {
+            (false, true) => {
+                self.frozen_holder_accounts = self
+                    .frozen_holder_accounts
+                    .checked_add(1)
+                    .ok_or(crate::errors::ErrorCode::ArithmeticOverflow)?;
+            }
+            (true, false) => {
+                self.frozen_holder_accounts = self
+                    .frozen_holder_accounts
+                    .checked_sub(1)
+                    .ok_or(crate::errors::ErrorCode::ArithmeticUnderflow)?;
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    /// Token-2022 transfer fee withheld when moving `amount` of this token
+    pub fn transfer_fee(&self, mint: &AccountInfo, amount: u64) -> Result<u64> {
+        if self.transfer_fee_bps.is_none() {
+            return Ok(0);
+        }
+
+        let mint_data = mint.try_borrow_data()?;
+        let mint_state = StateWithExtensions::<SplMint>::unpack(&mint_data)?;
+        let fee_config = mint_state.get_extension::<TransferFeeConfig>()?;
+        let fee = fee_config
+            .calculate_epoch_fee(Clock::get()?.epoch, amount)
+            .ok_or(crate::errors::ErrorCode::ArithmeticOverflow)?;
+
+        Ok(fee)
+    }
+}
@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+
+#[account]
+#[derive(InitSpace)]
+pub struct VestingEscrow {
+    /// Associated token ID
+    pub token_id: u64,
+
+    /// Token mint address
+    pub mint: Pubkey,
+
+    /// Wallet the allocation vests to (the token creator)
+    pub beneficiary: Pubkey,
+
+    /// Escrow token account holding the unclaimed allocation
+    pub token_account: Pubkey,
+
+    /// Total tokens allocated
+    pub total_amount: u64,
+
+    /// Tokens already claimed
+    pub claimed_amount: u64,
+
+    /// Vesting start timestamp (token creation)
+    pub start_time: i64,
+
+    /// Nothing is claimable before this timestamp
+    pub cliff_time: i64,
+
+    /// Everything is claimable from this timestamp
+    pub end_time: i64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl VestingEscrow {
+    /// Tokens vested at `now`: nothing before the cliff, then linear from `start_time` to `end_time`
+    pub fn vested_amount(&self, now: i64) -> Result<u64> {
+        if now < self.cliff_time {
+            return Ok(0);
+        }
+        if now >= self.end_time {
+            return Ok(self.total_amount);
+        }
+
+        let elapsed = now
+            .checked_sub(self.start_time)
+            .ok_or(ErrorCode::ArithmeticUnderflow)? as u128;
+        let duration = self
+            .end_time
+            .checked_sub(self.start_time)
+            .ok_or(ErrorCode::ArithmeticUnderflow)? as u128;
+
+        let vested = (self.total_amount as u128)
+            .checked_mul(elapsed)
+            .ok_or(ErrorCode::ArithmeticOverflow)?
+            .checked_div(duration)
+            .ok_or(ErrorCode::DivisionByZero)?;
+
+        u64::try_from(vested).map_err(|_| ErrorCode::ArithmeticOverflow.into())
+    }
+
+    /// Vested tokens not yet claimed
+    pub fn claimable_amount(&self, now: i64) -> Result<u64> {
+        self.vested_amount(now)?
+            .checked_sub(self.claimed_amount)
+            .ok_or(ErrorCode::ArithmeticUnderflow.into())
+    }
+}
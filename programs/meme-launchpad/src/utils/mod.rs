@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022::onchain::invoke_transfer_checked;
+use anchor_spl::token_2022::TransferChecked;
+use holder_tracker::constants::HOLDER_STATS_SEED;
+use holder_tracker::states::HolderStats;
+
+use crate::errors::ErrorCode;
+
+/// `transfer_checked` that forwards the CPI's remaining accounts to the mint's
+/// transfer hook, if it has one
+pub fn transfer_checked_with_hook<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, TransferChecked<'info>>,
+    amount: u64,
+    decimals: u8,
+) -> Result<()> {
+    invoke_transfer_checked(
+        ctx.program.key,
+        ctx.accounts.from,
+        ctx.accounts.mint,
+        ctx.accounts.to,
+        ctx.accounts.authority,
+        &ctx.remaining_accounts,
+        amount,
+        decimals,
+        ctx.signer_seeds,
+    )
+    .map_err(Into::into)
+}
+
+/// Reads the mint's holder count from its holder stats
+pub fn holder_count(holder_stats: &AccountInfo, mint: &Pubkey) -> Result<u64> {
+    let (expected_stats, _) =
+        Pubkey::find_program_address(&[HOLDER_STATS_SEED, mint.as_ref()], &holder_tracker::ID);
+    if holder_stats.key() != expected_stats {
+        return Err(ErrorCode::InvalidHolderStats.into());
+    }
+
+    let holder_stats = HolderStats::try_deserialize(&mut &holder_stats.try_borrow_data()?[..])?;
+    Ok(holder_stats.holder_count)
+}
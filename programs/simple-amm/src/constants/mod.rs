@@ -0,0 +1,10 @@
+pub const ANCHOR_DISCRIMINATOR_SIZE: usize = 8;
+
+// Swap fee
+pub const SWAP_FEE_BPS: u64 = 30; // 0.3% in basis points
+pub const BPS_DENOMINATOR: u64 = 10_000;
+
+// Seeds
+pub const POOL_SEED: &[u8] = b"pool";
+pub const LP_MINT_SEED: &[u8] = b"lp_mint";
+pub const TOKEN_VAULT_SEED: &[u8] = b"token_vault";
@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Invalid liquidity amount")]
+    InvalidLiquidityAmount,
+
+    #[msg("Invalid swap amount")]
+    InvalidSwapAmount,
+
+    #[msg("Slippage tolerance exceeded")]
+    SlippageExceeded,
+
+    #[msg("Insufficient pool reserves")]
+    InsufficientReserves,
+
+    #[msg("Arithmetic overflow")]
+    ArithmeticOverflow,
+
+    #[msg("Arithmetic underflow")]
+    ArithmeticUnderflow,
+
+    #[msg("Division by zero")]
+    DivisionByZero,
+}
@@ -0,0 +1,171 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{
+    self, Mint, MintTo, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::states::Pool;
+use crate::utils::transfer_checked_with_hook;
+
+pub fn initialize_pool<'info>(
+    ctx: Context<'_, '_, 'info, 'info, InitializePoolCtx<'info>>,
+    sol_amount: u64,
+    token_amount: u64,
+) -> Result<()> {
+    if sol_amount == 0 || token_amount == 0 {
+        return Err(ErrorCode::InvalidLiquidityAmount.into());
+    }
+
+    // Deposit SOL into the pool account
+    let transfer_accounts = system_program::Transfer {
+        from: ctx.accounts.depositor.to_account_info(),
+        to: ctx.accounts.pool.to_account_info(),
+    };
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            transfer_accounts,
+        ),
+        sol_amount,
+    )?;
+
+    // Deposit tokens into the pool vault
+    let transfer_accounts = TransferChecked {
+        from: ctx.accounts.depositor_token_account.to_account_info(),
+        mint: ctx.accounts.token_mint.to_account_info(),
+        to: ctx.accounts.token_vault.to_account_info(),
+        authority: ctx.accounts.depositor.to_account_info(),
+    };
+    transfer_checked_with_hook(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            transfer_accounts,
+        )
+        .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+        token_amount,
+        ctx.accounts.token_mint.decimals,
+    )?;
+
+    // Tokens with a transfer fee deliver less than `token_amount` to the vault
+    ctx.accounts.token_vault.reload()?;
+    let token_amount = ctx.accounts.token_vault.amount;
+
+    let lp_amount = Pool::calculate_initial_lp(sol_amount, token_amount)?;
+    if lp_amount == 0 {
+        return Err(ErrorCode::InvalidLiquidityAmount.into());
+    }
+
+    // Mint LP tokens to the depositor (pool PDA is signer)
+    let token_mint_key = ctx.accounts.token_mint.key();
+    let creator_key = ctx.accounts.depositor.key();
+    let pool_seeds: &[&[u8]] = &[
+        POOL_SEED,
+        token_mint_key.as_ref(),
+        creator_key.as_ref(),
+        &[ctx.bumps.pool],
+    ];
+    let signer_seeds = &[pool_seeds];
+
+    let mint_accounts = MintTo {
+        mint: ctx.accounts.lp_mint.to_account_info(),
+        to: ctx.accounts.depositor_lp_account.to_account_info(),
+        authority: ctx.accounts.pool.to_account_info(),
+    };
+    token_interface::mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            mint_accounts,
+            signer_seeds,
+        ),
+        lp_amount,
+    )?;
+
+    let pool = &mut ctx.accounts.pool;
+    pool.token_mint = token_mint_key;
+    pool.creator = creator_key;
+    pool.lp_mint = ctx.accounts.lp_mint.key();
+    pool.token_vault = ctx.accounts.token_vault.key();
+    pool.sol_reserves = sol_amount;
+    pool.token_reserves = token_amount;
+    pool.lp_supply = lp_amount;
+    pool.created_at = Clock::get()?.unix_timestamp;
+    pool.bump = ctx.bumps.pool;
+
+    msg!("Pool initialized for mint: {}", token_mint_key);
+    msg!("Pool creator: {}", creator_key);
+    msg!("SOL reserves: {}", sol_amount);
+    msg!("Token reserves: {}", token_amount);
+    msg!("LP minted: {}", lp_amount);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializePoolCtx<'info> {
+    /// Pool PDA, namespaced by the depositor so a third party cannot claim
+    /// another creator's pool address ahead of them
+    #[account(
+        init,
+        payer = payer,
+        space = ANCHOR_DISCRIMINATOR_SIZE + Pool::INIT_SPACE,
+        seeds = [POOL_SEED, token_mint.key().as_ref(), depositor.key().as_ref()],
+        bump
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = payer,
+        seeds = [LP_MINT_SEED, pool.key().as_ref()],
+        bump,
+        mint::decimals = token_mint.decimals,
+        mint::authority = pool,
+        mint::token_program = token_program
+    )]
+    pub lp_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = payer,
+        seeds = [TOKEN_VAULT_SEED, pool.key().as_ref()],
+        bump,
+        token::mint = token_mint,
+        token::authority = pool,
+        token::token_program = token_program
+    )]
+    pub token_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Liquidity provider supplying the SOL and tokens
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::authority = depositor,
+        token::token_program = token_program
+    )]
+    pub depositor_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = lp_mint,
+        associated_token::authority = depositor,
+        associated_token::token_program = token_program
+    )]
+    pub depositor_lp_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Pays rent for the pool accounts
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,7 @@
+pub mod initialize_pool;
+pub mod swap;
+pub mod remove_liquidity;
+
+pub use initialize_pool::*;
+pub use swap::*;
+pub use remove_liquidity::*;
@@ -0,0 +1,144 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    self, Burn, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::states::Pool;
+use crate::utils::transfer_checked_with_hook;
+
+pub fn remove_liquidity<'info>(
+    ctx: Context<'_, '_, 'info, 'info, RemoveLiquidityCtx<'info>>,
+    lp_amount: u64,
+    minimum_sol_out: u64,
+    minimum_token_out: u64,
+) -> Result<()> {
+    if lp_amount == 0 {
+        return Err(ErrorCode::InvalidLiquidityAmount.into());
+    }
+
+    let pool = &ctx.accounts.pool;
+    let sol_out = pool.calculate_withdraw_share(lp_amount, pool.sol_reserves)?;
+    let token_out = pool.calculate_withdraw_share(lp_amount, pool.token_reserves)?;
+
+    if sol_out < minimum_sol_out || token_out < minimum_token_out {
+        return Err(ErrorCode::SlippageExceeded.into());
+    }
+
+    // Burn LP tokens from the provider
+    let burn_accounts = Burn {
+        mint: ctx.accounts.lp_mint.to_account_info(),
+        from: ctx.accounts.user_lp_account.to_account_info(),
+        authority: ctx.accounts.user.to_account_info(),
+    };
+    token_interface::burn(
+        CpiContext::new(ctx.accounts.token_program.to_account_info(), burn_accounts),
+        lp_amount,
+    )?;
+
+    // Tokens out: vault -> user (pool PDA is signer)
+    let token_mint_key = ctx.accounts.token_mint.key();
+    let pool_seeds: &[&[u8]] = &[
+        POOL_SEED,
+        token_mint_key.as_ref(),
+        pool.creator.as_ref(),
+        &[pool.bump],
+    ];
+    let signer_seeds = &[pool_seeds];
+
+    let transfer_accounts = TransferChecked {
+        from: ctx.accounts.token_vault.to_account_info(),
+        mint: ctx.accounts.token_mint.to_account_info(),
+        to: ctx.accounts.user_token_account.to_account_info(),
+        authority: ctx.accounts.pool.to_account_info(),
+    };
+    transfer_checked_with_hook(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            transfer_accounts,
+            signer_seeds,
+        )
+        .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+        token_out,
+        ctx.accounts.token_mint.decimals,
+    )?;
+
+    // SOL out: pool -> user
+    **ctx.accounts.pool.to_account_info().try_borrow_mut_lamports()? = ctx
+        .accounts
+        .pool
+        .to_account_info()
+        .lamports()
+        .checked_sub(sol_out)
+        .ok_or(ErrorCode::ArithmeticUnderflow)?;
+    **ctx.accounts.user.to_account_info().try_borrow_mut_lamports()? = ctx
+        .accounts
+        .user
+        .to_account_info()
+        .lamports()
+        .checked_add(sol_out)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+    let pool = &mut ctx.accounts.pool;
+    pool.sol_reserves = pool
+        .sol_reserves
+        .checked_sub(sol_out)
+        .ok_or(ErrorCode::ArithmeticUnderflow)?;
+    pool.token_reserves = pool
+        .token_reserves
+        .checked_sub(token_out)
+        .ok_or(ErrorCode::ArithmeticUnderflow)?;
+    pool.lp_supply = pool
+        .lp_supply
+        .checked_sub(lp_amount)
+        .ok_or(ErrorCode::ArithmeticUnderflow)?;
+
+    msg!("Liquidity removed!");
+    msg!("LP burned: {}", lp_amount);
+    msg!("SOL out: {}", sol_out);
+    msg!("Token out: {}", token_out);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RemoveLiquidityCtx<'info> {
+    #[account(
+        mut,
+        seeds = [POOL_SEED, token_mint.key().as_ref(), pool.creator.as_ref()],
+        bump = pool.bump
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    #[account(address = pool.token_mint)]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut, address = pool.lp_mint)]
+    pub lp_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut, address = pool.token_vault)]
+    pub token_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        token::mint = lp_mint,
+        token::authority = user,
+        token::token_program = token_program
+    )]
+    pub user_lp_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::authority = user,
+        token::token_program = token_program
+    )]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,189 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked};
+
+use crate::constants::*;
+use crate::errors::ErrorCode;
+use crate::states::Pool;
+use crate::utils::transfer_checked_with_hook;
+
+pub fn swap<'info>(
+    ctx: Context<'_, '_, 'info, 'info, SwapCtx<'info>>,
+    amount_in: u64,
+    minimum_amount_out: u64,
+    sol_to_token: bool,
+) -> Result<()> {
+    if amount_in == 0 {
+        return Err(ErrorCode::InvalidSwapAmount.into());
+    }
+
+    let pool = &ctx.accounts.pool;
+    let token_mint_key = ctx.accounts.token_mint.key();
+    let pool_seeds: &[&[u8]] = &[
+        POOL_SEED,
+        token_mint_key.as_ref(),
+        pool.creator.as_ref(),
+        &[pool.bump],
+    ];
+    let signer_seeds = &[pool_seeds];
+
+    let (amount_in, amount_out) = if sol_to_token {
+        let amount_out =
+            Pool::calculate_swap_output(amount_in, pool.sol_reserves, pool.token_reserves)?;
+        check_swap_output(amount_out, minimum_amount_out, pool.token_reserves)?;
+
+        // SOL in: user -> pool
+        let transfer_accounts = system_program::Transfer {
+            from: ctx.accounts.user.to_account_info(),
+            to: ctx.accounts.pool.to_account_info(),
+        };
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                transfer_accounts,
+            ),
+            amount_in,
+        )?;
+
+        // Tokens out: vault -> user (pool PDA is signer)
+        let transfer_accounts = TransferChecked {
+            from: ctx.accounts.token_vault.to_account_info(),
+            mint: ctx.accounts.token_mint.to_account_info(),
+            to: ctx.accounts.user_token_account.to_account_info(),
+            authority: ctx.accounts.pool.to_account_info(),
+        };
+        transfer_checked_with_hook(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                transfer_accounts,
+                signer_seeds,
+            )
+            .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+            amount_out,
+            ctx.accounts.token_mint.decimals,
+        )?;
+
+        (amount_in, amount_out)
+    } else {
+        // Tokens in: user -> vault
+        let vault_balance_before = ctx.accounts.token_vault.amount;
+        let transfer_accounts = TransferChecked {
+            from: ctx.accounts.user_token_account.to_account_info(),
+            mint: ctx.accounts.token_mint.to_account_info(),
+            to: ctx.accounts.token_vault.to_account_info(),
+            authority: ctx.accounts.user.to_account_info(),
+        };
+        transfer_checked_with_hook(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                transfer_accounts,
+            )
+            .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+            amount_in,
+            ctx.accounts.token_mint.decimals,
+        )?;
+
+        // Price on what the vault received (tokens with a transfer fee deliver less)
+        ctx.accounts.token_vault.reload()?;
+        let amount_received = ctx
+            .accounts
+            .token_vault
+            .amount
+            .checked_sub(vault_balance_before)
+            .ok_or(ErrorCode::ArithmeticUnderflow)?;
+
+        let pool = &ctx.accounts.pool;
+        let amount_out =
+            Pool::calculate_swap_output(amount_received, pool.token_reserves, pool.sol_reserves)?;
+        check_swap_output(amount_out, minimum_amount_out, pool.sol_reserves)?;
+
+        // SOL out: pool -> user (pool is owned by this program)
+        **ctx.accounts.pool.to_account_info().try_borrow_mut_lamports()? = ctx
+            .accounts
+            .pool
+            .to_account_info()
+            .lamports()
+            .checked_sub(amount_out)
+            .ok_or(ErrorCode::ArithmeticUnderflow)?;
+        **ctx.accounts.user.to_account_info().try_borrow_mut_lamports()? = ctx
+            .accounts
+            .user
+            .to_account_info()
+            .lamports()
+            .checked_add(amount_out)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        (amount_received, amount_out)
+    };
+
+    let pool = &mut ctx.accounts.pool;
+    if sol_to_token {
+        pool.sol_reserves = pool
+            .sol_reserves
+            .checked_add(amount_in)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        pool.token_reserves = pool
+            .token_reserves
+            .checked_sub(amount_out)
+            .ok_or(ErrorCode::ArithmeticUnderflow)?;
+    } else {
+        pool.token_reserves = pool
+            .token_reserves
+            .checked_add(amount_in)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        pool.sol_reserves = pool
+            .sol_reserves
+            .checked_sub(amount_out)
+            .ok_or(ErrorCode::ArithmeticUnderflow)?;
+    }
+
+    msg!("Swap successful!");
+    msg!("Amount in: {}", amount_in);
+    msg!("Amount out: {}", amount_out);
+
+    Ok(())
+}
+
+/// Rejects empty, slippage-exceeding or reserve-draining swap outputs
+fn check_swap_output(amount_out: u64, minimum_amount_out: u64, reserve_out: u64) -> Result<()> {
+    if amount_out == 0 {
+        return Err(ErrorCode::InvalidSwapAmount.into());
+    }
+    if amount_out < minimum_amount_out {
+        return Err(ErrorCode::SlippageExceeded.into());
+    }
+    if amount_out >= reserve_out {
+        return Err(ErrorCode::InsufficientReserves.into());
+    }
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SwapCtx<'info> {
+    #[account(
+        mut,
+        seeds = [POOL_SEED, token_mint.key().as_ref(), pool.creator.as_ref()],
+        bump = pool.bump
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    #[account(address = pool.token_mint)]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut, address = pool.token_vault)]
+    pub token_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::authority = user,
+        token::token_program = token_program
+    )]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,44 @@
+#![allow(unexpected_cfgs)]
+#![allow(deprecated)]
+use anchor_lang::prelude::*;
+
+pub mod constants;
+pub mod errors;
+pub mod instructions;
+pub mod states;
+pub mod utils;
+
+use instructions::*;
+
+declare_id!("FrGa8GkC2GuRNETwr2WH7fPx6tNjFerPWxKkVSGmgJuU");
+
+#[program]
+pub mod simple_amm {
+    use super::*;
+
+    pub fn initialize_pool<'info>(
+        ctx: Context<'_, '_, 'info, 'info, InitializePoolCtx<'info>>,
+        sol_amount: u64,
+        token_amount: u64,
+    ) -> Result<()> {
+        instructions::initialize_pool(ctx, sol_amount, token_amount)
+    }
+
+    pub fn swap<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SwapCtx<'info>>,
+        amount_in: u64,
+        minimum_amount_out: u64,
+        sol_to_token: bool,
+    ) -> Result<()> {
+        instructions::swap(ctx, amount_in, minimum_amount_out, sol_to_token)
+    }
+
+    pub fn remove_liquidity<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RemoveLiquidityCtx<'info>>,
+        lp_amount: u64,
+        minimum_sol_out: u64,
+        minimum_token_out: u64,
+    ) -> Result<()> {
+        instructions::remove_liquidity(ctx, lp_amount, minimum_sol_out, minimum_token_out)
+    }
+}
@@ -0,0 +1,113 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{BPS_DENOMINATOR, SWAP_FEE_BPS};
+use crate::errors::ErrorCode;
+
+#[account]
+#[derive(InitSpace)]
+pub struct Pool {
+    /// Mint of the token side of the pool
+    pub token_mint: Pubkey,
+
+    /// Depositor that created the pool (part of the pool PDA seeds)
+    pub creator: Pubkey,
+
+    /// LP token mint (authority is the pool PDA)
+    pub lp_mint: Pubkey,
+
+    /// Token vault owned by the pool PDA
+    pub token_vault: Pubkey,
+
+    /// SOL reserves held as lamports on the pool account (excluding rent)
+    pub sol_reserves: u64,
+
+    /// Token reserves held in the token vault
+    pub token_reserves: u64,
+
+    /// Outstanding LP token supply
+    pub lp_supply: u64,
+
+    /// Pool creation timestamp
+    pub created_at: i64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl Pool {
+    /// Calculate the LP tokens minted for the initial deposit: sqrt(sol * token)
+    pub fn calculate_initial_lp(sol_amount: u64, token_amount: u64) -> Result<u64> {
+        let product = (sol_amount as u128)
+            .checked_mul(token_amount as u128)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        let lp_amount = integer_sqrt(product);
+        u64::try_from(lp_amount).map_err(|_| ErrorCode::ArithmeticOverflow.into())
+    }
+
+    /// Calculate swap output after the pool fee
+    /// Uses the formula: out = out_reserves * in_after_fee / (in_reserves + in_after_fee)
+    pub fn calculate_swap_output(
+        amount_in: u64,
+        reserve_in: u64,
+        reserve_out: u64,
+    ) -> Result<u64> {
+        if amount_in == 0 {
+            return Ok(0);
+        }
+
+        let amount_in_after_fee = (amount_in as u128)
+            .checked_mul((BPS_DENOMINATOR - SWAP_FEE_BPS) as u128)
+            .ok_or(ErrorCode::ArithmeticOverflow)?
+            .checked_div(BPS_DENOMINATOR as u128)
+            .ok_or(ErrorCode::DivisionByZero)?;
+
+        let numerator = (reserve_out as u128)
+            .checked_mul(amount_in_after_fee)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        let denominator = (reserve_in as u128)
+            .checked_add(amount_in_after_fee)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+
+        if denominator == 0 {
+            return Err(ErrorCode::DivisionByZero.into());
+        }
+
+        let amount_out = numerator
+            .checked_div(denominator)
+            .ok_or(ErrorCode::DivisionByZero)?;
+
+        Ok(amount_out as u64)
+    }
+
+    /// Calculate the pro-rata share of a reserve for a given LP amount
+    pub fn calculate_withdraw_share(&self, lp_amount: u64, reserve: u64) -> Result<u64> {
+        if self.lp_supply == 0 {
+            return Err(ErrorCode::DivisionByZero.into());
+        }
+
+        let share = (reserve as u128)
+            .checked_mul(lp_amount as u128)
+            .ok_or(ErrorCode::ArithmeticOverflow)?
+            .checked_div(self.lp_supply as u128)
+            .ok_or(ErrorCode::DivisionByZero)?;
+
+        Ok(share as u64)
+    }
+}
+
+/// Integer square root (floor) using Newton's method
+fn integer_sqrt(value: u128) -> u128 {
+    if value < 2 {
+        return value;
+    }
+
+    let mut x = value;
+    let mut y = x.div_ceil(2);
+    while y < x {
+        x = y;
+        y = (x + value / x) / 2;
+    }
+    x
+}
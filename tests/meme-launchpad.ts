@@ -1,9 +1,23 @@
 import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
 import { MemeLaunchpad } from "../target/types/meme_launchpad";
+import { SimpleAmm } from "../target/types/simple_amm";
+import { HolderTracker } from "../target/types/holder_tracker";
 import { PublicKey, SystemProgram, Keypair, LAMPORTS_PER_SOL } from "@solana/web3.js";
-import { getAssociatedTokenAddress, TOKEN_2022_PROGRAM_ID, ASSOCIATED_TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import {
+  createAssociatedTokenAccountIdempotentInstruction,
+  createBurnCheckedInstruction,
+  createTransferCheckedInstruction,
+  createTransferCheckedWithTransferHookInstruction,
+  getAccount,
+  getAssociatedTokenAddress,
+  getAssociatedTokenAddressSync,
+  getMint,
+  TOKEN_2022_PROGRAM_ID,
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+} from "@solana/spl-token";
 import { assert } from "chai";
+import { createHash } from "crypto";
 
 describe("meme-launchpad", () => {
   // Configure the client to use the local cluster.
@@ -11,11 +25,28 @@ describe("meme-launchpad", () => {
   anchor.setProvider(provider);
 
   const program = anchor.workspace.memeLaunchpad as Program<MemeLaunchpad>;
+  const ammProgram = anchor.workspace.simpleAmm as Program<SimpleAmm>;
+  const holderTracker = anchor.workspace.holderTracker as Program<HolderTracker>;
 
   const PROGRAM_STATE_SEED = Buffer.from("program_state");
   const TOKEN_INFO_SEED = Buffer.from("token_info");
   const BONDING_CURVE_SEED = Buffer.from("bonding_curve");
   const TRANSACTION_SEED = Buffer.from("transaction");
+  const LAUNCH_ESCROW_SEED = Buffer.from("launch_escrow");
+  const CREATOR_VAULT_SEED = Buffer.from("creator_vault");
+  const PLATFORM_FEE_VAULT_SEED = Buffer.from("platform_fee_vault");
+
+  // Localnet configuration: a low threshold and no minimum trading time so
+  // tokens can complete and launch within a test run
+  const LAUNCH_THRESHOLD = new anchor.BN(2 * LAMPORTS_PER_SOL);
+  const PLATFORM_LIMITS = {
+    minTokenPurchase: new anchor.BN(LAMPORTS_PER_SOL / 10),
+    maxTokenPurchase: new anchor.BN(10 * LAMPORTS_PER_SOL),
+    minTradingTime: new anchor.BN(0),
+    launchLiquidityBps: new anchor.BN(8000),
+    maxPlatformFeeRate: new anchor.BN(1000),
+    minLaunchThreshold: new anchor.BN(LAMPORTS_PER_SOL),
+  };
 
   let programStatePda: PublicKey;
   let deployer: Keypair; // In localnet, provider.wallet is the deployer usually
@@ -34,11 +65,218 @@ describe("meme-launchpad", () => {
   let tokenInfoPda: PublicKey;
   let bondingCurvePda: PublicKey;
   let mintPda: PublicKey;
+  let creatorVaultPda: PublicKey;
+
+  const fundWallet = async (wallet: PublicKey, sol: number) => {
+    const signature = await provider.connection.requestAirdrop(wallet, sol * LAMPORTS_PER_SOL);
+    await provider.connection.confirmTransaction(signature);
+  };
+
+  const tokenPdas = (id: anchor.BN) => {
+    const idBytes = id.toArrayLike(Buffer, "le", 8);
+    const [tokenInfo] = PublicKey.findProgramAddressSync([TOKEN_INFO_SEED, idBytes], program.programId);
+    const [bondingCurve] = PublicKey.findProgramAddressSync([BONDING_CURVE_SEED, idBytes], program.programId);
+    const [mint] = PublicKey.findProgramAddressSync(
+      [Buffer.from("mint"), bondingCurve.toBuffer()],
+      program.programId
+    );
+    const [creatorVault] = PublicKey.findProgramAddressSync([CREATOR_VAULT_SEED, idBytes], program.programId);
+    const [launchEscrow] = PublicKey.findProgramAddressSync([LAUNCH_ESCROW_SEED, idBytes], program.programId);
+    return { tokenInfo, bondingCurve, mint, creatorVault, launchEscrow };
+  };
+
+  const transactionPdaFor = (wallet: PublicKey, id: anchor.BN, txId: anchor.BN) =>
+    PublicKey.findProgramAddressSync(
+      [
+        TRANSACTION_SEED,
+        wallet.toBuffer(),
+        id.toArrayLike(Buffer, "le", 8),
+        txId.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    )[0];
+
+  // AMM pools are namespaced by the depositor that created them
+  const ammPoolAccounts = (mint: PublicKey, depositor: PublicKey) => {
+    const [pool] = PublicKey.findProgramAddressSync(
+      [Buffer.from("pool"), mint.toBuffer(), depositor.toBuffer()],
+      ammProgram.programId
+    );
+    const [lpMint] = PublicKey.findProgramAddressSync(
+      [Buffer.from("lp_mint"), pool.toBuffer()],
+      ammProgram.programId
+    );
+    const [tokenVault] = PublicKey.findProgramAddressSync(
+      [Buffer.from("token_vault"), pool.toBuffer()],
+      ammProgram.programId
+    );
+    return { pool, lpMint, tokenVault };
+  };
+
+  // Holder tracker accounts for tokens created with holder tracking
+  const holderTrackerAccounts = (mint: PublicKey) => ({
+    holderTrackerProgram: holderTracker.programId,
+    holderStats: PublicKey.findProgramAddressSync(
+      [Buffer.from("holder_stats"), mint.toBuffer()],
+      holderTracker.programId
+    )[0],
+  });
+
+  // Holder tracker position of a token account
+  const holderPositionFor = (tokenAccount: PublicKey) =>
+    PublicKey.findProgramAddressSync([Buffer.from("position"), tokenAccount.toBuffer()], holderTracker.programId)[0];
+
+  const tokenBalance = async (tokenAccount: PublicKey) =>
+    new anchor.BN(
+      (await getAccount(provider.connection, tokenAccount, "confirmed", TOKEN_2022_PROGRAM_ID)).amount.toString()
+    );
+
+  const createTestToken = async (creator: Keypair, overrides: object = {}, accounts: object = {}) => {
+    const state = await program.account.programState.fetch(programStatePda);
+    const id = state.tokenCount.addn(1);
+    const pdas = tokenPdas(id);
+
+    await program.methods
+      .createToken(tokenName, tokenSymbol, tokenUri, tokenDecimals, initialSupply, {
+        graduationTarget: { constantProductAmm: {} },
+        graduationProgram: ammProgram.programId,
+        liquidityLock: { burn: {} },
+        mintAuthority: null,
+        freezeAuthority: null,
+        transferFeeBps: null,
+        transferLockedUntilLaunch: false,
+        holderTracking: false,
+        creatorVesting: null,
+        airdrop: null,
+        ...overrides,
+      })
+      .accountsPartial({
+        programState: programStatePda,
+        tokenInfo: pdas.tokenInfo,
+        bondingCurve: pdas.bondingCurve,
+        creatorVault: pdas.creatorVault,
+        mint: pdas.mint,
+        creator: creator.publicKey,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+        ...accounts,
+      })
+      .signers([creator])
+      .rpc();
+
+    return id;
+  };
+
+  const buyTestToken = async (id: anchor.BN, buyer: Keypair, lamports: anchor.BN, accounts: object = {}) => {
+    const pdas = tokenPdas(id);
+    const info = await program.account.tokenInfo.fetch(pdas.tokenInfo);
+
+    return program.methods
+      .buyToken(id, lamports)
+      .accountsPartial({
+        programState: programStatePda,
+        tokenInfo: pdas.tokenInfo,
+        bondingCurve: pdas.bondingCurve,
+        transaction: transactionPdaFor(buyer.publicKey, id, info.transactionCount.addn(1)),
+        mint: pdas.mint,
+        buyerTokenAccount: getAssociatedTokenAddressSync(pdas.mint, buyer.publicKey, false, TOKEN_2022_PROGRAM_ID),
+        liquidityPool: null,
+        poolTokenVault: null,
+        creatorVault: pdas.creatorVault,
+        buyer: buyer.publicKey,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+        ...accounts,
+      })
+      .signers([buyer])
+      .rpc();
+  };
+
+  const sellTestToken = async (id: anchor.BN, seller: Keypair, amount: anchor.BN) => {
+    const pdas = tokenPdas(id);
+    const info = await program.account.tokenInfo.fetch(pdas.tokenInfo);
+
+    await program.methods
+      .sellToken(id, amount)
+      .accountsPartial({
+        programState: programStatePda,
+        tokenInfo: pdas.tokenInfo,
+        bondingCurve: pdas.bondingCurve,
+        transaction: transactionPdaFor(seller.publicKey, id, info.transactionCount.addn(1)),
+        mint: pdas.mint,
+        sellerTokenAccount: getAssociatedTokenAddressSync(pdas.mint, seller.publicKey, false, TOKEN_2022_PROGRAM_ID),
+        liquidityPool: null,
+        poolTokenVault: null,
+        creatorVault: pdas.creatorVault,
+        seller: seller.publicKey,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([seller])
+      .rpc();
+  };
+
+  // Launches a ConstantProductAmm token into the pool owned by its launch escrow
+  const launchTestToken = async (id: anchor.BN, launcher: Keypair, accounts: object = {}) => {
+    const pdas = tokenPdas(id);
+    const info = await program.account.tokenInfo.fetch(pdas.tokenInfo);
+    const nextTxId = info.transactionCount.addn(1);
+    const amm = ammPoolAccounts(pdas.mint, pdas.launchEscrow);
+
+    return program.methods
+      .launchToDex(id, nextTxId)
+      .accountsPartial({
+        programState: programStatePda,
+        tokenInfo: pdas.tokenInfo,
+        bondingCurve: pdas.bondingCurve,
+        transaction: transactionPdaFor(launcher.publicKey, id, nextTxId),
+        mint: pdas.mint,
+        launchEscrow: pdas.launchEscrow,
+        escrowTokenAccount: getAssociatedTokenAddressSync(pdas.mint, pdas.launchEscrow, true, TOKEN_2022_PROGRAM_ID),
+        escrowLpAccount: getAssociatedTokenAddressSync(amm.lpMint, pdas.launchEscrow, true, TOKEN_2022_PROGRAM_ID),
+        ammPool: amm.pool,
+        ammLpMint: amm.lpMint,
+        ammTokenVault: amm.tokenVault,
+        liquidityPool: null,
+        poolTokenVault: null,
+        airdrop: null,
+        airdropTokenAccount: null,
+        launcher: launcher.publicKey,
+        ammProgram: ammProgram.programId,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        ...accounts,
+      })
+      .signers([launcher])
+      .rpc();
+  };
+
+  // Frozen `tokenAccounts` are thawed before the freeze authority is revoked
+  const finalizeUnlockFor = async (id: anchor.BN, tokenAccounts: PublicKey[] = [], moderator?: Keypair) => {
+    const pdas = tokenPdas(id);
+    return program.methods
+      .finalizeUnlock(id)
+      .accountsPartial({
+        programState: programStatePda,
+        tokenInfo: pdas.tokenInfo,
+        bondingCurve: pdas.bondingCurve,
+        mint: pdas.mint,
+        moderator: moderator ? moderator.publicKey : provider.wallet.publicKey,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .remainingAccounts(tokenAccounts.map((pubkey) => ({ pubkey, isWritable: true, isSigner: false })))
+      .signers(moderator ? [moderator] : [])
+      .rpc();
+  };
 
   before(async () => {
     // Airdrop SOL to user
-    const signature = await provider.connection.requestAirdrop(user.publicKey, 100 * LAMPORTS_PER_SOL);
-    await provider.connection.confirmTransaction(signature);
+    await fundWallet(user.publicKey, 100);
   });
 
   it("Is initialized!", async () => {
@@ -55,7 +293,7 @@ describe("meme-launchpad", () => {
     } catch (e) {
       // If not, initialize
       await program.methods
-        .initialize()
+        .initialize(LAUNCH_THRESHOLD, PLATFORM_LIMITS)
         .accountsPartial({
           programState: programStatePda,
           deployer: provider.wallet.publicKey,
@@ -69,6 +307,21 @@ describe("meme-launchpad", () => {
     assert.equal(state.platformFeeRate.toNumber(), 250); // 2.5%
   });
 
+  it("Allowlists the AMM graduation program", async () => {
+    await program.methods
+      .addGraduationProgram({ constantProductAmm: {} }, ammProgram.programId)
+      .accountsPartial({
+        programState: programStatePda,
+        authority: provider.wallet.publicKey,
+      })
+      .rpc();
+
+    const state = await program.account.programState.fetch(programStatePda);
+    assert.isTrue(
+      state.graduationPrograms.some((entry) => entry.programId.equals(ammProgram.programId))
+    );
+  });
+
   it("Creates a token", async () => {
     const state = await program.account.programState.fetch(programStatePda);
     const nextTokenId = state.tokenCount.add(new anchor.BN(1));
@@ -89,18 +342,36 @@ describe("meme-launchpad", () => {
       program.programId
     );
 
+    [creatorVaultPda] = PublicKey.findProgramAddressSync(
+      [CREATOR_VAULT_SEED, nextTokenId.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+
     await program.methods
       .createToken(
         tokenName,
         tokenSymbol,
         tokenUri,
         tokenDecimals,
-        initialSupply
+        initialSupply,
+        {
+          graduationTarget: { constantProductAmm: {} },
+          graduationProgram: ammProgram.programId,
+          liquidityLock: { burn: {} },
+          mintAuthority: null,
+          freezeAuthority: null,
+          transferFeeBps: null,
+          transferLockedUntilLaunch: false,
+          holderTracking: false,
+          creatorVesting: null,
+          airdrop: null,
+        }
       )
       .accountsPartial({
         programState: programStatePda,
         tokenInfo: tokenInfoPda,
         bondingCurve: bondingCurvePda,
+        creatorVault: creatorVaultPda,
         mint: mintPda,
         creator: user.publicKey,
         tokenProgram: TOKEN_2022_PROGRAM_ID,
@@ -114,6 +385,8 @@ describe("meme-launchpad", () => {
     assert.equal(tokenInfo.name, tokenName);
     assert.equal(tokenInfo.symbol, tokenSymbol);
     assert.equal(tokenInfo.tokenId.toString(), nextTokenId.toString());
+    assert.deepEqual(tokenInfo.graduationTarget, { constantProductAmm: {} });
+    assert.isTrue(tokenInfo.graduationProgram.equals(ammProgram.programId));
 
     const bondingCurve = await program.account.bondingCurve.fetch(bondingCurvePda);
     assert.isTrue(bondingCurve.active);
@@ -143,15 +416,8 @@ describe("meme-launchpad", () => {
       program.programId
     );
     
-    // We assume the deployer is the platform auth and current treasury?
-    // In initialize: platform_treasury = deployer.key()
-    // In buy_token: program_state has to be passed. The instruction transfers fee to program_state account?
-    // Wait, let's check buy_token.rs: 
-    // Transfer platform fee to: ctx.accounts.program_state.to_account_info()
-    // This implies program_state account MUST accept standard lamport transfers.
-    // However, program_state is an Anchor account (PDA). It CAN receive SOL.
-    
-    // Also token_creator is user.publicKey since user created it.
+    // Platform fees collect in the platform fee vault PDA (resolved from its constant seed),
+    // creator fees accrue in the creator vault PDA.
 
     await program.methods
       .buyToken(tokenId, solAmount)
@@ -162,7 +428,9 @@ describe("meme-launchpad", () => {
         transaction: transactionPda,
         mint: mintPda,
         buyerTokenAccount: buyerAta,
-        tokenCreator: user.publicKey,
+        liquidityPool: null, // Only used after graduation to the built-in pool
+        poolTokenVault: null,
+        creatorVault: creatorVaultPda,
         buyer: user.publicKey,
         tokenProgram: TOKEN_2022_PROGRAM_ID,
         associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
@@ -180,6 +448,9 @@ describe("meme-launchpad", () => {
     // Sol reserves should be > 0 (it was 0 real reserves initially)
     assert.isTrue(bc.realSolReserves.gt(new anchor.BN(0)));
     assert.isTrue(bc.totalSolVolume.eq(solAmount));
+
+    const state = await program.account.programState.fetch(programStatePda);
+    assert.isTrue(state.totalFeesCollected.gt(new anchor.BN(0)));
   });
 
   it("Sells tokens", async () => {
@@ -221,7 +492,9 @@ describe("meme-launchpad", () => {
         transaction: transactionPda,
         mint: mintPda,
         sellerTokenAccount: buyerAta,
-        tokenCreator: user.publicKey,
+        liquidityPool: null,
+        poolTokenVault: null,
+        creatorVault: creatorVaultPda,
         seller: user.publicKey,
         tokenProgram: TOKEN_2022_PROGRAM_ID, // Use TOKEN_2_22
         associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
@@ -235,78 +508,1072 @@ describe("meme-launchpad", () => {
     assert.isTrue(bc.totalTokenVolume.gt(new anchor.BN(0)));
   });
 
-  it("Fails to launch to DEX due to cooldown", async () => {
-    const tokenInfo = await program.account.tokenInfo.fetch(tokenInfoPda);
-    const nextTxId = tokenInfo.transactionCount.add(new anchor.BN(1));
-
-    const [transactionPda] = PublicKey.findProgramAddressSync(
-      [
-        TRANSACTION_SEED,
-        user.publicKey.toBuffer(),
-        tokenId.toArrayLike(Buffer, "le", 8),
-        nextTxId.toArrayLike(Buffer, "le", 8),
-      ],
-      program.programId
+  it("Halts and resumes trading on a token", async () => {
+    const buyerAta = await getAssociatedTokenAddress(
+      mintPda,
+      user.publicKey,
+      false,
+      TOKEN_2022_PROGRAM_ID
     );
+    const nextTransactionPda = async () => {
+      const info = await program.account.tokenInfo.fetch(tokenInfoPda);
+      return PublicKey.findProgramAddressSync(
+        [
+          TRANSACTION_SEED,
+          user.publicKey.toBuffer(),
+          tokenId.toArrayLike(Buffer, "le", 8),
+          info.transactionCount.add(new anchor.BN(1)).toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      )[0];
+    };
+
+    // Halt buys but let holders exit
+    await program.methods.haltTokenTrading(tokenId, 1, true)
+      .accountsPartial({
+        programState: programStatePda,
+        tokenInfo: tokenInfoPda,
+        moderator: provider.wallet.publicKey,
+      })
+      .rpc();
+    let tokenInfo = await program.account.tokenInfo.fetch(tokenInfoPda);
+    assert.isFalse(tokenInfo.tradingActive);
+    assert.equal(tokenInfo.tradingHalt.reasonCode, 1);
 
     try {
-      await program.methods.launchToDex(tokenId, nextTxId)
+      await program.methods
+        .buyToken(tokenId, new anchor.BN(LAMPORTS_PER_SOL))
+        .accountsPartial({
+          programState: programStatePda,
+          tokenInfo: tokenInfoPda,
+          bondingCurve: bondingCurvePda,
+          transaction: await nextTransactionPda(),
+          mint: mintPda,
+          buyerTokenAccount: buyerAta,
+          liquidityPool: null,
+          poolTokenVault: null,
+          creatorVault: creatorVaultPda,
+          buyer: user.publicKey,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+          rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+        })
+        .signers([user])
+        .rpc();
+      assert.fail("Buy should have failed");
+    } catch (e) {
+      assert.include(e.message, "TradingHalted");
+    }
+
+    await program.methods.sellToken(tokenId, new anchor.BN(1000000))
       .accountsPartial({
         programState: programStatePda,
         tokenInfo: tokenInfoPda,
         bondingCurve: bondingCurvePda,
-        transaction: transactionPda,
+        transaction: await nextTransactionPda(),
         mint: mintPda,
-        tokenCreator: user.publicKey,
-        launcher: user.publicKey, // User launches
+        sellerTokenAccount: buyerAta,
+        liquidityPool: null,
+        poolTokenVault: null,
+        creatorVault: creatorVaultPda,
+        seller: user.publicKey,
         tokenProgram: TOKEN_2022_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
       })
       .signers([user])
       .rpc();
-      
-      assert.fail("Should have failed with LaunchCooldownActive");
-    } catch (e: any) {
-        // We expect error code for LaunchCooldownActive or ThresholdNotMet
-        // If we didn't buy enough SOL, it might be LaunchThresholdNotMet first.
-        // Let's check what we hit.
-        // Threshold is 1000 SOL. We only bought 1 SOL. So actually it should be "LaunchThresholdNotMet".
-        
-        // Wait, the instruction checks logic:
-        // 1. Threshold
-        // 2. Cooldown
-        
-        // if bonding_curve.real_sol_reserves < program_state.launch_threshold returns LaunchThresholdNotMet.
-        // So we expect LaunchThresholdNotMet.
-        
-        // Unless we want to test Cooldown, we'd need to pump it.
-        // Pumping 1000 SOL on localnet is possible but slow if curve is expensive or whatever, but actually curve price goes up.
-        // Since we want to test *comprehensive* coverage, detecting the failure is valid coverage of the guard clause.
-        
-        const isThreshold = e.message.includes("LaunchThresholdNotMet") || e.error?.errorCode?.code === "LaunchThresholdNotMet";
-        const isCooldown = e.message.includes("LaunchCooldownActive") || e.error?.errorCode?.code === "LaunchCooldownActive";
-        
-        if (!isThreshold && !isCooldown) {
-            console.log(e);
-            throw e;
-        }
+
+    await program.methods.resumeTokenTrading(tokenId)
+      .accountsPartial({
+        programState: programStatePda,
+        tokenInfo: tokenInfoPda,
+        moderator: provider.wallet.publicKey,
+      })
+      .rpc();
+    tokenInfo = await program.account.tokenInfo.fetch(tokenInfoPda);
+    assert.isTrue(tokenInfo.tradingActive);
+    assert.isNull(tokenInfo.tradingHalt);
+  });
+
+  it("Fails to launch to DEX before the threshold is met", async () => {
+    // Only 1 SOL has been bought against the 2 SOL threshold
+    try {
+      await launchTestToken(tokenId, user);
+      assert.fail("Launch should have failed");
+    } catch (e) {
+      assert.include(e.message, "LaunchThresholdNotMet");
+    }
+  });
+
+  it("Launches into the escrow's AMM pool despite a squatted pool for the mint", async () => {
+    const squatter = Keypair.generate();
+    await fundWallet(squatter.publicKey, 20);
+    const id = await createTestToken(user);
+    const pdas = tokenPdas(id);
+
+    // Anyone can still open their own pool for the mint, under their own address
+    await buyTestToken(id, squatter, new anchor.BN(LAMPORTS_PER_SOL / 2));
+    const squatterAta = getAssociatedTokenAddressSync(pdas.mint, squatter.publicKey, false, TOKEN_2022_PROGRAM_ID);
+    const squatterTokens = await tokenBalance(squatterAta);
+    const squatterPool = ammPoolAccounts(pdas.mint, squatter.publicKey);
+    const squatterLpAccount = getAssociatedTokenAddressSync(
+      squatterPool.lpMint,
+      squatter.publicKey,
+      false,
+      TOKEN_2022_PROGRAM_ID
+    );
+    await ammProgram.methods
+      .initializePool(new anchor.BN(LAMPORTS_PER_SOL / 10), squatterTokens.divn(2))
+      .accountsPartial({
+        pool: squatterPool.pool,
+        tokenMint: pdas.mint,
+        lpMint: squatterPool.lpMint,
+        tokenVault: squatterPool.tokenVault,
+        depositor: squatter.publicKey,
+        depositorTokenAccount: squatterAta,
+        depositorLpAccount: squatterLpAccount,
+        payer: squatter.publicKey,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([squatter])
+      .rpc();
+
+    // Complete the curve and launch
+    await buyTestToken(id, user, new anchor.BN(3 * LAMPORTS_PER_SOL));
+    const curve = await program.account.bondingCurve.fetch(pdas.bondingCurve);
+    const info = await program.account.tokenInfo.fetch(pdas.tokenInfo);
+    assert.isTrue(curve.complete);
+    await launchTestToken(id, user);
+
+    // The escrow's pool holds exactly the seeded liquidity
+    const escrowPool = ammPoolAccounts(pdas.mint, pdas.launchEscrow);
+    let pool = await ammProgram.account.pool.fetch(escrowPool.pool);
+    const seededSol = curve.realSolReserves.muln(8000).divn(10000);
+    assert.equal(pool.creator.toBase58(), pdas.launchEscrow.toBase58());
+    assert.equal(pool.solReserves.toString(), seededSol.toString());
+    assert.equal(pool.tokenReserves.toString(), info.liquidityTokenReserve.toString());
+    assert.equal((await tokenBalance(escrowPool.tokenVault)).toString(), info.liquidityTokenReserve.toString());
+    const launched = await program.account.tokenInfo.fetch(pdas.tokenInfo);
+    assert.equal(launched.dexPool.toBase58(), escrowPool.pool.toBase58());
+
+    // Swap SOL for tokens against the seeded pool
+    const swapIn = new anchor.BN(LAMPORTS_PER_SOL / 10);
+    const inAfterFee = swapIn.muln(9970).divn(10000);
+    const expectedOut = pool.tokenReserves.mul(inAfterFee).div(pool.solReserves.add(inAfterFee));
+    const balanceBefore = await tokenBalance(squatterAta);
+    await ammProgram.methods
+      .swap(swapIn, expectedOut, true)
+      .accountsPartial({
+        pool: escrowPool.pool,
+        tokenMint: pdas.mint,
+        tokenVault: escrowPool.tokenVault,
+        user: squatter.publicKey,
+        userTokenAccount: squatterAta,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([squatter])
+      .rpc();
+    assert.equal((await tokenBalance(squatterAta)).sub(balanceBefore).toString(), expectedOut.toString());
+    pool = await ammProgram.account.pool.fetch(escrowPool.pool);
+    assert.equal(pool.solReserves.toString(), seededSol.add(swapIn).toString());
+    assert.equal(pool.tokenReserves.toString(), info.liquidityTokenReserve.sub(expectedOut).toString());
+
+    // The squatter's own pool can be unwound in full
+    const lpAmount = await tokenBalance(squatterLpAccount);
+    const squatterPoolState = await ammProgram.account.pool.fetch(squatterPool.pool);
+    await ammProgram.methods
+      .removeLiquidity(lpAmount, squatterPoolState.solReserves, squatterPoolState.tokenReserves)
+      .accountsPartial({
+        pool: squatterPool.pool,
+        tokenMint: pdas.mint,
+        lpMint: squatterPool.lpMint,
+        tokenVault: squatterPool.tokenVault,
+        user: squatter.publicKey,
+        userLpAccount: squatterLpAccount,
+        userTokenAccount: squatterAta,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([squatter])
+      .rpc();
+    const drained = await ammProgram.account.pool.fetch(squatterPool.pool);
+    assert.equal(drained.lpSupply.toNumber(), 0);
+    assert.equal(drained.solReserves.toNumber(), 0);
+    assert.equal(drained.tokenReserves.toNumber(), 0);
+  });
+
+  it("Retargets a token whose graduation program was delisted", async () => {
+    const delisted = Keypair.generate().publicKey;
+    await program.methods
+      .addGraduationProgram({ constantProductAmm: {} }, delisted)
+      .accountsPartial({
+        programState: programStatePda,
+        authority: provider.wallet.publicKey,
+      })
+      .rpc();
+    const id = await createTestToken(user, { graduationProgram: delisted });
+    const pdas = tokenPdas(id);
+
+    const retarget = (signer: Keypair) =>
+      program.methods
+        .retargetGraduation(id, { constantProductAmm: {} }, ammProgram.programId)
+        .accountsPartial({
+          programState: programStatePda,
+          tokenInfo: pdas.tokenInfo,
+          authority: signer.publicKey,
+        })
+        .signers([signer])
+        .rpc();
+
+    // Not allowed while the original program is still allowlisted
+    try {
+      await retarget(user);
+      assert.fail("Retarget should have failed");
+    } catch (e) {
+      assert.include(e.message, "GraduationProgramStillAllowed");
+    }
+
+    await program.methods
+      .removeGraduationProgram(delisted)
+      .accountsPartial({
+        programState: programStatePda,
+        authority: provider.wallet.publicKey,
+      })
+      .rpc();
+
+    const stranger = Keypair.generate();
+    try {
+      await retarget(stranger);
+      assert.fail("Retarget should have failed");
+    } catch (e) {
+      assert.include(e.message, "Unauthorized");
+    }
+
+    await retarget(user);
+    const info = await program.account.tokenInfo.fetch(pdas.tokenInfo);
+    assert.isTrue(info.graduationProgram.equals(ammProgram.programId));
+  });
+
+  it("Keeps time-locked LP in escrow for the minimum lock duration", async () => {
+    // The creator asks for a lock that ends a few seconds after creation
+    const unlockAt = new anchor.BN(Math.floor(Date.now() / 1000) + 5);
+    const id = await createTestToken(user, { liquidityLock: { lock: { unlockAt } } });
+    const pdas = tokenPdas(id);
+    await buyTestToken(id, user, new anchor.BN(3 * LAMPORTS_PER_SOL));
+    await launchTestToken(id, user);
+    await new Promise((resolve) => setTimeout(resolve, 6000));
+
+    const info = await program.account.tokenInfo.fetch(pdas.tokenInfo);
+    assert.isTrue(info.lpTokenAmount.gtn(0));
+    try {
+      await program.methods
+        .claimLockedLp(id)
+        .accountsPartial({
+          tokenInfo: pdas.tokenInfo,
+          launchEscrow: pdas.launchEscrow,
+          escrowLpAccount: info.lpTokenAccount,
+          lpMint: info.lpMint,
+          creatorLpAccount: getAssociatedTokenAddressSync(info.lpMint, user.publicKey, false, TOKEN_2022_PROGRAM_ID),
+          creator: user.publicKey,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user])
+        .rpc();
+      assert.fail("Claim should have failed");
+    } catch (e) {
+      assert.include(e.message, "LiquidityLocked");
+    }
+    assert.equal(
+      (await tokenBalance(info.lpTokenAccount)).toString(),
+      info.lpTokenAmount.toString()
+    );
+  });
+
+  it("Completes the curve when a buy crosses the launch threshold", async () => {
+    const id = await createTestToken(user);
+    const pdas = tokenPdas(id);
+
+    await buyTestToken(id, user, new anchor.BN(LAMPORTS_PER_SOL));
+    let curve = await program.account.bondingCurve.fetch(pdas.bondingCurve);
+    assert.isFalse(curve.complete);
+    assert.isNull(curve.completedAt);
+
+    await buyTestToken(id, user, new anchor.BN(3 * LAMPORTS_PER_SOL));
+    curve = await program.account.bondingCurve.fetch(pdas.bondingCurve);
+    assert.isTrue(curve.complete);
+    assert.isNotNull(curve.completedAt);
+    assert.equal(curve.realSolReserves.toString(), LAUNCH_THRESHOLD.toString());
+
+    // The curve stops trading in both directions until launch
+    try {
+      await buyTestToken(id, user, new anchor.BN(LAMPORTS_PER_SOL / 10));
+      assert.fail("Buy should have failed");
+    } catch (e) {
+      assert.include(e.message, "BondingCurveComplete");
+    }
+    try {
+      await sellTestToken(id, user, new anchor.BN(1_000_000));
+      assert.fail("Sell should have failed");
+    } catch (e) {
+      assert.include(e.message, "BondingCurveComplete");
+    }
+  });
+
+  it("Charges only the capped amount for a buy overshooting the threshold", async () => {
+    const id = await createTestToken(user);
+    const pdas = tokenPdas(id);
+    await buyTestToken(id, user, new anchor.BN(LAMPORTS_PER_SOL));
+
+    const curve = await program.account.bondingCurve.fetch(pdas.bondingCurve);
+    const state = await program.account.programState.fetch(programStatePda);
+    const info = await program.account.tokenInfo.fetch(pdas.tokenInfo);
+    const buyerAta = getAssociatedTokenAddressSync(pdas.mint, user.publicKey, false, TOKEN_2022_PROGRAM_ID);
+
+    // Gross amount whose net of platform (fee rate) and creator (1%) fees lands on the threshold
+    const remaining = LAUNCH_THRESHOLD.sub(curve.realSolReserves);
+    const netBps = new anchor.BN(10000).sub(state.platformFeeRate).subn(100);
+    const cappedGross = remaining.muln(10000).add(netBps).subn(1).div(netBps);
+    const expectedTokens = curve.virtualTokenReserves
+      .mul(cappedGross)
+      .div(curve.virtualSolReserves.add(cappedGross));
+
+    const lamportsBefore = await provider.connection.getBalance(user.publicKey, "confirmed");
+    const tokensBefore = await tokenBalance(buyerAta);
+    const signature = await buyTestToken(id, user, new anchor.BN(5 * LAMPORTS_PER_SOL));
+
+    // The buyer also funds the transaction record and the network fee
+    const txRecordRent = await provider.connection.getBalance(
+      transactionPdaFor(user.publicKey, id, info.transactionCount.addn(1)),
+      "confirmed"
+    );
+    const confirmed = await provider.connection.getTransaction(signature, {
+      commitment: "confirmed",
+      maxSupportedTransactionVersion: 0,
+    });
+    const lamportsAfter = await provider.connection.getBalance(user.publicKey, "confirmed");
+    assert.equal(
+      lamportsBefore - lamportsAfter,
+      cappedGross.toNumber() + txRecordRent + confirmed.meta.fee
+    );
+    assert.equal((await tokenBalance(buyerAta)).sub(tokensBefore).toString(), expectedTokens.toString());
+
+    const completed = await program.account.bondingCurve.fetch(pdas.bondingCurve);
+    assert.isTrue(completed.complete);
+    assert.equal(completed.realSolReserves.toString(), LAUNCH_THRESHOLD.toString());
+  });
+
+  it("Lets anyone crank the launch for a reward after the grace period", async () => {
+    const setLaunchCrank = (gracePeriod: number) =>
+      program.methods
+        .updateLaunchCrank(new anchor.BN(500), new anchor.BN(gracePeriod))
+        .accountsPartial({
+          programState: programStatePda,
+          feeManager: provider.wallet.publicKey,
+        })
+        .rpc();
+    await setLaunchCrank(3);
+
+    const cranker = Keypair.generate();
+    await fundWallet(cranker.publicKey, 5);
+    const id = await createTestToken(user);
+    const pdas = tokenPdas(id);
+    await buyTestToken(id, user, new anchor.BN(3 * LAMPORTS_PER_SOL));
+
+    // Only the creator or platform authority may launch right after completion
+    try {
+      await launchTestToken(id, cranker);
+      assert.fail("Launch should have failed");
+    } catch (e) {
+      assert.include(e.message, "LaunchGracePeriodActive");
+    }
+
+    await new Promise((resolve) => setTimeout(resolve, 4000));
+
+    const [platformFeeVaultPda] = PublicKey.findProgramAddressSync(
+      [PLATFORM_FEE_VAULT_SEED],
+      program.programId
+    );
+    const curve = await program.account.bondingCurve.fetch(pdas.bondingCurve);
+    const info = await program.account.tokenInfo.fetch(pdas.tokenInfo);
+    const state = await program.account.programState.fetch(programStatePda);
+    const launchFee = curve.realSolReserves.sub(curve.realSolReserves.muln(8000).divn(10000));
+    const reward = launchFee.mul(state.launcherRewardBps).divn(10000);
+    assert.isTrue(reward.gtn(0));
+
+    const vaultBefore = await provider.connection.getBalance(platformFeeVaultPda, "confirmed");
+    const crankerBefore = await provider.connection.getBalance(cranker.publicKey, "confirmed");
+    const signature = await launchTestToken(id, cranker);
+
+    // The platform keeps the launch fee minus the reward
+    const vaultAfter = await provider.connection.getBalance(platformFeeVaultPda, "confirmed");
+    assert.equal(vaultAfter - vaultBefore, launchFee.sub(reward).toNumber());
+
+    // The cranker receives the reward, less the rent and fee it paid for the launch
+    const amm = ammPoolAccounts(pdas.mint, pdas.launchEscrow);
+    const pool = await ammProgram.account.pool.fetch(amm.pool);
+    const balanceOf = (account: PublicKey) => provider.connection.getBalance(account, "confirmed");
+    const rentPaid =
+      (await balanceOf(transactionPdaFor(cranker.publicKey, id, info.transactionCount.addn(1)))) +
+      (await balanceOf(getAssociatedTokenAddressSync(pdas.mint, pdas.launchEscrow, true, TOKEN_2022_PROGRAM_ID))) +
+      (await balanceOf(getAssociatedTokenAddressSync(amm.lpMint, pdas.launchEscrow, true, TOKEN_2022_PROGRAM_ID))) +
+      (await balanceOf(amm.pool)) - pool.solReserves.toNumber() +
+      (await balanceOf(amm.lpMint)) +
+      (await balanceOf(amm.tokenVault));
+    const confirmed = await provider.connection.getTransaction(signature, {
+      commitment: "confirmed",
+      maxSupportedTransactionVersion: 0,
+    });
+    const crankerAfter = await balanceOf(cranker.publicKey);
+    assert.equal(crankerAfter - crankerBefore, reward.toNumber() - rentPaid - confirmed.meta.fee);
+
+    await setLaunchCrank(3600);
+  });
+
+  it("Revokes the freeze authority of a transfer-locked token once holders are thawed", async () => {
+    const id = await createTestToken(user, { transferLockedUntilLaunch: true });
+    const pdas = tokenPdas(id);
+    const buyerAta = getAssociatedTokenAddressSync(pdas.mint, user.publicKey, false, TOKEN_2022_PROGRAM_ID);
+    await buyTestToken(id, user, new anchor.BN(3 * LAMPORTS_PER_SOL));
+    assert.equal((await program.account.tokenInfo.fetch(pdas.tokenInfo)).frozenHolderAccounts.toNumber(), 1);
+    await launchTestToken(id, user);
+
+    // Only the moderator may revoke, since frozen accounts it is not given stay frozen for good
+    const outsider = Keypair.generate();
+    await fundWallet(outsider.publicKey, 1);
+    try {
+      await finalizeUnlockFor(id, [], outsider);
+      assert.fail("Finalize should have failed");
+    } catch (e) {
+      assert.include(e.message, "Unauthorized");
+    }
+
+    // The buyer still needs the bonding curve to thaw them
+    try {
+      await finalizeUnlockFor(id);
+      assert.fail("Finalize should have failed");
+    } catch (e) {
+      assert.include(e.message, "HoldersStillFrozen");
+    }
+
+    await program.methods
+      .thawTokenAccount(id)
+      .accountsPartial({
+        tokenInfo: pdas.tokenInfo,
+        bondingCurve: pdas.bondingCurve,
+        mint: pdas.mint,
+        tokenAccount: buyerAta,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .rpc();
+    assert.equal((await program.account.tokenInfo.fetch(pdas.tokenInfo)).frozenHolderAccounts.toNumber(), 0);
+
+    await finalizeUnlockFor(id);
+    const mint = await getMint(provider.connection, pdas.mint, "confirmed", TOKEN_2022_PROGRAM_ID);
+    assert.isNull(mint.freezeAuthority);
+  });
+
+  it("Thaws empty accounts created before launch when finalizing the unlock", async () => {
+    const id = await createTestToken(user, { transferLockedUntilLaunch: true });
+    const pdas = tokenPdas(id);
+    const userAta = getAssociatedTokenAddressSync(pdas.mint, user.publicKey, false, TOKEN_2022_PROGRAM_ID);
+
+    // Anyone can open a (frozen) account for a wallet before launch and never fund it
+    const recipient = Keypair.generate();
+    const recipientAta = getAssociatedTokenAddressSync(pdas.mint, recipient.publicKey, false, TOKEN_2022_PROGRAM_ID);
+    await provider.sendAndConfirm(
+      new anchor.web3.Transaction().add(
+        createAssociatedTokenAccountIdempotentInstruction(
+          provider.wallet.publicKey,
+          recipientAta,
+          recipient.publicKey,
+          pdas.mint,
+          TOKEN_2022_PROGRAM_ID
+        )
+      )
+    );
+    assert.isTrue((await getAccount(provider.connection, recipientAta, "confirmed", TOKEN_2022_PROGRAM_ID)).isFrozen);
+
+    await buyTestToken(id, user, new anchor.BN(3 * LAMPORTS_PER_SOL));
+    await launchTestToken(id, user);
+
+    // Both the buyer's and the never-funded account are thawed before the freeze authority goes
+    await finalizeUnlockFor(id, [userAta, recipientAta]);
+    const mint = await getMint(provider.connection, pdas.mint, "confirmed", TOKEN_2022_PROGRAM_ID);
+    assert.isNull(mint.freezeAuthority);
+    assert.equal((await program.account.tokenInfo.fetch(pdas.tokenInfo)).frozenHolderAccounts.toNumber(), 0);
+
+    const amount = (await tokenBalance(userAta)).divn(2);
+    await provider.sendAndConfirm(
+      new anchor.web3.Transaction().add(
+        createTransferCheckedInstruction(
+          userAta,
+          pdas.mint,
+          recipientAta,
+          user.publicKey,
+          BigInt(amount.toString()),
+          tokenDecimals,
+          [],
+          TOKEN_2022_PROGRAM_ID
+        )
+      ),
+      [user]
+    );
+    assert.equal((await tokenBalance(recipientAta)).toString(), amount.toString());
+  });
+
+  it("Counts wallet-to-wallet transfers to fresh wallets as holders", async () => {
+    const state = await program.account.programState.fetch(programStatePda);
+    const pdas = tokenPdas(state.tokenCount.addn(1));
+    const trackerAccounts = holderTrackerAccounts(pdas.mint);
+    const id = await createTestToken(user, { holderTracking: true }, {
+      ...trackerAccounts,
+      extraAccountMetaList: PublicKey.findProgramAddressSync(
+        [Buffer.from("extra-account-metas"), pdas.mint.toBuffer()],
+        holderTracker.programId
+      )[0],
+    });
+    const holderCount = async () =>
+      (await holderTracker.account.holderStats.fetch(trackerAccounts.holderStats)).holderCount.toNumber();
+
+    const userAta = getAssociatedTokenAddressSync(pdas.mint, user.publicKey, false, TOKEN_2022_PROGRAM_ID);
+    await buyTestToken(id, user, new anchor.BN(LAMPORTS_PER_SOL), {
+      ...trackerAccounts,
+      holderPosition: holderPositionFor(userAta),
+    });
+    assert.equal(await holderCount(), 1);
+
+    // The recipient never traded on the launchpad
+    const recipient = Keypair.generate();
+    const recipientAta = getAssociatedTokenAddressSync(pdas.mint, recipient.publicKey, false, TOKEN_2022_PROGRAM_ID);
+    const transferToRecipient = async (amount: anchor.BN) => {
+      const tx = new anchor.web3.Transaction().add(
+        createAssociatedTokenAccountIdempotentInstruction(
+          user.publicKey,
+          recipientAta,
+          recipient.publicKey,
+          pdas.mint,
+          TOKEN_2022_PROGRAM_ID
+        ),
+        await createTransferCheckedWithTransferHookInstruction(
+          provider.connection,
+          userAta,
+          pdas.mint,
+          recipientAta,
+          user.publicKey,
+          BigInt(amount.toString()),
+          tokenDecimals,
+          [],
+          "confirmed",
+          TOKEN_2022_PROGRAM_ID
+        )
+      );
+      await provider.sendAndConfirm(tx, [user]);
+    };
+
+    // The hook opens the recipient's position from the rent reserve
+    const balance = await tokenBalance(userAta);
+    await transferToRecipient(balance.divn(2));
+    assert.equal(await holderCount(), 2);
+    const recipientPosition = await holderTracker.account.holderPosition.fetch(holderPositionFor(recipientAta));
+    assert.equal(recipientPosition.balance.toString(), (await tokenBalance(recipientAta)).toString());
+
+    // Emptying the buyer's account counts them out and closes their position, the recipient stays in
+    await transferToRecipient(await tokenBalance(userAta));
+    assert.equal(await holderCount(), 1);
+    assert.isNull(await provider.connection.getAccountInfo(holderPositionFor(userAta), "confirmed"));
+
+    // The hook is only reachable through a Token-2022 transfer
+    try {
+      await holderTracker.methods
+        .execute(new anchor.BN(1))
+        .accountsPartial({
+          sourceToken: recipientAta,
+          mint: pdas.mint,
+          destinationToken: userAta,
+          owner: recipient.publicKey,
+          holderStats: trackerAccounts.holderStats,
+          sourcePosition: holderPositionFor(recipientAta),
+          destinationPosition: holderPositionFor(userAta),
+        })
+        .rpc();
+      assert.fail("Direct execute should have failed");
+    } catch (e) {
+      assert.include(e.message, "NotTransferring");
     }
+
+    // Burns bypass the hook; anyone can sync the position to the real balance afterwards
+    await fundWallet(recipient.publicKey, 1);
+    await provider.sendAndConfirm(
+      new anchor.web3.Transaction().add(
+        createBurnCheckedInstruction(
+          recipientAta,
+          pdas.mint,
+          recipient.publicKey,
+          BigInt((await tokenBalance(recipientAta)).toString()),
+          tokenDecimals,
+          [],
+          TOKEN_2022_PROGRAM_ID
+        )
+      ),
+      [recipient]
+    );
+    assert.equal(await holderCount(), 1);
+    await holderTracker.methods
+      .syncPosition()
+      .accountsPartial({
+        mint: pdas.mint,
+        tokenAccount: recipientAta,
+        holderStats: trackerAccounts.holderStats,
+        position: holderPositionFor(recipientAta),
+        payer: provider.wallet.publicKey,
+      })
+      .rpc();
+    assert.equal(await holderCount(), 0);
+
+    // TokenInfo picks up the transfers and the burn once refreshed
+    await program.methods
+      .refreshHolderCount(id)
+      .accountsPartial({
+        tokenInfo: pdas.tokenInfo,
+        holderStats: trackerAccounts.holderStats,
+      })
+      .rpc();
+    assert.equal((await program.account.tokenInfo.fetch(pdas.tokenInfo)).holderCount.toNumber(), 0);
   });
 
-  it("Updates platform settings", async () => {
+  it("Keeps the vested creator allocation escrowed until launch", async () => {
+    const state = await program.account.programState.fetch(programStatePda);
+    const id = state.tokenCount.addn(1);
+    const pdas = tokenPdas(id);
+    const [vestingEscrow] = PublicKey.findProgramAddressSync(
+      [Buffer.from("vesting_escrow"), id.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    const vestingTokenAccount = getAssociatedTokenAddressSync(pdas.mint, vestingEscrow, true, TOKEN_2022_PROGRAM_ID);
+    await createTestToken(
+      user,
+      { creatorVesting: { allocationBps: 500, cliffDuration: new anchor.BN(0), vestingDuration: new anchor.BN(1) } },
+      { vestingEscrow, vestingTokenAccount }
+    );
+    await new Promise((resolve) => setTimeout(resolve, 2000));
+
+    // Fully vested, but claimed tokens could be sold straight into the curve
+    try {
+      await program.methods
+        .claimVested(id)
+        .accountsPartial({
+          tokenInfo: pdas.tokenInfo,
+          vestingEscrow,
+          vestingTokenAccount,
+          mint: pdas.mint,
+          beneficiary: user.publicKey,
+          creatorTokenAccount: getAssociatedTokenAddressSync(pdas.mint, user.publicKey, false, TOKEN_2022_PROGRAM_ID),
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .signers([user])
+        .rpc();
+      assert.fail("Claim should have failed");
+    } catch (e) {
+      assert.include(e.message, "TokenNotLaunched");
+    }
+  });
+
+  it("Opens airdrop claims at launch", async () => {
+    const state = await program.account.programState.fetch(programStatePda);
+    const id = state.tokenCount.addn(1);
+    const pdas = tokenPdas(id);
+    const idBytes = id.toArrayLike(Buffer, "le", 8);
+    const [airdrop] = PublicKey.findProgramAddressSync([Buffer.from("airdrop"), idBytes], program.programId);
+    const airdropTokenAccount = getAssociatedTokenAddressSync(pdas.mint, airdrop, true, TOKEN_2022_PROGRAM_ID);
+
+    // Single-leaf tree: the root is the claimant's leaf and the proof is empty
+    const claimAmount = new anchor.BN(1_000_000);
+    const leaf = createHash("sha256")
+      .update(Buffer.concat([user.publicKey.toBuffer(), claimAmount.toArrayLike(Buffer, "le", 8)]))
+      .digest();
+    await createTestToken(user, { airdrop: { allocationBps: 100, merkleRoot: [...leaf] } }, { airdrop });
+
+    const claimAirdrop = (escrowTokenAccount: PublicKey | null) =>
+      program.methods
+        .claimAirdrop(id, claimAmount, [])
+        .accountsPartial({
+          tokenInfo: pdas.tokenInfo,
+          airdrop,
+          airdropClaim: PublicKey.findProgramAddressSync(
+            [Buffer.from("airdrop_claim"), idBytes, user.publicKey.toBuffer()],
+            program.programId
+          )[0],
+          mint: pdas.mint,
+          airdropTokenAccount: escrowTokenAccount,
+          claimant: user.publicKey,
+          claimantTokenAccount: getAssociatedTokenAddressSync(pdas.mint, user.publicKey, false, TOKEN_2022_PROGRAM_ID),
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .signers([user])
+        .rpc();
+
+    // Airdropped tokens could otherwise be sold straight into the curve
+    try {
+      await claimAirdrop(null);
+      assert.fail("Claim should have failed");
+    } catch (e) {
+      assert.include(e.message, "TokenNotLaunched");
+    }
+
+    await buyTestToken(id, user, new anchor.BN(3 * LAMPORTS_PER_SOL));
+    await launchTestToken(id, user, { airdrop, airdropTokenAccount });
+    const circulatingBefore = (await program.account.tokenInfo.fetch(pdas.tokenInfo)).circulatingSupply;
+    const userAta = getAssociatedTokenAddressSync(pdas.mint, user.publicKey, false, TOKEN_2022_PROGRAM_ID);
+    const balanceBefore = await tokenBalance(userAta);
+
+    await claimAirdrop(airdropTokenAccount);
+
+    assert.equal((await tokenBalance(userAta)).sub(balanceBefore).toString(), claimAmount.toString());
+    const info = await program.account.tokenInfo.fetch(pdas.tokenInfo);
+    assert.equal(info.circulatingSupply.sub(circulatingBefore).toString(), claimAmount.toString());
+  });
+
+  it("Schedules platform settings behind the governance delay", async () => {
      // Only deployer can update (platform authority)
      const newFee = new anchor.BN(300); // 3%
      const newThreshold = new anchor.BN(500_000_000_000); // 500 SOL
+     const newDelay = new anchor.BN(86_400); // 24 hours
      
-     await program.methods.updatePlatformSettings(newFee, newThreshold)
+     await program.methods.schedulePlatformSettings(newFee, newThreshold, newDelay)
      .accountsPartial({
          programState: programStatePda,
-         authority: provider.wallet.publicKey,
+         feeManager: provider.wallet.publicKey,
      })
      .rpc();
      
-     const state = await program.account.programState.fetch(programStatePda);
-     assert.equal(state.platformFeeRate.toNumber(), 300);
-     assert.equal(state.launchThreshold.toNumber(), 500_000_000_000);
+     let state = await program.account.programState.fetch(programStatePda);
+     assert.equal(state.pendingSettings.feeRate.toNumber(), 300);
+     assert.equal(state.pendingSettings.launchThreshold.toNumber(), 500_000_000_000);
+     assert.isTrue(state.pendingSettings.executableAt.gt(state.initializedAt));
+     assert.equal(state.platformFeeRate.toNumber(), 250);
+
+     // The change cannot be applied before the delay elapses
+     try {
+       await program.methods.executePlatformSettings()
+       .accountsPartial({
+           programState: programStatePda,
+           feeManager: provider.wallet.publicKey,
+       })
+       .rpc();
+       assert.fail("Execute should have failed");
+     } catch (e) {
+       assert.include(e.message, "TimelockNotElapsed");
+     }
+
+     await program.methods.cancelPlatformSettings()
+     .accountsPartial({
+         programState: programStatePda,
+         feeManager: provider.wallet.publicKey,
+     })
+     .rpc();
+     state = await program.account.programState.fetch(programStatePda);
+     assert.isNull(state.pendingSettings);
+  });
+
+  it("Schedules platform limits behind the governance delay", async () => {
+    const state = await program.account.programState.fetch(programStatePda);
+    assert.equal(state.limits.launchLiquidityBps.toNumber(), 8000);
+    assert.equal(state.limits.maxTokenPurchase.toNumber(), 10 * LAMPORTS_PER_SOL);
+
+    const newLimits = {
+      ...state.limits,
+      maxTokenPurchase: new anchor.BN(25 * LAMPORTS_PER_SOL),
+      launchLiquidityBps: new anchor.BN(8500),
+    };
+
+    // Limits outside the hard bounds are rejected
+    try {
+      await program.methods.schedulePlatformLimits({ ...newLimits, launchLiquidityBps: new anchor.BN(4000) })
+        .accountsPartial({
+          programState: programStatePda,
+          authority: provider.wallet.publicKey,
+        })
+        .rpc();
+      assert.fail("Schedule should have failed");
+    } catch (e) {
+      assert.include(e.message, "InvalidPlatformLimits");
+    }
+
+    await program.methods.schedulePlatformLimits(newLimits)
+      .accountsPartial({
+        programState: programStatePda,
+        authority: provider.wallet.publicKey,
+      })
+      .rpc();
+    let updated = await program.account.programState.fetch(programStatePda);
+    assert.equal(updated.pendingLimits.limits.launchLiquidityBps.toNumber(), 8500);
+    assert.equal(updated.limits.launchLiquidityBps.toNumber(), 8000);
+
+    await program.methods.cancelPlatformLimits()
+      .accountsPartial({
+        programState: programStatePda,
+        authority: provider.wallet.publicKey,
+      })
+      .rpc();
+    updated = await program.account.programState.fetch(programStatePda);
+    assert.isNull(updated.pendingLimits);
+  });
+
+  it("Schedules a treasury change behind the governance delay", async () => {
+    const newTreasury = Keypair.generate().publicKey;
+
+    await program.methods.schedulePlatformTreasury(newTreasury)
+      .accountsPartial({
+        programState: programStatePda,
+        treasurer: provider.wallet.publicKey,
+      })
+      .rpc();
+    let state = await program.account.programState.fetch(programStatePda);
+    assert.equal(state.pendingTreasury.treasury.toBase58(), newTreasury.toBase58());
+
+    try {
+      await program.methods.executePlatformTreasury()
+        .accountsPartial({
+          programState: programStatePda,
+          treasurer: provider.wallet.publicKey,
+        })
+        .rpc();
+      assert.fail("Execute should have failed");
+    } catch (e) {
+      assert.include(e.message, "TimelockNotElapsed");
+    }
+
+    await program.methods.cancelPlatformTreasury()
+      .accountsPartial({
+        programState: programStatePda,
+        treasurer: provider.wallet.publicKey,
+      })
+      .rpc();
+    state = await program.account.programState.fetch(programStatePda);
+    assert.isNull(state.pendingTreasury);
+    assert.notEqual(state.platformTreasury.toBase58(), newTreasury.toBase58());
+  });
+
+  it("Withdraws platform fees to the treasury", async () => {
+    const [platformFeeVaultPda] = PublicKey.findProgramAddressSync(
+      [PLATFORM_FEE_VAULT_SEED],
+      program.programId
+    );
+    const stateBefore = await program.account.programState.fetch(programStatePda);
+    const vaultInfo = await provider.connection.getAccountInfo(platformFeeVaultPda);
+    const rentReserve = await provider.connection.getMinimumBalanceForRentExemption(vaultInfo.data.length);
+    const available = new anchor.BN(vaultInfo.lamports - rentReserve);
+
+    // Draining the vault below its rent reserve is rejected
+    try {
+      await program.methods.withdrawPlatformFees(available.addn(1))
+        .accountsPartial({
+          programState: programStatePda,
+          platformFeeVault: platformFeeVaultPda,
+          treasurer: provider.wallet.publicKey,
+          treasury: stateBefore.platformTreasury,
+        })
+        .rpc();
+      assert.fail("Withdrawal should have failed");
+    } catch (e) {
+      assert.include(e.message, "InsufficientSolBalance");
+    }
+
+    await program.methods.withdrawPlatformFees(available)
+      .accountsPartial({
+        programState: programStatePda,
+        platformFeeVault: platformFeeVaultPda,
+        treasurer: provider.wallet.publicKey,
+        treasury: stateBefore.platformTreasury,
+      })
+      .rpc();
+
+    const state = await program.account.programState.fetch(programStatePda);
+    assert.equal(
+      state.totalFeesWithdrawn.toString(),
+      stateBefore.totalFeesWithdrawn.add(available).toString()
+    );
+    assert.equal(await provider.connection.getBalance(platformFeeVaultPda), rentReserve);
+  });
+
+  it("Transfers platform authority in two steps", async () => {
+    const newAuthority = Keypair.generate();
+
+    // A cancelled proposal cannot be accepted
+    await program.methods.proposePlatformAuthority(newAuthority.publicKey)
+      .accountsPartial({
+        programState: programStatePda,
+        currentAuthority: provider.wallet.publicKey,
+      })
+      .rpc();
+    await program.methods.cancelPlatformAuthorityTransfer()
+      .accountsPartial({
+        programState: programStatePda,
+        authority: provider.wallet.publicKey,
+      })
+      .rpc();
+    let state = await program.account.programState.fetch(programStatePda);
+    assert.isNull(state.pendingPlatformAuthority);
+
+    try {
+      await program.methods.acceptPlatformAuthority()
+        .accountsPartial({
+          programState: programStatePda,
+          newAuthority: newAuthority.publicKey,
+        })
+        .signers([newAuthority])
+        .rpc();
+      assert.fail("Accept should have failed");
+    } catch (e) {
+      assert.include(e.message, "Unauthorized");
+    }
+
+    // Hand over to the new authority and back again
+    await program.methods.proposePlatformAuthority(newAuthority.publicKey)
+      .accountsPartial({
+        programState: programStatePda,
+        currentAuthority: provider.wallet.publicKey,
+      })
+      .rpc();
+    await program.methods.acceptPlatformAuthority()
+      .accountsPartial({
+        programState: programStatePda,
+        newAuthority: newAuthority.publicKey,
+      })
+      .signers([newAuthority])
+      .rpc();
+    state = await program.account.programState.fetch(programStatePda);
+    assert.equal(state.platformAuthority.toBase58(), newAuthority.publicKey.toBase58());
+    assert.isNull(state.pendingPlatformAuthority);
+
+    await program.methods.proposePlatformAuthority(provider.wallet.publicKey)
+      .accountsPartial({
+        programState: programStatePda,
+        currentAuthority: newAuthority.publicKey,
+      })
+      .signers([newAuthority])
+      .rpc();
+    await program.methods.acceptPlatformAuthority()
+      .accountsPartial({
+        programState: programStatePda,
+        newAuthority: provider.wallet.publicKey,
+      })
+      .rpc();
+    state = await program.account.programState.fetch(programStatePda);
+    assert.equal(state.platformAuthority.toBase58(), provider.wallet.publicKey.toBase58());
+  });
+
+  it("Sets granular pause flags", async () => {
+    const noPause = { create: false, buy: false, sell: false, launch: false, withdraw: false };
+    const incident = { ...noPause, buy: true, launch: true };
+    const now = Math.floor(Date.now() / 1000);
+
+    // An auto-unpause time in the past is rejected
+    try {
+      await program.methods.setPauseFlags(incident, 7, new anchor.BN(now - 60))
+        .accountsPartial({
+          programState: programStatePda,
+          pauser: provider.wallet.publicKey,
+        })
+        .rpc();
+      assert.fail("Pause should have failed");
+    } catch (e) {
+      assert.include(e.message, "InvalidPauseSchedule");
+    }
+
+    // Stop buys and launches while sells stay open, lapsing after an hour
+    await program.methods.setPauseFlags(incident, 7, new anchor.BN(now + 3600))
+      .accountsPartial({
+        programState: programStatePda,
+        pauser: provider.wallet.publicKey,
+      })
+      .rpc();
+    let state = await program.account.programState.fetch(programStatePda);
+    assert.isTrue(state.pauseFlags.buy);
+    assert.isTrue(state.pauseFlags.launch);
+    assert.isFalse(state.pauseFlags.sell);
+    assert.equal(state.pauseReason, 7);
+    assert.equal(state.pausedUntil.toNumber(), now + 3600);
+
+    await program.methods.setPauseFlags(noPause, 0, null)
+      .accountsPartial({
+        programState: programStatePda,
+        pauser: provider.wallet.publicKey,
+      })
+      .rpc();
+    state = await program.account.programState.fetch(programStatePda);
+    assert.isFalse(state.pauseFlags.buy);
+    assert.isNull(state.pausedUntil);
+  });
+
+  it("Delegates the pauser role", async () => {
+    const onCall = Keypair.generate();
+
+    await program.methods.updatePlatformRole({ pauser: {} }, onCall.publicKey)
+      .accountsPartial({
+        programState: programStatePda,
+        authority: provider.wallet.publicKey,
+      })
+      .rpc();
+    let state = await program.account.programState.fetch(programStatePda);
+    assert.equal(state.pauser.toBase58(), onCall.publicKey.toBase58());
+    assert.equal(state.treasurer.toBase58(), provider.wallet.publicKey.toBase58());
+
+    // The pauser can pause but cannot touch the treasury
+    await program.methods.setPauseFlags(
+        { create: true, buy: true, sell: true, launch: true, withdraw: true },
+        1,
+        null
+      )
+      .accountsPartial({
+        programState: programStatePda,
+        pauser: onCall.publicKey,
+      })
+      .signers([onCall])
+      .rpc();
+    state = await program.account.programState.fetch(programStatePda);
+    assert.isTrue(state.pauseFlags.buy);
+
+    try {
+      await program.methods.schedulePlatformTreasury(onCall.publicKey)
+        .accountsPartial({
+          programState: programStatePda,
+          treasurer: onCall.publicKey,
+        })
+        .signers([onCall])
+        .rpc();
+      assert.fail("Treasury change should have failed");
+    } catch (e) {
+      assert.include(e.message, "Unauthorized");
+    }
+
+    await program.methods.setPauseFlags(
+        { create: false, buy: false, sell: false, launch: false, withdraw: false },
+        0,
+        null
+      )
+      .accountsPartial({
+        programState: programStatePda,
+        pauser: onCall.publicKey,
+      })
+      .signers([onCall])
+      .rpc();
+
+    await program.methods.updatePlatformRole({ pauser: {} }, provider.wallet.publicKey)
+      .accountsPartial({
+        programState: programStatePda,
+        authority: provider.wallet.publicKey,
+      })
+      .rpc();
+    state = await program.account.programState.fetch(programStatePda);
+    assert.isFalse(state.pauseFlags.buy);
+    assert.equal(state.pauser.toBase58(), provider.wallet.publicKey.toBase58());
   });
 });
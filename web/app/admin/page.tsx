@@ -3,7 +3,16 @@
 import React, { useEffect, useState } from "react";
 import { useAnchorWallet } from "@solana/wallet-adapter-react";
 import { BN } from "@coral-xyz/anchor";
-import { getProvider, getProviderReadonly, getProgramState, initialize, updatePlatformSettings } from "@/service/services";
+import {
+  getProvider,
+  getProviderReadonly,
+  getProgramState,
+  initialize,
+  schedulePlatformSettings,
+  executePlatformSettings,
+  DEFAULT_LAUNCH_THRESHOLD,
+  DEFAULT_PLATFORM_LIMITS,
+} from "@/service/services";
 import { WalletMultiButton } from "@solana/wallet-adapter-react-ui";
 import { LAMPORTS_PER_SOL } from "@solana/web3.js";
 
@@ -12,7 +21,7 @@ export default function AdminPage() {
   const [programState, setProgramState] = useState<any>(null);
   const [loading, setLoading] = useState(false);
   const [feeRate, setFeeRate] = useState("");
-  const [threshold, setThreshold] = useState("");
+  const [threshold, setThreshold] = useState((DEFAULT_LAUNCH_THRESHOLD.toNumber() / LAMPORTS_PER_SOL).toString());
   const [status, setStatus] = useState("");
   const [isInitialized, setIsInitialized] = useState(false);
 
@@ -46,7 +55,8 @@ export default function AdminPage() {
         const provider = getProvider(wallet.publicKey, wallet.signTransaction, wallet.signAllTransactions);
         if (!provider) return;
         
-        await initialize(provider, wallet.publicKey);
+        const launchThreshold = new BN(parseFloat(threshold) * LAMPORTS_PER_SOL);
+        await initialize(provider, wallet.publicKey, launchThreshold, DEFAULT_PLATFORM_LIMITS);
         setStatus("Initialized successfully!");
         fetchData();
     } catch (err) {
@@ -58,7 +68,7 @@ export default function AdminPage() {
   const handleUpdate = async (e: React.FormEvent) => {
     e.preventDefault();
     if (!wallet) return;
-    setStatus("Updating settings...");
+    setStatus("Scheduling settings change...");
     
     try {
         const provider = getProvider(wallet.publicKey, wallet.signTransaction, wallet.signAllTransactions);
@@ -67,12 +77,30 @@ export default function AdminPage() {
         const newFee = new BN(feeRate);
         const newThresh = new BN(parseFloat(threshold) * LAMPORTS_PER_SOL);
         
-        await updatePlatformSettings(provider, wallet.publicKey, newFee, newThresh);
+        // Keep the current governance delay
+        await schedulePlatformSettings(provider, wallet.publicKey, newFee, newThresh, programState.governanceDelay);
+        setStatus("Settings change scheduled!");
+        fetchData();
+    } catch (err) {
+        console.error(err);
+        setStatus("Failed to schedule settings change.");
+    }
+  };
+
+  const handleExecute = async () => {
+    if (!wallet) return;
+    setStatus("Applying scheduled settings...");
+
+    try {
+        const provider = getProvider(wallet.publicKey, wallet.signTransaction, wallet.signAllTransactions);
+        if (!provider) return;
+
+        await executePlatformSettings(provider, wallet.publicKey);
         setStatus("Settings updated successfully!");
         fetchData();
     } catch (err) {
         console.error(err);
-        setStatus("Failed to update settings.");
+        setStatus("Failed to apply settings (the governance delay may not have elapsed).");
     }
   };
 
@@ -99,12 +127,23 @@ export default function AdminPage() {
                     </div>
                     
                     {!isInitialized && (
+                        <>
+                        <div className="mt-6">
+                            <label className="block text-sm text-neutral-400 mb-2">Launch Threshold (SOL)</label>
+                            <input 
+                                type="number"
+                                value={threshold}
+                                onChange={(e) => setThreshold(e.target.value)}
+                                className="block w-full rounded-md border-0 py-1.5 text-white bg-white/5 shadow-sm ring-1 ring-inset ring-white/10 focus:ring-2 focus:ring-inset focus:ring-indigo-500 sm:text-sm sm:leading-6 pl-2"
+                            />
+                        </div>
                         <button 
                             onClick={handleInitialize}
                             className="mt-6 px-6 py-2 bg-blue-600 rounded-lg font-bold hover:bg-blue-500 transition-colors"
                         >
                             Initialize Program
                         </button>
+                        </>
                     )}
                 </div>
 
@@ -139,9 +178,24 @@ export default function AdminPage() {
                                 type="submit"
                                 className="w-full py-3 bg-green-600 rounded-lg font-bold hover:bg-green-500 transition-colors"
                             >
-                                Update Settings
+                                Schedule Settings Change
                             </button>
                         </form>
+
+                        {programState?.pendingSettings && (
+                            <div className="mt-6 pt-6 border-t border-white/10">
+                                <p className="text-sm text-neutral-400">
+                                    Pending: {programState.pendingSettings.feeRate.toString()} bps, {programState.pendingSettings.launchThreshold.toNumber() / LAMPORTS_PER_SOL} SOL
+                                    {" "}(executable {new Date(programState.pendingSettings.executableAt.toNumber() * 1000).toLocaleString()})
+                                </p>
+                                <button 
+                                    onClick={handleExecute}
+                                    className="mt-4 w-full py-3 bg-blue-600 rounded-lg font-bold hover:bg-blue-500 transition-colors"
+                                >
+                                    Execute Scheduled Change
+                                </button>
+                            </div>
+                        )}
                     </div>
                 )}
 
@@ -11,12 +11,9 @@ import {
   PublicKey,
   Transaction,
   LAMPORTS_PER_SOL,
-  SystemProgram,
-  SYSVAR_RENT_PUBKEY,
 } from "@solana/web3.js";
 import { BN } from "@coral-xyz/anchor";
-import { getProviderReadonly, RPC_URL } from "@/service/services";
-import { TOKEN_2022_PROGRAM_ID, ASSOCIATED_TOKEN_PROGRAM_ID, getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { buyTokenInstruction, getProviderReadonly, RPC_URL } from "@/service/services";
 
 // Standard headers for CORS
 const headers = createActionHeaders({ headers: ACTIONS_CORS_HEADERS });
@@ -144,53 +141,9 @@ export const POST = async (
         );
         const tokenInfo = await program.account.tokenInfo.fetch(tokenInfoPda);
         
-        const [bondingCurvePda] = PublicKey.findProgramAddressSync(
-            [Buffer.from("bonding_curve"), tokenId.toArrayLike(Buffer, "le", 8)],
-            program.programId
-        );
-        const [programStatePda] = PublicKey.findProgramAddressSync(
-            [Buffer.from("program_state")],
-            program.programId
-        );
-        
-        // Transaction PDA derived with [transaction, buyer, tokenId, nextTxId]
-        const nextTxId = tokenInfo.transactionCount.add(new BN(1));
-        const [transactionPda] = PublicKey.findProgramAddressSync(
-            [
-                Buffer.from("transaction"),
-                account.toBuffer(),
-                tokenId.toArrayLike(Buffer, "le", 8),
-                nextTxId.toArrayLike(Buffer, "le", 8)
-            ],
-            program.programId
-        );
-        
-        const buyerTokenAccount = getAssociatedTokenAddressSync(
-            tokenInfo.mint,
-            account,
-            false,
-            TOKEN_2022_PROGRAM_ID
-        );
-        
         const solAmount = new BN(amount * LAMPORTS_PER_SOL);
         
-        const ix = await program.methods
-            .buyToken(tokenId, solAmount)
-            .accountsPartial({
-                programState: programStatePda,
-                tokenInfo: tokenInfoPda,
-                bondingCurve: bondingCurvePda,
-                transaction: transactionPda,
-                mint: tokenInfo.mint,
-                buyerTokenAccount: buyerTokenAccount,
-                tokenCreator: tokenInfo.creator,
-                buyer: account,
-                tokenProgram: TOKEN_2022_PROGRAM_ID,
-                associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
-                systemProgram: SystemProgram.programId,
-                rent: SYSVAR_RENT_PUBKEY,
-            })
-            .instruction();
+        const ix = await buyTokenInstruction(program, account, tokenId, solAmount);
             
         const { blockhash } = await connection.getLatestBlockhash();
         const transaction = new Transaction({
@@ -13,6 +13,111 @@ export type MemeLaunchpad = {
     "description": "Created with Anchor"
   },
   "instructions": [
+    {
+      "name": "acceptPlatformAuthority",
+      "discriminator": [
+        147,
+        215,
+        144,
+        55,
+        11,
+        5,
+        151,
+        130
+      ],
+      "accounts": [
+        {
+          "name": "programState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "newAuthority",
+          "signer": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "addGraduationProgram",
+      "discriminator": [
+        145,
+        107,
+        64,
+        184,
+        98,
+        96,
+        81,
+        19
+      ],
+      "accounts": [
+        {
+          "name": "programState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "target",
+          "type": {
+            "defined": {
+              "name": "graduationTarget"
+            }
+          }
+        },
+        {
+          "name": "programId",
+          "type": "pubkey"
+        }
+      ]
+    },
     {
       "name": "buyToken",
       "discriminator": [
@@ -114,11 +219,47 @@ export type MemeLaunchpad = {
           "writable": true
         },
         {
-          "name": "mint",
+          "name": "liquidityPool",
           "docs": [
-            "We validate this in the handler by comparing `mint.key().to_bytes()` == `token_info.mint.to_bytes()`",
-            "and by using it in the token_2022 CPI."
+            "Built-in liquidity pool, required once the token has graduated to it"
           ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  105,
+                  113,
+                  117,
+                  105,
+                  100,
+                  105,
+                  116,
+                  121,
+                  95,
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "tokenId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "poolTokenVault",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "mint",
           "writable": true
         },
         {
@@ -134,17 +275,95 @@ export type MemeLaunchpad = {
           "writable": true
         },
         {
-          "name": "tokenCreator",
+          "name": "platformFeeVault",
           "docs": [
-            "Verified at runtime / via constraint with token_info.creator."
+            "Vault collecting platform fees"
           ],
-          "writable": true
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  116,
+                  102,
+                  111,
+                  114,
+                  109,
+                  95,
+                  102,
+                  101,
+                  101,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "creatorVault",
+          "docs": [
+            "Vault accruing the token's creator fees"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  114,
+                  101,
+                  97,
+                  116,
+                  111,
+                  114,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "tokenId"
+              }
+            ]
+          }
         },
         {
           "name": "buyer",
           "writable": true,
           "signer": true
         },
+        {
+          "name": "holderTrackerProgram",
+          "docs": [
+            "Holder tracker program, required for curve trades of tokens with holder tracking"
+          ],
+          "optional": true,
+          "address": "7gfVkkN4DQpTjZCHZtEY2Sjys1K5dQk9yV992piFrELc"
+        },
+        {
+          "name": "holderStats",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "holderPosition",
+          "writable": true,
+          "optional": true
+        },
         {
           "name": "tokenProgram",
           "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
@@ -177,109 +396,108 @@ export type MemeLaunchpad = {
       ]
     },
     {
-      "name": "createToken",
+      "name": "cancelPlatformAuthorityTransfer",
       "discriminator": [
-        84,
-        52,
-        204,
-        228,
-        24,
-        140,
-        234,
-        75
+        121,
+        185,
+        134,
+        254,
+        241,
+        68,
+        15,
+        49
       ],
       "accounts": [
         {
           "name": "programState",
-          "writable": true
-        },
-        {
-          "name": "tokenInfo",
-          "writable": true
-        },
-        {
-          "name": "bondingCurve",
-          "writable": true
-        },
-        {
-          "name": "mint",
-          "docs": [
-            "The new Mint (SPL Token-2022 compatible)"
-          ],
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
                   109,
-                  105,
-                  110,
-                  116
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
                 ]
-              },
-              {
-                "kind": "account",
-                "path": "bondingCurve"
               }
             ]
           }
         },
         {
-          "name": "creator",
-          "writable": true,
+          "name": "authority",
           "signer": true
-        },
-        {
-          "name": "tokenProgram",
-          "docs": [
-            "Token-2022 program"
-          ],
-          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        },
-        {
-          "name": "rent",
-          "address": "SysvarRent111111111111111111111111111111111"
         }
       ],
-      "args": [
+      "args": []
+    },
+    {
+      "name": "cancelPlatformLimits",
+      "discriminator": [
+        52,
+        30,
+        137,
+        4,
+        249,
+        162,
+        97,
+        144
+      ],
+      "accounts": [
         {
-          "name": "name",
-          "type": "string"
-        },
-        {
-          "name": "symbol",
-          "type": "string"
-        },
-        {
-          "name": "uri",
-          "type": "string"
-        },
-        {
-          "name": "decimals",
-          "type": "u8"
+          "name": "programState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
         },
         {
-          "name": "initialSupply",
-          "type": "u64"
+          "name": "authority",
+          "signer": true
         }
-      ]
+      ],
+      "args": []
     },
     {
-      "name": "initialize",
+      "name": "cancelPlatformSettings",
       "discriminator": [
-        175,
-        175,
-        109,
-        31,
-        13,
-        152,
-        155,
-        237
+        73,
+        112,
+        161,
+        162,
+        226,
+        65,
+        171,
+        109
       ],
       "accounts": [
         {
@@ -309,28 +527,23 @@ export type MemeLaunchpad = {
           }
         },
         {
-          "name": "deployer",
-          "writable": true,
+          "name": "feeManager",
           "signer": true
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
         }
       ],
       "args": []
     },
     {
-      "name": "launchToDex",
+      "name": "cancelPlatformTreasury",
       "discriminator": [
-        195,
-        102,
-        177,
-        151,
-        21,
-        235,
-        134,
-        205
+        224,
+        223,
+        33,
+        59,
+        163,
+        65,
+        155,
+        30
       ],
       "accounts": [
         {
@@ -359,6 +572,26 @@ export type MemeLaunchpad = {
             ]
           }
         },
+        {
+          "name": "treasurer",
+          "signer": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claimAirdrop",
+      "discriminator": [
+        137,
+        50,
+        122,
+        111,
+        89,
+        254,
+        8,
+        20
+      ],
+      "accounts": [
         {
           "name": "tokenInfo",
           "writable": true,
@@ -387,26 +620,20 @@ export type MemeLaunchpad = {
           }
         },
         {
-          "name": "bondingCurve",
+          "name": "airdrop",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  98,
-                  111,
-                  110,
-                  100,
+                  97,
                   105,
-                  110,
-                  103,
-                  95,
-                  99,
-                  117,
                   114,
-                  118,
-                  101
+                  100,
+                  114,
+                  111,
+                  112
                 ]
               },
               {
@@ -417,37 +644,38 @@ export type MemeLaunchpad = {
           }
         },
         {
-          "name": "transaction",
+          "name": "airdropClaim",
+          "docs": [
+            "One claim per wallet"
+          ],
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  116,
-                  114,
-                  97,
-                  110,
-                  115,
                   97,
-                  99,
-                  116,
                   105,
+                  114,
+                  100,
+                  114,
                   111,
-                  110
+                  112,
+                  95,
+                  99,
+                  108,
+                  97,
+                  105,
+                  109
                 ]
               },
-              {
-                "kind": "account",
-                "path": "launcher"
-              },
               {
                 "kind": "arg",
                 "path": "tokenId"
               },
               {
-                "kind": "arg",
-                "path": "nextTxId"
+                "kind": "account",
+                "path": "claimant"
               }
             ]
           }
@@ -457,18 +685,83 @@ export type MemeLaunchpad = {
           "writable": true
         },
         {
-          "name": "tokenCreator",
-          "writable": true
+          "name": "airdropTokenAccount",
+          "docs": [
+            "Airdrop escrow token account, created at launch"
+          ],
+          "writable": true,
+          "optional": true
         },
         {
-          "name": "launcher",
+          "name": "claimant",
           "writable": true,
           "signer": true
         },
+        {
+          "name": "claimantTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "claimant"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
         {
           "name": "tokenProgram",
           "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
         },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
         {
           "name": "systemProgram",
           "address": "11111111111111111111111111111111"
@@ -480,22 +773,33 @@ export type MemeLaunchpad = {
           "type": "u64"
         },
         {
-          "name": "liquidityAmount",
+          "name": "amount",
           "type": "u64"
+        },
+        {
+          "name": "proof",
+          "type": {
+            "vec": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
         }
       ]
     },
     {
-      "name": "sellToken",
+      "name": "claimCreatorFees",
       "discriminator": [
-        109,
-        61,
-        40,
-        187,
-        230,
-        176,
-        135,
-        174
+        0,
+        23,
+        125,
+        234,
+        156,
+        118,
+        134,
+        89
       ],
       "accounts": [
         {
@@ -525,7 +829,6 @@ export type MemeLaunchpad = {
         },
         {
           "name": "tokenInfo",
-          "writable": true,
           "pda": {
             "seeds": [
               {
@@ -551,26 +854,26 @@ export type MemeLaunchpad = {
           }
         },
         {
-          "name": "bondingCurve",
+          "name": "creatorVault",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  98,
-                  111,
-                  110,
-                  100,
-                  105,
-                  110,
-                  103,
-                  95,
                   99,
-                  117,
                   114,
+                  101,
+                  97,
+                  116,
+                  111,
+                  114,
+                  95,
                   118,
-                  101
+                  97,
+                  117,
+                  108,
+                  116
                 ]
               },
               {
@@ -581,189 +884,3403 @@ export type MemeLaunchpad = {
           }
         },
         {
-          "name": "transaction",
-          "writable": true
-        },
-        {
-          "name": "mint",
-          "writable": true
-        },
-        {
-          "name": "sellerTokenAccount",
-          "writable": true
-        },
-        {
-          "name": "tokenCreator",
-          "writable": true
-        },
-        {
-          "name": "seller",
+          "name": "creator",
           "writable": true,
-          "signer": true
-        },
-        {
-          "name": "tokenProgram",
-          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
-        },
-        {
-          "name": "associatedTokenProgram",
-          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
+          "signer": true,
+          "relations": [
+            "creatorVault"
+          ]
         }
       ],
       "args": [
         {
           "name": "tokenId",
           "type": "u64"
-        },
-        {
-          "name": "tokenAmount",
-          "type": "u64"
         }
       ]
     },
     {
-      "name": "updatePlatformSettings",
+      "name": "claimLockedLp",
       "discriminator": [
-        213,
-        238,
-        2,
-        39,
-        128,
-        157,
-        3,
-        95
+        40,
+        250,
+        89,
+        173,
+        229,
+        111,
+        129,
+        10
       ],
       "accounts": [
         {
-          "name": "programState",
+          "name": "tokenInfo",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
-                  114,
+                  116,
                   111,
-                  103,
-                  114,
-                  97,
-                  109,
+                  107,
+                  101,
+                  110,
                   95,
-                  115,
-                  116,
-                  97,
-                  116,
-                  101
+                  105,
+                  110,
+                  102,
+                  111
                 ]
+              },
+              {
+                "kind": "arg",
+                "path": "tokenId"
               }
             ]
           }
         },
         {
-          "name": "authority",
+          "name": "launchEscrow",
+          "docs": [
+            "Launch escrow holding the locked LP tokens"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  97,
+                  117,
+                  110,
+                  99,
+                  104,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "tokenId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "escrowLpAccount",
+          "writable": true
+        },
+        {
+          "name": "lpMint"
+        },
+        {
+          "name": "creatorLpAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "creator"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "lpMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "creator",
+          "writable": true,
           "signer": true
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
         }
       ],
       "args": [
         {
-          "name": "newFeeRate",
-          "type": "u64"
-        },
-        {
-          "name": "newLaunchThreshold",
+          "name": "tokenId",
           "type": "u64"
         }
       ]
-    }
-  ],
-  "accounts": [
-    {
-      "name": "bondingCurve",
-      "discriminator": [
-        23,
-        183,
-        248,
-        55,
-        96,
-        216,
-        172,
-        96
-      ]
-    },
-    {
-      "name": "programState",
-      "discriminator": [
-        77,
-        209,
-        137,
-        229,
-        149,
-        67,
-        167,
-        230
-      ]
     },
     {
-      "name": "tokenInfo",
+      "name": "claimVested",
       "discriminator": [
-        109,
-        162,
-        52,
-        125,
-        77,
+        208,
+        190,
         166,
-        37,
-        202
+        114,
+        203,
+        225,
+        140,
+        208
+      ],
+      "accounts": [
+        {
+          "name": "tokenInfo",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  111,
+                  107,
+                  101,
+                  110,
+                  95,
+                  105,
+                  110,
+                  102,
+                  111
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "tokenId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vestingEscrow",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  101,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "tokenId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vestingTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "beneficiary",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "vestingEscrow"
+          ]
+        },
+        {
+          "name": "creatorTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "beneficiary"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "tokenId",
+          "type": "u64"
+        }
       ]
     },
     {
-      "name": "transaction",
+      "name": "createToken",
       "discriminator": [
-        11,
+        84,
+        52,
+        204,
+        228,
         24,
-        174,
-        129,
-        203,
-        117,
-        242,
-        23
-      ]
-    }
-  ],
-  "errors": [
-    {
-      "code": 6000,
-      "name": "alreadyInitialized",
-      "msg": "Program already initialized"
-    },
-    {
-      "code": 6001,
-      "name": "tokenNameTooLong",
-      "msg": "Token name too long"
-    },
-    {
-      "code": 6002,
-      "name": "tokenSymbolTooLong",
-      "msg": "Token symbol too long"
-    },
-    {
-      "code": 6003,
-      "name": "tokenUriTooLong",
-      "msg": "Token URI too long"
-    },
-    {
-      "code": 6004,
-      "name": "invalidDecimals",
-      "msg": "Invalid decimals value"
-    },
-    {
-      "code": 6005,
-      "name": "invalidInitialSupply",
+        140,
+        234,
+        75
+      ],
+      "accounts": [
+        {
+          "name": "programState",
+          "writable": true
+        },
+        {
+          "name": "tokenInfo",
+          "writable": true
+        },
+        {
+          "name": "bondingCurve",
+          "writable": true
+        },
+        {
+          "name": "creatorVault",
+          "writable": true
+        },
+        {
+          "name": "mint",
+          "docs": [
+            "The new Mint (SPL Token-2022 compatible)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  105,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "bondingCurve"
+              }
+            ]
+          }
+        },
+        {
+          "name": "creator",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "holderTrackerProgram",
+          "docs": [
+            "Holder tracker program, required when `options.holder_tracking` is set"
+          ],
+          "optional": true,
+          "address": "7gfVkkN4DQpTjZCHZtEY2Sjys1K5dQk9yV992piFrELc"
+        },
+        {
+          "name": "extraAccountMetaList",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "holderStats",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "vestingEscrow",
+          "docs": [
+            "Vesting escrow for the creator allocation, required when `options.creator_vesting` is set"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "vestingTokenAccount",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "vestingPosition",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "airdrop",
+          "docs": [
+            "Airdrop commitment, required when `options.airdrop` is set"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "tokenProgram",
+          "docs": [
+            "Token-2022 program"
+          ],
+          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "rent",
+          "address": "SysvarRent111111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "name",
+          "type": "string"
+        },
+        {
+          "name": "symbol",
+          "type": "string"
+        },
+        {
+          "name": "uri",
+          "type": "string"
+        },
+        {
+          "name": "decimals",
+          "type": "u8"
+        },
+        {
+          "name": "initialSupply",
+          "type": "u64"
+        },
+        {
+          "name": "options",
+          "type": {
+            "defined": {
+              "name": "launchOptions"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "deleteToken",
+      "discriminator": [
+        208,
+        193,
+        211,
+        235,
+        81,
+        190,
+        115,
+        68
+      ],
+      "accounts": [
+        {
+          "name": "tokenInfo",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  111,
+                  107,
+                  101,
+                  110,
+                  95,
+                  105,
+                  110,
+                  102,
+                  111
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "tokenId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "bondingCurve",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  111,
+                  110,
+                  100,
+                  105,
+                  110,
+                  103,
+                  95,
+                  99,
+                  117,
+                  114,
+                  118,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "tokenId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "creatorVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  114,
+                  101,
+                  97,
+                  116,
+                  111,
+                  114,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "tokenId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vestingEscrow",
+          "docs": [
+            "Vesting escrow, required when the token has a creator allocation"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  101,
+                  115,
+                  116,
+                  105,
+                  110,
+                  103,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "tokenId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vestingTokenAccount",
+          "docs": [
+            "Vesting escrow's token account, required when the token has a creator allocation"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "mint",
+          "writable": true
+        },
+        {
+          "name": "airdrop",
+          "docs": [
+            "Airdrop commitment, required when the token has an airdrop allocation"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  105,
+                  114,
+                  100,
+                  114,
+                  111,
+                  112
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "tokenId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "creator",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "tokenId",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "executePlatformLimits",
+      "discriminator": [
+        91,
+        55,
+        118,
+        159,
+        111,
+        78,
+        116,
+        110
+      ],
+      "accounts": [
+        {
+          "name": "programState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "executePlatformSettings",
+      "discriminator": [
+        241,
+        39,
+        164,
+        115,
+        102,
+        30,
+        15,
+        206
+      ],
+      "accounts": [
+        {
+          "name": "programState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "feeManager",
+          "signer": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "executePlatformTreasury",
+      "discriminator": [
+        84,
+        28,
+        199,
+        176,
+        220,
+        12,
+        157,
+        240
+      ],
+      "accounts": [
+        {
+          "name": "programState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "treasurer",
+          "signer": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "finalizeUnlock",
+      "discriminator": [
+        105,
+        205,
+        233,
+        49,
+        162,
+        143,
+        188,
+        30
+      ],
+      "accounts": [
+        {
+          "name": "programState",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenInfo",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  111,
+                  107,
+                  101,
+                  110,
+                  95,
+                  105,
+                  110,
+                  102,
+                  111
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "tokenId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "bondingCurve",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  111,
+                  110,
+                  100,
+                  105,
+                  110,
+                  103,
+                  95,
+                  99,
+                  117,
+                  114,
+                  118,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "tokenId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint",
+          "writable": true
+        },
+        {
+          "name": "moderator",
+          "signer": true
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        }
+      ],
+      "args": [
+        {
+          "name": "tokenId",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "haltTokenTrading",
+      "discriminator": [
+        69,
+        121,
+        240,
+        63,
+        80,
+        224,
+        65,
+        135
+      ],
+      "accounts": [
+        {
+          "name": "programState",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenInfo",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  111,
+                  107,
+                  101,
+                  110,
+                  95,
+                  105,
+                  110,
+                  102,
+                  111
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "tokenId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "moderator",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "tokenId",
+          "type": "u64"
+        },
+        {
+          "name": "reasonCode",
+          "type": "u16"
+        },
+        {
+          "name": "allowSells",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "harvestTransferFees",
+      "discriminator": [
+        246,
+        104,
+        231,
+        171,
+        131,
+        105,
+        50,
+        55
+      ],
+      "accounts": [
+        {
+          "name": "tokenInfo",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  111,
+                  107,
+                  101,
+                  110,
+                  95,
+                  105,
+                  110,
+                  102,
+                  111
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "tokenId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "bondingCurve",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  111,
+                  110,
+                  100,
+                  105,
+                  110,
+                  103,
+                  95,
+                  99,
+                  117,
+                  114,
+                  118,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "tokenId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint",
+          "writable": true
+        },
+        {
+          "name": "creator"
+        },
+        {
+          "name": "creatorTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "creator"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "payer",
+          "docs": [
+            "Anyone may crank the harvest; fees always go to the creator"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "tokenId",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "initialize",
+      "discriminator": [
+        175,
+        175,
+        109,
+        31,
+        13,
+        152,
+        155,
+        237
+      ],
+      "accounts": [
+        {
+          "name": "programState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "platformFeeVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  116,
+                  102,
+                  111,
+                  114,
+                  109,
+                  95,
+                  102,
+                  101,
+                  101,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "deployer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "launchThreshold",
+          "type": "u64"
+        },
+        {
+          "name": "limits",
+          "type": {
+            "defined": {
+              "name": "platformLimits"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "launchToDex",
+      "discriminator": [
+        195,
+        102,
+        177,
+        151,
+        21,
+        235,
+        134,
+        205
+      ],
+      "accounts": [
+        {
+          "name": "programState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenInfo",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  111,
+                  107,
+                  101,
+                  110,
+                  95,
+                  105,
+                  110,
+                  102,
+                  111
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "tokenId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "bondingCurve",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  111,
+                  110,
+                  100,
+                  105,
+                  110,
+                  103,
+                  95,
+                  99,
+                  117,
+                  114,
+                  118,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "tokenId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "transaction",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  97,
+                  110,
+                  115,
+                  97,
+                  99,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "launcher"
+              },
+              {
+                "kind": "arg",
+                "path": "tokenId"
+              },
+              {
+                "kind": "arg",
+                "path": "nextTxId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "platformFeeVault",
+          "docs": [
+            "Vault collecting platform fees"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  116,
+                  102,
+                  111,
+                  114,
+                  109,
+                  95,
+                  102,
+                  101,
+                  101,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint",
+          "writable": true
+        },
+        {
+          "name": "launchEscrow",
+          "docs": [
+            "Program-controlled escrow that deposits liquidity into external AMMs"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  97,
+                  117,
+                  110,
+                  99,
+                  104,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "tokenId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "escrowTokenAccount",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "launchEscrow"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "escrowLpAccount",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "ammPool",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "ammLpMint",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "ammTokenVault",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "liquidityPool",
+          "docs": [
+            "Built-in liquidity pool that keeps the token trading in this program"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  105,
+                  113,
+                  117,
+                  105,
+                  100,
+                  105,
+                  116,
+                  121,
+                  95,
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "tokenId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "poolTokenVault",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  111,
+                  111,
+                  108,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "tokenId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "airdrop",
+          "docs": [
+            "Airdrop commitment, required for tokens with an airdrop allocation"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  105,
+                  114,
+                  100,
+                  114,
+                  111,
+                  112
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "tokenId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "airdropTokenAccount",
+          "docs": [
+            "Escrow for the unclaimed airdrop, paid out by `claim_airdrop` after launch"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "airdrop"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "holderTrackerProgram",
+          "docs": [
+            "Holder tracker program, required for tokens with holder tracking"
+          ],
+          "optional": true,
+          "address": "7gfVkkN4DQpTjZCHZtEY2Sjys1K5dQk9yV992piFrELc"
+        },
+        {
+          "name": "holderStats",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "liquidityPosition",
+          "docs": [
+            "(pool vault or escrow token account), validated by the holder tracker."
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "airdropPosition",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "launcher",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "ammProgram",
+          "docs": [
+            "and the `ProgramState` allowlist in the handler. This program for the built-in pool."
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "tokenId",
+          "type": "u64"
+        },
+        {
+          "name": "nextTxId",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "proposePlatformAuthority",
+      "discriminator": [
+        174,
+        55,
+        230,
+        197,
+        197,
+        70,
+        205,
+        64
+      ],
+      "accounts": [
+        {
+          "name": "programState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "currentAuthority",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "newAuthority",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "refreshHolderCount",
+      "discriminator": [
+        159,
+        162,
+        212,
+        108,
+        18,
+        111,
+        156,
+        87
+      ],
+      "accounts": [
+        {
+          "name": "tokenInfo",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  111,
+                  107,
+                  101,
+                  110,
+                  95,
+                  105,
+                  110,
+                  102,
+                  111
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "tokenId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "holderStats",
+          "docs": [
+            "Holder stats kept by the holder tracker, only created for tokens with holder tracking"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  104,
+                  111,
+                  108,
+                  100,
+                  101,
+                  114,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "token_info.mint",
+                "account": "tokenInfo"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                99,
+                79,
+                76,
+                231,
+                177,
+                28,
+                84,
+                190,
+                229,
+                181,
+                86,
+                80,
+                66,
+                9,
+                242,
+                201,
+                79,
+                210,
+                80,
+                91,
+                125,
+                50,
+                152,
+                150,
+                183,
+                37,
+                137,
+                149,
+                34,
+                180,
+                246,
+                173
+              ]
+            }
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "tokenId",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "removeGraduationProgram",
+      "discriminator": [
+        243,
+        37,
+        104,
+        89,
+        111,
+        151,
+        196,
+        103
+      ],
+      "accounts": [
+        {
+          "name": "programState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "programId",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "resumeTokenTrading",
+      "discriminator": [
+        80,
+        51,
+        52,
+        78,
+        74,
+        69,
+        127,
+        206
+      ],
+      "accounts": [
+        {
+          "name": "programState",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenInfo",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  111,
+                  107,
+                  101,
+                  110,
+                  95,
+                  105,
+                  110,
+                  102,
+                  111
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "tokenId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "moderator",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "tokenId",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "retargetGraduation",
+      "discriminator": [
+        14,
+        105,
+        72,
+        0,
+        236,
+        47,
+        36,
+        37
+      ],
+      "accounts": [
+        {
+          "name": "programState",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenInfo",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  111,
+                  107,
+                  101,
+                  110,
+                  95,
+                  105,
+                  110,
+                  102,
+                  111
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "tokenId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "docs": [
+            "Token creator or platform authority"
+          ],
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "tokenId",
+          "type": "u64"
+        },
+        {
+          "name": "target",
+          "type": {
+            "defined": {
+              "name": "graduationTarget"
+            }
+          }
+        },
+        {
+          "name": "programId",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "schedulePlatformLimits",
+      "discriminator": [
+        229,
+        131,
+        69,
+        43,
+        141,
+        203,
+        66,
+        201
+      ],
+      "accounts": [
+        {
+          "name": "programState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "newLimits",
+          "type": {
+            "defined": {
+              "name": "platformLimits"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "schedulePlatformSettings",
+      "discriminator": [
+        171,
+        28,
+        186,
+        235,
+        27,
+        99,
+        55,
+        48
+      ],
+      "accounts": [
+        {
+          "name": "programState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "feeManager",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "newFeeRate",
+          "type": "u64"
+        },
+        {
+          "name": "newLaunchThreshold",
+          "type": "u64"
+        },
+        {
+          "name": "newGovernanceDelay",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "schedulePlatformTreasury",
+      "discriminator": [
+        178,
+        64,
+        78,
+        228,
+        205,
+        164,
+        213,
+        35
+      ],
+      "accounts": [
+        {
+          "name": "programState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "treasurer",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "newTreasury",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "sellToken",
+      "discriminator": [
+        109,
+        61,
+        40,
+        187,
+        230,
+        176,
+        135,
+        174
+      ],
+      "accounts": [
+        {
+          "name": "programState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenInfo",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  111,
+                  107,
+                  101,
+                  110,
+                  95,
+                  105,
+                  110,
+                  102,
+                  111
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "tokenId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "bondingCurve",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  111,
+                  110,
+                  100,
+                  105,
+                  110,
+                  103,
+                  95,
+                  99,
+                  117,
+                  114,
+                  118,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "tokenId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "transaction",
+          "writable": true
+        },
+        {
+          "name": "liquidityPool",
+          "docs": [
+            "Built-in liquidity pool, required once the token has graduated to it"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  105,
+                  113,
+                  117,
+                  105,
+                  100,
+                  105,
+                  116,
+                  121,
+                  95,
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "tokenId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "poolTokenVault",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "mint",
+          "writable": true
+        },
+        {
+          "name": "sellerTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "platformFeeVault",
+          "docs": [
+            "Vault collecting platform fees"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  116,
+                  102,
+                  111,
+                  114,
+                  109,
+                  95,
+                  102,
+                  101,
+                  101,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "creatorVault",
+          "docs": [
+            "Vault accruing the token's creator fees"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  114,
+                  101,
+                  97,
+                  116,
+                  111,
+                  114,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "tokenId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "seller",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "holderTrackerProgram",
+          "docs": [
+            "Holder tracker program, required for curve trades of tokens with holder tracking"
+          ],
+          "optional": true,
+          "address": "7gfVkkN4DQpTjZCHZtEY2Sjys1K5dQk9yV992piFrELc"
+        },
+        {
+          "name": "holderStats",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "holderPosition",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "tokenId",
+          "type": "u64"
+        },
+        {
+          "name": "tokenAmount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "setPauseFlags",
+      "discriminator": [
+        205,
+        167,
+        85,
+        237,
+        144,
+        202,
+        248,
+        175
+      ],
+      "accounts": [
+        {
+          "name": "programState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "pauser",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "pauseFlags",
+          "type": {
+            "defined": {
+              "name": "pauseFlags"
+            }
+          }
+        },
+        {
+          "name": "reasonCode",
+          "type": "u16"
+        },
+        {
+          "name": "pausedUntil",
+          "type": {
+            "option": "i64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setTokenAccountFrozen",
+      "discriminator": [
+        99,
+        170,
+        46,
+        143,
+        36,
+        175,
+        184,
+        41
+      ],
+      "accounts": [
+        {
+          "name": "programState",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenInfo",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  111,
+                  107,
+                  101,
+                  110,
+                  95,
+                  105,
+                  110,
+                  102,
+                  111
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "tokenId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "bondingCurve",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  111,
+                  110,
+                  100,
+                  105,
+                  110,
+                  103,
+                  95,
+                  99,
+                  117,
+                  114,
+                  118,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "tokenId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "tokenAccount",
+          "writable": true
+        },
+        {
+          "name": "complianceAuthority",
+          "signer": true
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        }
+      ],
+      "args": [
+        {
+          "name": "tokenId",
+          "type": "u64"
+        },
+        {
+          "name": "frozen",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "thawTokenAccount",
+      "discriminator": [
+        199,
+        172,
+        96,
+        93,
+        244,
+        252,
+        137,
+        171
+      ],
+      "accounts": [
+        {
+          "name": "tokenInfo",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  111,
+                  107,
+                  101,
+                  110,
+                  95,
+                  105,
+                  110,
+                  102,
+                  111
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "tokenId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "bondingCurve",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  111,
+                  110,
+                  100,
+                  105,
+                  110,
+                  103,
+                  95,
+                  99,
+                  117,
+                  114,
+                  118,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "tokenId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "tokenAccount",
+          "writable": true
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        }
+      ],
+      "args": [
+        {
+          "name": "tokenId",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "updateComplianceAuthority",
+      "discriminator": [
+        7,
+        48,
+        216,
+        245,
+        125,
+        181,
+        253,
+        240
+      ],
+      "accounts": [
+        {
+          "name": "programState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "newComplianceAuthority",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "updateLaunchCrank",
+      "discriminator": [
+        231,
+        197,
+        200,
+        194,
+        217,
+        210,
+        215,
+        86
+      ],
+      "accounts": [
+        {
+          "name": "programState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "feeManager",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "launcherRewardBps",
+          "type": "u64"
+        },
+        {
+          "name": "launchGracePeriod",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "updatePlatformRole",
+      "discriminator": [
+        179,
+        105,
+        83,
+        68,
+        241,
+        239,
+        142,
+        129
+      ],
+      "accounts": [
+        {
+          "name": "programState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "role",
+          "type": {
+            "defined": {
+              "name": "platformRole"
+            }
+          }
+        },
+        {
+          "name": "newHolder",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "updateTokenMetadata",
+      "discriminator": [
+        243,
+        6,
+        8,
+        23,
+        126,
+        181,
+        251,
+        158
+      ],
+      "accounts": [
+        {
+          "name": "tokenInfo",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  111,
+                  107,
+                  101,
+                  110,
+                  95,
+                  105,
+                  110,
+                  102,
+                  111
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "tokenId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "bondingCurve",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  111,
+                  110,
+                  100,
+                  105,
+                  110,
+                  103,
+                  95,
+                  99,
+                  117,
+                  114,
+                  118,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "tokenId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint",
+          "writable": true
+        },
+        {
+          "name": "creator",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "tokenId",
+          "type": "u64"
+        },
+        {
+          "name": "uri",
+          "type": {
+            "option": "string"
+          }
+        },
+        {
+          "name": "lockMetadata",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "withdrawPlatformFees",
+      "discriminator": [
+        87,
+        24,
+        138,
+        122,
+        62,
+        146,
+        186,
+        199
+      ],
+      "accounts": [
+        {
+          "name": "programState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "platformFeeVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  116,
+                  102,
+                  111,
+                  114,
+                  109,
+                  95,
+                  102,
+                  101,
+                  101,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "treasurer",
+          "signer": true
+        },
+        {
+          "name": "treasury",
+          "writable": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "airdrop",
+      "discriminator": [
+        31,
+        112,
+        159,
+        158,
+        124,
+        237,
+        9,
+        241
+      ]
+    },
+    {
+      "name": "airdropClaim",
+      "discriminator": [
+        231,
+        12,
+        74,
+        54,
+        245,
+        181,
+        248,
+        38
+      ]
+    },
+    {
+      "name": "bondingCurve",
+      "discriminator": [
+        23,
+        183,
+        248,
+        55,
+        96,
+        216,
+        172,
+        96
+      ]
+    },
+    {
+      "name": "creatorVault",
+      "discriminator": [
+        200,
+        135,
+        38,
+        98,
+        35,
+        236,
+        238,
+        12
+      ]
+    },
+    {
+      "name": "holderStats",
+      "discriminator": [
+        166,
+        146,
+        18,
+        100,
+        12,
+        147,
+        206,
+        78
+      ]
+    },
+    {
+      "name": "liquidityPool",
+      "discriminator": [
+        66,
+        38,
+        17,
+        64,
+        188,
+        80,
+        68,
+        129
+      ]
+    },
+    {
+      "name": "platformFeeVault",
+      "discriminator": [
+        108,
+        202,
+        143,
+        121,
+        8,
+        11,
+        125,
+        143
+      ]
+    },
+    {
+      "name": "programState",
+      "discriminator": [
+        77,
+        209,
+        137,
+        229,
+        149,
+        67,
+        167,
+        230
+      ]
+    },
+    {
+      "name": "tokenInfo",
+      "discriminator": [
+        109,
+        162,
+        52,
+        125,
+        77,
+        166,
+        37,
+        202
+      ]
+    },
+    {
+      "name": "transaction",
+      "discriminator": [
+        11,
+        24,
+        174,
+        129,
+        203,
+        117,
+        242,
+        23
+      ]
+    },
+    {
+      "name": "vestingEscrow",
+      "discriminator": [
+        244,
+        119,
+        183,
+        4,
+        73,
+        116,
+        135,
+        195
+      ]
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "alreadyInitialized",
+      "msg": "Program already initialized"
+    },
+    {
+      "code": 6001,
+      "name": "tokenNameTooLong",
+      "msg": "Token name too long"
+    },
+    {
+      "code": 6002,
+      "name": "tokenSymbolTooLong",
+      "msg": "Token symbol too long"
+    },
+    {
+      "code": 6003,
+      "name": "tokenUriTooLong",
+      "msg": "Token URI too long"
+    },
+    {
+      "code": 6004,
+      "name": "invalidDecimals",
+      "msg": "Invalid decimals value"
+    },
+    {
+      "code": 6005,
+      "name": "invalidInitialSupply",
       "msg": "Invalid initial supply"
     },
     {
@@ -832,249 +4349,1406 @@ export type MemeLaunchpad = {
       "msg": "Invalid launch threshold"
     },
     {
-      "code": 6019,
-      "name": "tradingNotActive",
-      "msg": "Trading not active"
+      "code": 6019,
+      "name": "tradingNotActive",
+      "msg": "Trading not active"
+    },
+    {
+      "code": 6020,
+      "name": "slippageExceeded",
+      "msg": "Slippage tolerance exceeded"
+    },
+    {
+      "code": 6021,
+      "name": "tokenCreationFailed",
+      "msg": "Token creation failed"
+    },
+    {
+      "code": 6022,
+      "name": "mintAuthorityTransferFailed",
+      "msg": "Mint authority transfer failed"
+    },
+    {
+      "code": 6023,
+      "name": "tokenAccountCreationFailed",
+      "msg": "Token account creation failed"
+    },
+    {
+      "code": 6024,
+      "name": "invalidTokenProgram",
+      "msg": "Invalid token program"
+    },
+    {
+      "code": 6025,
+      "name": "arithmeticOverflow",
+      "msg": "Arithmetic overflow"
+    },
+    {
+      "code": 6026,
+      "name": "arithmeticUnderflow",
+      "msg": "Arithmetic underflow"
+    },
+    {
+      "code": 6027,
+      "name": "divisionByZero",
+      "msg": "Division by zero"
+    },
+    {
+      "code": 6028,
+      "name": "invalidAccount",
+      "msg": "Invalid account"
+    },
+    {
+      "code": 6029,
+      "name": "accountNotMutable",
+      "msg": "Account not mutable"
+    },
+    {
+      "code": 6030,
+      "name": "invalidSigner",
+      "msg": "Invalid signer"
+    },
+    {
+      "code": 6031,
+      "name": "tokenMetadataUpdateFailed",
+      "msg": "Token metadata update failed"
+    },
+    {
+      "code": 6032,
+      "name": "insufficientReserves",
+      "msg": "Insufficient reserves"
+    },
+    {
+      "code": 6033,
+      "name": "bondingCurveInactive",
+      "msg": "Bonding curve inactive"
+    },
+    {
+      "code": 6034,
+      "name": "invalidTransactionId",
+      "msg": "Invalid transaction ID"
+    },
+    {
+      "code": 6035,
+      "name": "invalidCreator",
+      "msg": "Invalid creator"
+    },
+    {
+      "code": 6036,
+      "name": "invalidTokenAccount",
+      "msg": "Invalid token account"
+    },
+    {
+      "code": 6037,
+      "name": "invalidPoolAccount",
+      "msg": "Invalid liquidity pool account"
+    },
+    {
+      "code": 6038,
+      "name": "graduationProgramNotAllowed",
+      "msg": "Graduation program not allowlisted"
+    },
+    {
+      "code": 6039,
+      "name": "graduationProgramListFull",
+      "msg": "Graduation program allowlist is full"
+    },
+    {
+      "code": 6040,
+      "name": "invalidGraduationTarget",
+      "msg": "Invalid graduation target"
+    },
+    {
+      "code": 6041,
+      "name": "invalidUnlockTimestamp",
+      "msg": "Invalid unlock timestamp"
+    },
+    {
+      "code": 6042,
+      "name": "liquidityLocked",
+      "msg": "LP tokens are still locked"
+    },
+    {
+      "code": 6043,
+      "name": "noLockedLiquidity",
+      "msg": "No locked LP tokens"
+    },
+    {
+      "code": 6044,
+      "name": "bondingCurveComplete",
+      "msg": "Bonding curve complete, awaiting launch"
+    },
+    {
+      "code": 6045,
+      "name": "launchGracePeriodActive",
+      "msg": "Launch grace period active, only the creator or authority may launch"
+    },
+    {
+      "code": 6046,
+      "name": "invalidLauncherReward",
+      "msg": "Invalid launcher reward"
+    },
+    {
+      "code": 6047,
+      "name": "invalidLaunchGracePeriod",
+      "msg": "Invalid launch grace period"
+    },
+    {
+      "code": 6048,
+      "name": "invalidMintAuthority",
+      "msg": "Invalid mint authority"
+    },
+    {
+      "code": 6049,
+      "name": "complianceAuthorityNotSet",
+      "msg": "Compliance authority not set"
+    },
+    {
+      "code": 6050,
+      "name": "freezeAuthorityNotHeld",
+      "msg": "Bonding curve does not hold the freeze authority"
+    },
+    {
+      "code": 6051,
+      "name": "metadataLocked",
+      "msg": "Token metadata is locked"
+    },
+    {
+      "code": 6052,
+      "name": "invalidTransferFee",
+      "msg": "Invalid transfer fee"
+    },
+    {
+      "code": 6053,
+      "name": "transferFeeNotEnabled",
+      "msg": "Transfer fee not enabled for this token"
+    },
+    {
+      "code": 6054,
+      "name": "invalidTransferLock",
+      "msg": "Transfer lock requires the bonding curve as freeze authority"
+    },
+    {
+      "code": 6055,
+      "name": "transferLockActive",
+      "msg": "Token is not transfer-locked or not launched yet"
+    },
+    {
+      "code": 6056,
+      "name": "holderTrackerAccountsMissing",
+      "msg": "Holder tracker accounts missing"
+    },
+    {
+      "code": 6057,
+      "name": "invalidVestingSchedule",
+      "msg": "Invalid vesting schedule"
+    },
+    {
+      "code": 6058,
+      "name": "vestingAccountsMissing",
+      "msg": "Vesting accounts missing"
+    },
+    {
+      "code": 6059,
+      "name": "nothingVested",
+      "msg": "No vested tokens to claim"
+    },
+    {
+      "code": 6060,
+      "name": "invalidAirdrop",
+      "msg": "Invalid airdrop allocation"
+    },
+    {
+      "code": 6061,
+      "name": "airdropAccountsMissing",
+      "msg": "Airdrop accounts missing"
+    },
+    {
+      "code": 6062,
+      "name": "invalidAirdropProof",
+      "msg": "Invalid airdrop proof"
+    },
+    {
+      "code": 6063,
+      "name": "airdropExhausted",
+      "msg": "Airdrop allocation exhausted"
+    },
+    {
+      "code": 6064,
+      "name": "noFeesToClaim",
+      "msg": "No fees to claim"
+    },
+    {
+      "code": 6065,
+      "name": "invalidAuthority",
+      "msg": "Invalid platform authority"
+    },
+    {
+      "code": 6066,
+      "name": "noPendingAuthorityTransfer",
+      "msg": "No pending authority transfer"
+    },
+    {
+      "code": 6067,
+      "name": "invalidGovernanceDelay",
+      "msg": "Invalid governance delay"
+    },
+    {
+      "code": 6068,
+      "name": "noPendingChange",
+      "msg": "No pending change scheduled"
+    },
+    {
+      "code": 6069,
+      "name": "timelockNotElapsed",
+      "msg": "Governance delay has not elapsed"
+    },
+    {
+      "code": 6070,
+      "name": "tradingHalted",
+      "msg": "Trading is halted for this token"
+    },
+    {
+      "code": 6071,
+      "name": "tradingNotHalted",
+      "msg": "Trading is not halted for this token"
+    },
+    {
+      "code": 6072,
+      "name": "actionPaused",
+      "msg": "This action is paused"
+    },
+    {
+      "code": 6073,
+      "name": "invalidPauseSchedule",
+      "msg": "Auto-unpause time must be in the future"
+    },
+    {
+      "code": 6074,
+      "name": "invalidPlatformLimits",
+      "msg": "Invalid platform limits"
+    },
+    {
+      "code": 6075,
+      "name": "graduationProgramStillAllowed",
+      "msg": "Graduation program is still allowlisted"
+    },
+    {
+      "code": 6076,
+      "name": "unsupportedLiquidityLock",
+      "msg": "Liquidity lock not supported by the graduation target"
+    },
+    {
+      "code": 6077,
+      "name": "holdersStillFrozen",
+      "msg": "Holder accounts holding tokens are still frozen"
+    },
+    {
+      "code": 6078,
+      "name": "invalidHolderStats",
+      "msg": "Holder stats do not belong to the mint"
+    },
+    {
+      "code": 6079,
+      "name": "tokenNotLaunched",
+      "msg": "Token has not launched yet"
+    }
+  ],
+  "types": [
+    {
+      "name": "airdrop",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tokenId",
+            "docs": [
+              "Associated token ID"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "Token mint address"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "merkleRoot",
+            "docs": [
+              "Merkle root over `sha256(claimant || amount_le)` leaves, with sorted-pair hashing"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "totalAmount",
+            "docs": [
+              "Tokens allocated to the airdrop"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "claimedAmount",
+            "docs": [
+              "Tokens already claimed"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "tokenAccount",
+            "docs": [
+              "Escrow token account holding the unclaimed remainder after launch"
+            ],
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "Bump seed for PDA"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 6020,
-      "name": "slippageExceeded",
-      "msg": "Slippage tolerance exceeded"
+      "name": "airdropAllocation",
+      "docs": [
+        "Supply set aside for claimants of a Merkle airdrop"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "allocationBps",
+            "docs": [
+              "Share of the initial supply allocated to the airdrop, in basis points"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "merkleRoot",
+            "docs": [
+              "Merkle root over `sha256(claimant || amount_le)` leaves"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6021,
-      "name": "tokenCreationFailed",
-      "msg": "Token creation failed"
+      "name": "airdropClaim",
+      "docs": [
+        "Receipt marking a claimant's airdrop as claimed"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tokenId",
+            "docs": [
+              "Associated token ID"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "claimant",
+            "docs": [
+              "Wallet that claimed"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "Tokens claimed"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "claimedAt",
+            "docs": [
+              "Claim timestamp"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "Bump seed for PDA"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 6022,
-      "name": "mintAuthorityTransferFailed",
-      "msg": "Mint authority transfer failed"
+      "name": "bondingCurve",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tokenId",
+            "docs": [
+              "Associated token ID"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "virtualSolReserves",
+            "docs": [
+              "Virtual SOL reserves for bonding curve calculation"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "virtualTokenReserves",
+            "docs": [
+              "Virtual token reserves for bonding curve calculation"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "realSolReserves",
+            "docs": [
+              "Real SOL reserves (actual SOL held)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "realTokenReserves",
+            "docs": [
+              "Real token reserves (actual tokens held)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalSolVolume",
+            "docs": [
+              "Total SOL volume traded"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalTokenVolume",
+            "docs": [
+              "Total token volume traded"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "currentPrice",
+            "docs": [
+              "Current token price in lamports per token"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "marketCap",
+            "docs": [
+              "Market cap in lamports"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "active",
+            "docs": [
+              "Whether the curve is active"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "complete",
+            "docs": [
+              "Whether the curve reached the launch threshold (curve trading halted)"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "completedAt",
+            "docs": [
+              "Timestamp at which the curve completed"
+            ],
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "lastUpdated",
+            "docs": [
+              "Last update timestamp"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "Bump seed for PDA"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 6023,
-      "name": "tokenAccountCreationFailed",
-      "msg": "Token account creation failed"
+      "name": "creatorVault",
+      "docs": [
+        "Per-token vault accruing creator trading fees as lamports above its rent reserve"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tokenId",
+            "docs": [
+              "Associated token ID"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "creator",
+            "docs": [
+              "Token creator, the only wallet that may claim"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "feesClaimed",
+            "docs": [
+              "Creator fees claimed so far"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "Bump seed for PDA"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 6024,
-      "name": "invalidTokenProgram",
-      "msg": "Invalid token program"
+      "name": "creatorVesting",
+      "docs": [
+        "Creator allocation minted into a vesting escrow at creation"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "allocationBps",
+            "docs": [
+              "Share of the initial supply allocated to the creator, in basis points"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "cliffDuration",
+            "docs": [
+              "Seconds after creation before anything can be claimed"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "vestingDuration",
+            "docs": [
+              "Seconds after creation until the full allocation has vested"
+            ],
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6025,
-      "name": "arithmeticOverflow",
-      "msg": "Arithmetic overflow"
+      "name": "freezeAuthorityPolicy",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "disabled"
+          },
+          {
+            "name": "bondingCurveUntilLaunch"
+          },
+          {
+            "name": "compliance"
+          }
+        ]
+      }
     },
     {
-      "code": 6026,
-      "name": "arithmeticUnderflow",
-      "msg": "Arithmetic underflow"
+      "name": "graduationProgram",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "target",
+            "docs": [
+              "Kind of AMM the program implements"
+            ],
+            "type": {
+              "defined": {
+                "name": "graduationTarget"
+              }
+            }
+          },
+          {
+            "name": "programId",
+            "docs": [
+              "Allowlisted AMM program ID"
+            ],
+            "type": "pubkey"
+          }
+        ]
+      }
     },
     {
-      "code": 6027,
-      "name": "divisionByZero",
-      "msg": "Division by zero"
+      "name": "graduationTarget",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "constantProductAmm"
+          },
+          {
+            "name": "builtinPool"
+          },
+          {
+            "name": "concentratedLiquidity"
+          }
+        ]
+      }
     },
     {
-      "code": 6028,
-      "name": "invalidAccount",
-      "msg": "Invalid account"
+      "name": "holderStats",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "docs": [
+              "Tracked mint"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "holderCount",
+            "docs": [
+              "Number of positions with a non-zero balance"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "Bump seed for PDA"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 6029,
-      "name": "accountNotMutable",
-      "msg": "Account not mutable"
+      "name": "launchOptions",
+      "docs": [
+        "Per-token launch configuration chosen at creation"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "graduationTarget",
+            "docs": [
+              "Where liquidity goes at launch"
+            ],
+            "type": {
+              "defined": {
+                "name": "graduationTarget"
+              }
+            }
+          },
+          {
+            "name": "graduationProgram",
+            "docs": [
+              "AMM program receiving liquidity (this program for the built-in pool)"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "liquidityLock",
+            "docs": [
+              "What happens to the LP tokens received at launch"
+            ],
+            "type": {
+              "defined": {
+                "name": "liquidityLock"
+              }
+            }
+          },
+          {
+            "name": "mintAuthority",
+            "docs": [
+              "Who holds the mint authority after launch (revoked if `None`)"
+            ],
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "mintAuthorityOutcome"
+                }
+              }
+            }
+          },
+          {
+            "name": "freezeAuthority",
+            "docs": [
+              "Who holds the freeze authority (none if `None`)"
+            ],
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "freezeAuthorityPolicy"
+                }
+              }
+            }
+          },
+          {
+            "name": "transferFeeBps",
+            "docs": [
+              "Token-2022 transfer fee in basis points paid to the creator (disabled if `None`)"
+            ],
+            "type": {
+              "option": "u16"
+            }
+          },
+          {
+            "name": "transferLockedUntilLaunch",
+            "docs": [
+              "Freeze holder accounts by default so tokens only move through curve trades until launch"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "holderTracking",
+            "docs": [
+              "Route transfers through the holder tracker hook so `holder_count` stays accurate"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "creatorVesting",
+            "docs": [
+              "Creator allocation with cliff and linear vesting (no allocation if `None`)"
+            ],
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "creatorVesting"
+                }
+              }
+            }
+          },
+          {
+            "name": "airdrop",
+            "docs": [
+              "Merkle airdrop carved out of the supply, claimable via `claim_airdrop` (none if `None`)"
+            ],
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "airdropAllocation"
+                }
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6030,
-      "name": "invalidSigner",
-      "msg": "Invalid signer"
+      "name": "liquidityLock",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "burn"
+          },
+          {
+            "name": "lock",
+            "fields": [
+              {
+                "name": "unlockAt",
+                "type": {
+                  "option": "i64"
+                }
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "liquidityPool",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tokenId",
+            "docs": [
+              "Associated token ID"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "Token mint address"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "tokenVault",
+            "docs": [
+              "Token vault owned by the pool PDA"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "solReserves",
+            "docs": [
+              "Real SOL reserves (held as lamports on the pool account)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "tokenReserves",
+            "docs": [
+              "Real token reserves (held in the token vault)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalSolVolume",
+            "docs": [
+              "Total SOL volume traded"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalTokenVolume",
+            "docs": [
+              "Total token volume traded"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "createdAt",
+            "docs": [
+              "Pool creation timestamp"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "lastUpdated",
+            "docs": [
+              "Last update timestamp"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "Bump seed for PDA"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 6031,
-      "name": "tokenMetadataUpdateFailed",
-      "msg": "Token metadata update failed"
+      "name": "mintAuthorityOutcome",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "revoke"
+          },
+          {
+            "name": "creator"
+          },
+          {
+            "name": "dao",
+            "fields": [
+              {
+                "name": "authority",
+                "type": "pubkey"
+              }
+            ]
+          }
+        ]
+      }
     },
     {
-      "code": 6032,
-      "name": "insufficientReserves",
-      "msg": "Insufficient reserves"
+      "name": "pauseFlags",
+      "docs": [
+        "Platform actions that can be paused independently"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "create",
+            "docs": [
+              "Token creation"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "buy",
+            "docs": [
+              "Buys on the curve and in the built-in pool"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "sell",
+            "docs": [
+              "Sells on the curve and in the built-in pool"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "launch",
+            "docs": [
+              "Launches to DEX"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "withdraw",
+            "docs": [
+              "Platform fee withdrawals and creator fee claims"
+            ],
+            "type": "bool"
+          }
+        ]
+      }
     },
     {
-      "code": 6033,
-      "name": "bondingCurveInactive",
-      "msg": "Bonding curve inactive"
+      "name": "pendingPlatformLimits",
+      "docs": [
+        "Platform limits change queued behind the governance delay"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "limits",
+            "docs": [
+              "New platform limits"
+            ],
+            "type": {
+              "defined": {
+                "name": "platformLimits"
+              }
+            }
+          },
+          {
+            "name": "executableAt",
+            "docs": [
+              "Timestamp from which the change can be executed"
+            ],
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6034,
-      "name": "invalidTransactionId",
-      "msg": "Invalid transaction ID"
+      "name": "pendingPlatformSettings",
+      "docs": [
+        "Platform settings change queued behind the governance delay"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "feeRate",
+            "docs": [
+              "New platform fee rate in basis points"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "launchThreshold",
+            "docs": [
+              "New launch threshold in lamports"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "governanceDelay",
+            "docs": [
+              "New governance delay in seconds"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "executableAt",
+            "docs": [
+              "Timestamp from which the change can be executed"
+            ],
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6035,
-      "name": "invalidCreator",
-      "msg": "Invalid creator"
+      "name": "pendingTreasury",
+      "docs": [
+        "Treasury change queued behind the governance delay"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "treasury",
+            "docs": [
+              "New platform treasury address"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "executableAt",
+            "docs": [
+              "Timestamp from which the change can be executed"
+            ],
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6036,
-      "name": "invalidTokenAccount",
-      "msg": "Invalid token account"
-    }
-  ],
-  "types": [
+      "name": "platformFeeVault",
+      "docs": [
+        "Singleton vault holding collected platform fees as lamports above its rent reserve"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "docs": [
+              "Bump seed for PDA"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
     {
-      "name": "bondingCurve",
+      "name": "platformLimits",
+      "docs": [
+        "Trading and launch limits adjustable without a redeploy"
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "tokenId",
+            "name": "minTokenPurchase",
             "docs": [
-              "Associated token ID"
+              "Minimum SOL per buy, in lamports"
             ],
             "type": "u64"
           },
           {
-            "name": "virtualSolReserves",
+            "name": "maxTokenPurchase",
             "docs": [
-              "Virtual SOL reserves for bonding curve calculation"
+              "Maximum SOL per buy, in lamports"
             ],
             "type": "u64"
           },
           {
-            "name": "virtualTokenReserves",
+            "name": "minTradingTime",
             "docs": [
-              "Virtual token reserves for bonding curve calculation"
+              "Seconds a token must trade before it can launch"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "launchLiquidityBps",
+            "docs": [
+              "Share of curve reserves seeded as DEX liquidity at launch, in basis points (the rest is the launch fee)"
             ],
             "type": "u64"
           },
           {
-            "name": "realSolReserves",
+            "name": "maxPlatformFeeRate",
             "docs": [
-              "Real SOL reserves (actual SOL held)"
+              "Highest platform fee rate the fee manager may set, in basis points"
             ],
             "type": "u64"
           },
           {
-            "name": "realTokenReserves",
+            "name": "minLaunchThreshold",
             "docs": [
-              "Real token reserves (actual tokens held)"
+              "Lowest launch threshold the fee manager may set, in lamports"
             ],
             "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "platformRole",
+      "docs": [
+        "Delegated admin roles, each assigned by the platform authority"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "feeManager"
           },
           {
-            "name": "totalSolVolume",
+            "name": "pauser"
+          },
+          {
+            "name": "moderator"
+          },
+          {
+            "name": "treasurer"
+          }
+        ]
+      }
+    },
+    {
+      "name": "programState",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "initialized",
             "docs": [
-              "Total SOL volume traded"
+              "Whether the program has been initialized"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "tokenCount",
+            "docs": [
+              "Total number of tokens created"
             ],
             "type": "u64"
           },
           {
-            "name": "totalTokenVolume",
+            "name": "platformFeeRate",
             "docs": [
-              "Total token volume traded"
+              "Platform fee rate in basis points (250 = 2.5%)"
             ],
             "type": "u64"
           },
           {
-            "name": "currentPrice",
+            "name": "launchThreshold",
             "docs": [
-              "Current token price in lamports per token"
+              "Threshold amount to launch token to DEX (in lamports)"
             ],
             "type": "u64"
           },
           {
-            "name": "marketCap",
+            "name": "launcherRewardBps",
             "docs": [
-              "Market cap in lamports"
+              "Share of the platform launch fee paid to the launcher, in basis points"
             ],
             "type": "u64"
           },
           {
-            "name": "active",
+            "name": "launchGracePeriod",
+            "docs": [
+              "Seconds after curve completion during which only the creator or authority may launch"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "platformAuthority",
+            "docs": [
+              "Platform authority address"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "pendingPlatformAuthority",
+            "docs": [
+              "Proposed platform authority awaiting acceptance"
+            ],
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "feeManager",
+            "docs": [
+              "Role allowed to change fee rate, launch threshold and launch crank settings"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "pauser",
+            "docs": [
+              "Role allowed to set the platform pause flags"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "moderator",
+            "docs": [
+              "Role allowed to halt and resume trading on individual tokens"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "treasurer",
+            "docs": [
+              "Role allowed to change the treasury and withdraw platform fees"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "platformTreasury",
+            "docs": [
+              "Platform treasury address for collecting fees"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "governanceDelay",
+            "docs": [
+              "Seconds a scheduled settings or treasury change waits before it can be executed"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "pendingSettings",
             "docs": [
-              "Whether the curve is active"
+              "Scheduled fee rate / launch threshold / governance delay change"
             ],
-            "type": "bool"
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "pendingPlatformSettings"
+                }
+              }
+            }
           },
           {
-            "name": "lastUpdated",
+            "name": "pendingTreasury",
             "docs": [
-              "Last update timestamp"
+              "Scheduled treasury change"
             ],
-            "type": "i64"
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "pendingTreasury"
+                }
+              }
+            }
           },
           {
-            "name": "bump",
+            "name": "limits",
             "docs": [
-              "Bump seed for PDA"
+              "Purchase, launch and settings limits"
             ],
-            "type": "u8"
-          }
-        ]
-      }
-    },
-    {
-      "name": "programState",
-      "type": {
-        "kind": "struct",
-        "fields": [
+            "type": {
+              "defined": {
+                "name": "platformLimits"
+              }
+            }
+          },
           {
-            "name": "initialized",
+            "name": "pendingLimits",
             "docs": [
-              "Whether the program has been initialized"
+              "Scheduled limits change"
             ],
-            "type": "bool"
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "pendingPlatformLimits"
+                }
+              }
+            }
           },
           {
-            "name": "tokenCount",
+            "name": "complianceAuthority",
             "docs": [
-              "Total number of tokens created"
+              "Compliance authority that may freeze accounts of opted-in tokens"
             ],
-            "type": "u64"
+            "type": "pubkey"
           },
           {
-            "name": "platformFeeRate",
+            "name": "totalFeesCollected",
             "docs": [
-              "Platform fee rate in basis points (250 = 2.5%)"
+              "Total platform fees collected into the platform fee vault"
             ],
             "type": "u64"
           },
           {
-            "name": "launchThreshold",
+            "name": "totalFeesWithdrawn",
             "docs": [
-              "Threshold amount to launch token to DEX (in lamports)"
+              "Total platform fees withdrawn to the treasury"
             ],
             "type": "u64"
           },
           {
-            "name": "platformAuthority",
+            "name": "graduationPrograms",
             "docs": [
-              "Platform authority address"
+              "AMM programs tokens may graduate to"
             ],
-            "type": "pubkey"
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "graduationProgram"
+                }
+              }
+            }
           },
           {
-            "name": "platformTreasury",
+            "name": "pauseFlags",
             "docs": [
-              "Platform treasury address for collecting fees"
+              "Paused platform actions"
             ],
-            "type": "pubkey"
+            "type": {
+              "defined": {
+                "name": "pauseFlags"
+              }
+            }
           },
           {
-            "name": "totalFeesCollected",
+            "name": "pauseReason",
             "docs": [
-              "Total platform fees collected"
+              "Off-chain defined reason code for the current pause"
             ],
-            "type": "u64"
+            "type": "u16"
           },
           {
-            "name": "isPaused",
+            "name": "pausedUntil",
             "docs": [
-              "Emergency pause flag"
+              "Timestamp from which the pause flags lapse automatically (`None` = until cleared)"
             ],
-            "type": "bool"
+            "type": {
+              "option": "i64"
+            }
           },
           {
             "name": "initializedAt",
@@ -1177,6 +5851,151 @@ export type MemeLaunchpad = {
               "option": "i64"
             }
           },
+          {
+            "name": "liquidityTokenReserve",
+            "docs": [
+              "Tokens reserved from total supply for DEX liquidity"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "creatorAllocation",
+            "docs": [
+              "Tokens reserved from total supply for the creator's vesting escrow"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "airdropAllocation",
+            "docs": [
+              "Tokens reserved from total supply for the Merkle airdrop"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "graduationTarget",
+            "docs": [
+              "Where liquidity goes at launch"
+            ],
+            "type": {
+              "defined": {
+                "name": "graduationTarget"
+              }
+            }
+          },
+          {
+            "name": "graduationProgram",
+            "docs": [
+              "AMM program receiving liquidity (this program for the built-in pool)"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "dexPool",
+            "docs": [
+              "DEX pool created at launch"
+            ],
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "liquidityLock",
+            "docs": [
+              "What happens to the LP tokens received at launch"
+            ],
+            "type": {
+              "defined": {
+                "name": "liquidityLock"
+              }
+            }
+          },
+          {
+            "name": "lpMint",
+            "docs": [
+              "LP token mint of the DEX pool"
+            ],
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "lpTokenAmount",
+            "docs": [
+              "LP tokens received at launch"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "lpTokenAccount",
+            "docs": [
+              "Escrow token account holding locked LP tokens (None if burned or claimed)"
+            ],
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "mintAuthorityOutcome",
+            "docs": [
+              "Who holds the mint authority after launch"
+            ],
+            "type": {
+              "defined": {
+                "name": "mintAuthorityOutcome"
+              }
+            }
+          },
+          {
+            "name": "freezeAuthorityPolicy",
+            "docs": [
+              "Who holds the freeze authority"
+            ],
+            "type": {
+              "defined": {
+                "name": "freezeAuthorityPolicy"
+              }
+            }
+          },
+          {
+            "name": "metadataLocked",
+            "docs": [
+              "Whether the creator permanently locked the metadata"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "transferFeeBps",
+            "docs": [
+              "Token-2022 transfer fee in basis points, withheld for the creator (None if disabled)"
+            ],
+            "type": {
+              "option": "u16"
+            }
+          },
+          {
+            "name": "transferLockedUntilLaunch",
+            "docs": [
+              "Whether holder accounts stay frozen (only curve trades move tokens) until launch.",
+              "After launch holders are thawed with `thaw_token_account` or by the moderator's",
+              "`finalize_unlock`, which then revokes the bonding curve's freeze authority unless the policy is `Compliance`"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "frozenHolderAccounts",
+            "docs": [
+              "Transfer-locked holder accounts that are frozen while holding tokens"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "holderTracking",
+            "docs": [
+              "Whether the holder tracker transfer hook keeps `holder_count` up to date"
+            ],
+            "type": "bool"
+          },
           {
             "name": "totalSolRaised",
             "docs": [
@@ -1187,7 +6006,8 @@ export type MemeLaunchpad = {
           {
             "name": "holderCount",
             "docs": [
-              "Number of unique holders"
+              "Token accounts holding the token, as last read from the holder tracker",
+              "(only maintained for tokens with holder tracking, see `refresh_holder_count`)"
             ],
             "type": "u64"
           },
@@ -1212,6 +6032,19 @@ export type MemeLaunchpad = {
             ],
             "type": "bool"
           },
+          {
+            "name": "tradingHalt",
+            "docs": [
+              "Moderation halt, if trading was halted by the moderator"
+            ],
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "tradingHalt"
+                }
+              }
+            }
+          },
           {
             "name": "creatorFeesCollected",
             "docs": [
@@ -1229,6 +6062,38 @@ export type MemeLaunchpad = {
         ]
       }
     },
+    {
+      "name": "tradingHalt",
+      "docs": [
+        "Moderator-imposed trading halt on a single token"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "reasonCode",
+            "docs": [
+              "Off-chain defined reason code (e.g. suspected scam, legal request)"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "haltedAt",
+            "docs": [
+              "Timestamp the halt was imposed"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "allowSells",
+            "docs": [
+              "Whether holders may still sell while buys are halted"
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
     {
       "name": "transaction",
       "type": {
@@ -1346,6 +6211,84 @@ export type MemeLaunchpad = {
           }
         ]
       }
+    },
+    {
+      "name": "vestingEscrow",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tokenId",
+            "docs": [
+              "Associated token ID"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "Token mint address"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "beneficiary",
+            "docs": [
+              "Wallet the allocation vests to (the token creator)"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "tokenAccount",
+            "docs": [
+              "Escrow token account holding the unclaimed allocation"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "totalAmount",
+            "docs": [
+              "Total tokens allocated"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "claimedAmount",
+            "docs": [
+              "Tokens already claimed"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "startTime",
+            "docs": [
+              "Vesting start timestamp (token creation)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "cliffTime",
+            "docs": [
+              "Nothing is claimable before this timestamp"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "endTime",
+            "docs": [
+              "Everything is claimable from this timestamp"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "Bump seed for PDA"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
     }
   ]
 };
@@ -1,28 +1,66 @@
-import { AnchorProvider, BN, Program, Wallet } from "@coral-xyz/anchor";
+import { AnchorProvider, BN, IdlTypes, Program, Wallet } from "@coral-xyz/anchor";
 import {
   Connection,
+  LAMPORTS_PER_SOL,
   PublicKey,
   SystemProgram,
+  Transaction,
+  TransactionInstruction,
   TransactionSignature,
   SYSVAR_RENT_PUBKEY,
 } from "@solana/web3.js";
 import {
   ASSOCIATED_TOKEN_PROGRAM_ID,
   TOKEN_2022_PROGRAM_ID,
+  addExtraAccountMetasForExecute,
   getAssociatedTokenAddressSync,
 } from "@solana/spl-token";
-import { MemeTokenLaunchpad } from "@/programs/meme_token_launchpad";
-import idl from "../programs/meme_token_launchpad.json";
+import { MemeLaunchpad } from "@/programs/meme_launchpad";
+import idl from "../programs/meme_launchpad.json";
 import { getClusterURL } from "@/utils/helpers";
 
 const CLUSTER: string = process.env.NEXT_PUBLIC_CLUSTER || "devnet";
 export const RPC_URL: string = getClusterURL(CLUSTER);
 
+export const HOLDER_TRACKER_PROGRAM_ID = new PublicKey(
+  "7gfVkkN4DQpTjZCHZtEY2Sjys1K5dQk9yV992piFrELc"
+);
+
+export type LaunchOptions = IdlTypes<MemeLaunchpad>["launchOptions"];
+export type PlatformLimits = IdlTypes<MemeLaunchpad>["platformLimits"];
+
+// Program defaults from `constants/mod.rs`
+export const DEFAULT_LAUNCH_THRESHOLD = new BN(1000 * LAMPORTS_PER_SOL);
+export const DEFAULT_PLATFORM_LIMITS: PlatformLimits = {
+  minTokenPurchase: new BN(LAMPORTS_PER_SOL / 10),
+  maxTokenPurchase: new BN(10 * LAMPORTS_PER_SOL),
+  minTradingTime: new BN(3600),
+  launchLiquidityBps: new BN(8000),
+  maxPlatformFeeRate: new BN(1000),
+  minLaunchThreshold: new BN(100 * LAMPORTS_PER_SOL),
+};
+
+// Tokens created from the app keep trading in the program's own pool after launch
+export const defaultLaunchOptions = (
+  program: Program<MemeLaunchpad>
+): LaunchOptions => ({
+  graduationTarget: { builtinPool: {} },
+  graduationProgram: program.programId,
+  liquidityLock: { burn: {} },
+  mintAuthority: null,
+  freezeAuthority: null,
+  transferFeeBps: null,
+  transferLockedUntilLaunch: false,
+  holderTracking: false,
+  creatorVesting: null,
+  airdrop: null,
+});
+
 export const getProvider = (
   publicKey: PublicKey | null,
   signTransaction: unknown,
   sendTransaction: unknown
-): Program<MemeTokenLaunchpad> | null => {
+): Program<MemeLaunchpad> | null => {
   if (!publicKey || !signTransaction) {
     console.log("Wallet not connected or missing signTransaction");
     return null;
@@ -35,10 +73,10 @@ export const getProvider = (
     { commitment: "processed" }
   );
 
-  return new Program<MemeTokenLaunchpad>(idl as MemeTokenLaunchpad, provider);
+  return new Program<MemeLaunchpad>(idl as MemeLaunchpad, provider);
 };
 
-export const getProviderReadonly = (): Program<MemeTokenLaunchpad> => {
+export const getProviderReadonly = (): Program<MemeLaunchpad> => {
   const connection = new Connection(RPC_URL, "confirmed");
 
   const walllet = {
@@ -57,10 +95,10 @@ export const getProviderReadonly = (): Program<MemeTokenLaunchpad> => {
     { commitment: "processed" }
   );
 
-  return new Program<MemeTokenLaunchpad>(idl as MemeTokenLaunchpad, provider);
+  return new Program<MemeLaunchpad>(idl as MemeLaunchpad, provider);
 };
 
-export const getProgramState = async (program: Program<MemeTokenLaunchpad>) => {
+export const getProgramState = async (program: Program<MemeLaunchpad>) => {
   const [programStatePda] = PublicKey.findProgramAddressSync(
     [Buffer.from("program_state")],
     program.programId
@@ -69,7 +107,7 @@ export const getProgramState = async (program: Program<MemeTokenLaunchpad>) => {
 };
 
 export const getTokenInfo = async (
-  program: Program<MemeTokenLaunchpad>,
+  program: Program<MemeLaunchpad>,
   tokenId: BN
 ) => {
   const [tokenInfoPda] = PublicKey.findProgramAddressSync(
@@ -80,7 +118,7 @@ export const getTokenInfo = async (
 };
 
 export const getBondingCurve = async (
-  program: Program<MemeTokenLaunchpad>,
+  program: Program<MemeLaunchpad>,
   tokenId: BN
 ) => {
   const [bondingCurvePda] = PublicKey.findProgramAddressSync(
@@ -91,18 +129,26 @@ export const getBondingCurve = async (
 };
 
 export const initialize = async (
-  program: Program<MemeTokenLaunchpad>,
-  publicKey: PublicKey
+  program: Program<MemeLaunchpad>,
+  publicKey: PublicKey,
+  launchThreshold: BN = DEFAULT_LAUNCH_THRESHOLD,
+  limits: PlatformLimits = DEFAULT_PLATFORM_LIMITS
 ): Promise<TransactionSignature> => {
   const [programStatePda] = PublicKey.findProgramAddressSync(
     [Buffer.from("program_state")],
     program.programId
   );
 
+  const [platformFeeVaultPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("platform_fee_vault")],
+    program.programId
+  );
+
   const tx = await program.methods
-    .initialize()
+    .initialize(launchThreshold, limits)
     .accountsPartial({
       programState: programStatePda,
+      platformFeeVault: platformFeeVaultPda,
       deployer: publicKey,
       systemProgram: SystemProgram.programId,
     })
@@ -111,14 +157,50 @@ export const initialize = async (
   return tx;
 };
 
+const holderStatsPda = (mint: PublicKey) =>
+  PublicKey.findProgramAddressSync(
+    [Buffer.from("holder_stats"), mint.toBuffer()],
+    HOLDER_TRACKER_PROGRAM_ID
+  )[0];
+
+const holderPositionPda = (tokenAccount: PublicKey) =>
+  PublicKey.findProgramAddressSync(
+    [Buffer.from("position"), tokenAccount.toBuffer()],
+    HOLDER_TRACKER_PROGRAM_ID
+  )[0];
+
+// Accounts the holder tracker hook needs for a transfer the program makes
+// (the hook's seeds do not depend on the amount)
+const transferHookAccounts = async (
+  connection: Connection,
+  mint: PublicKey,
+  source: PublicKey,
+  destination: PublicKey,
+  owner: PublicKey
+) => {
+  const ix = new TransactionInstruction({ keys: [], programId: TOKEN_2022_PROGRAM_ID });
+  await addExtraAccountMetasForExecute(
+    connection,
+    ix,
+    HOLDER_TRACKER_PROGRAM_ID,
+    source,
+    mint,
+    destination,
+    owner,
+    0
+  );
+  return ix.keys;
+};
+
 export const createToken = async (
-  program: Program<MemeTokenLaunchpad>,
+  program: Program<MemeLaunchpad>,
   publicKey: PublicKey,
   name: string,
   symbol: string,
   uri: string,
   decimals: number,
-  initialSupply: BN
+  initialSupply: BN,
+  options: LaunchOptions = defaultLaunchOptions(program)
 ): Promise<TransactionSignature> => {
   const [programStatePda] = PublicKey.findProgramAddressSync(
     [Buffer.from("program_state")],
@@ -137,20 +219,58 @@ export const createToken = async (
     program.programId
   );
 
+  const [creatorVaultPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("creator_vault"), tokenId.toArrayLike(Buffer, "le", 8)],
+    program.programId
+  );
+
   const [mintPda] = PublicKey.findProgramAddressSync(
     [Buffer.from("mint"), bondingCurvePda.toBuffer()],
     program.programId
   );
 
+  const [vestingEscrowPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("vesting_escrow"), tokenId.toArrayLike(Buffer, "le", 8)],
+    program.programId
+  );
+  const vestingTokenAccount = getAssociatedTokenAddressSync(
+    mintPda,
+    vestingEscrowPda,
+    true,
+    TOKEN_2022_PROGRAM_ID
+  );
+
+  const [airdropPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("airdrop"), tokenId.toArrayLike(Buffer, "le", 8)],
+    program.programId
+  );
+
+  const holderTracking = options.holderTracking;
+  const vesting = options.creatorVesting !== null;
+
   const tx = await program.methods
-    .createToken(name, symbol, uri, decimals, initialSupply)
+    .createToken(name, symbol, uri, decimals, initialSupply, options)
     .accountsPartial({
       programState: programStatePda,
       tokenInfo: tokenInfoPda,
       bondingCurve: bondingCurvePda,
+      creatorVault: creatorVaultPda,
       mint: mintPda,
       creator: publicKey,
+      holderTrackerProgram: holderTracking ? HOLDER_TRACKER_PROGRAM_ID : null,
+      extraAccountMetaList: holderTracking
+        ? PublicKey.findProgramAddressSync(
+            [Buffer.from("extra-account-metas"), mintPda.toBuffer()],
+            HOLDER_TRACKER_PROGRAM_ID
+          )[0]
+        : null,
+      holderStats: holderTracking ? holderStatsPda(mintPda) : null,
+      vestingEscrow: vesting ? vestingEscrowPda : null,
+      vestingTokenAccount: vesting ? vestingTokenAccount : null,
+      vestingPosition: vesting && holderTracking ? holderPositionPda(vestingTokenAccount) : null,
+      airdrop: options.airdrop !== null ? airdropPda : null,
       tokenProgram: TOKEN_2022_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
       systemProgram: SystemProgram.programId,
       rent: SYSVAR_RENT_PUBKEY,
     })
@@ -159,12 +279,13 @@ export const createToken = async (
   return tx;
 };
 
-export const buyToken = async (
-  program: Program<MemeTokenLaunchpad>,
+// Shared by the trade page and the buy action
+export const buyTokenInstruction = async (
+  program: Program<MemeLaunchpad>,
   publicKey: PublicKey,
   tokenId: BN,
   solAmount: BN
-): Promise<TransactionSignature> => {
+): Promise<TransactionInstruction> => {
   const [programStatePda] = PublicKey.findProgramAddressSync(
     [Buffer.from("program_state")],
     program.programId
@@ -181,13 +302,19 @@ export const buyToken = async (
     program.programId
   );
 
-  const [mintPda] = PublicKey.findProgramAddressSync(
-    [Buffer.from("mint"), bondingCurvePda.toBuffer()],
+  const [platformFeeVaultPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("platform_fee_vault")],
+    program.programId
+  );
+
+  const [creatorVaultPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("creator_vault"), tokenId.toArrayLike(Buffer, "le", 8)],
     program.programId
   );
 
+  const mint = tokenInfo.mint;
   const buyerTokenAccount = getAssociatedTokenAddressSync(
-    mintPda,
+    mint,
     publicKey,
     false,
     TOKEN_2022_PROGRAM_ID,
@@ -205,29 +332,67 @@ export const buyToken = async (
     program.programId
   );
 
-  const tx = await program.methods
+  // After launch the token trades against the built-in pool instead of the curve
+  const [liquidityPoolPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("liquidity_pool"), tokenId.toArrayLike(Buffer, "le", 8)],
+    program.programId
+  );
+  const [poolTokenVaultPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("pool_vault"), tokenId.toArrayLike(Buffer, "le", 8)],
+    program.programId
+  );
+  const usePool = tokenInfo.launchedToDex;
+  const holderTracking = tokenInfo.holderTracking;
+
+  const hookAccounts =
+    usePool && holderTracking
+      ? await transferHookAccounts(
+          program.provider.connection,
+          mint,
+          poolTokenVaultPda,
+          buyerTokenAccount,
+          liquidityPoolPda
+        )
+      : [];
+
+  return await program.methods
     .buyToken(tokenId, solAmount)
     .accountsPartial({
       programState: programStatePda,
       tokenInfo: tokenInfoPda,
       bondingCurve: bondingCurvePda,
       transaction: transactionPda,
-      mint: mintPda,
+      liquidityPool: usePool ? liquidityPoolPda : null,
+      poolTokenVault: usePool ? poolTokenVaultPda : null,
+      mint,
       buyerTokenAccount: buyerTokenAccount,
-      tokenCreator: tokenInfo.creator,
+      platformFeeVault: platformFeeVaultPda,
+      creatorVault: creatorVaultPda,
       buyer: publicKey,
+      holderTrackerProgram: holderTracking ? HOLDER_TRACKER_PROGRAM_ID : null,
+      holderStats: holderTracking ? holderStatsPda(mint) : null,
+      holderPosition: holderTracking ? holderPositionPda(buyerTokenAccount) : null,
       tokenProgram: TOKEN_2022_PROGRAM_ID,
       associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
       systemProgram: SystemProgram.programId,
       rent: SYSVAR_RENT_PUBKEY,
     })
-    .rpc();
+    .remainingAccounts(hookAccounts)
+    .instruction();
+};
 
-  return tx;
+export const buyToken = async (
+  program: Program<MemeLaunchpad>,
+  publicKey: PublicKey,
+  tokenId: BN,
+  solAmount: BN
+): Promise<TransactionSignature> => {
+  const ix = await buyTokenInstruction(program, publicKey, tokenId, solAmount);
+  return await program.provider.sendAndConfirm!(new Transaction().add(ix));
 };
 
 export const sellToken = async (
-  program: Program<MemeTokenLaunchpad>,
+  program: Program<MemeLaunchpad>,
   publicKey: PublicKey,
   tokenId: BN,
   tokenAmount: BN
@@ -248,13 +413,19 @@ export const sellToken = async (
     program.programId
   );
 
-  const [mintPda] = PublicKey.findProgramAddressSync(
-    [Buffer.from("mint"), bondingCurvePda.toBuffer()],
+  const [platformFeeVaultPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("platform_fee_vault")],
     program.programId
   );
 
+  const [creatorVaultPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("creator_vault"), tokenId.toArrayLike(Buffer, "le", 8)],
+    program.programId
+  );
+
+  const mint = tokenInfo.mint;
   const sellerTokenAccount = getAssociatedTokenAddressSync(
-    mintPda,
+    mint,
     publicKey,
     false,
     TOKEN_2022_PROGRAM_ID
@@ -271,6 +442,28 @@ export const sellToken = async (
     program.programId
   );
 
+  const [liquidityPoolPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("liquidity_pool"), tokenId.toArrayLike(Buffer, "le", 8)],
+    program.programId
+  );
+  const [poolTokenVaultPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("pool_vault"), tokenId.toArrayLike(Buffer, "le", 8)],
+    program.programId
+  );
+  const usePool = tokenInfo.launchedToDex;
+  const holderTracking = tokenInfo.holderTracking;
+
+  const hookAccounts =
+    usePool && holderTracking
+      ? await transferHookAccounts(
+          program.provider.connection,
+          mint,
+          sellerTokenAccount,
+          poolTokenVaultPda,
+          publicKey
+        )
+      : [];
+
   const tx = await program.methods
     .sellToken(tokenId, tokenAmount)
     .accountsPartial({
@@ -278,24 +471,30 @@ export const sellToken = async (
       tokenInfo: tokenInfoPda,
       bondingCurve: bondingCurvePda,
       transaction: transactionPda,
-      mint: mintPda,
+      liquidityPool: usePool ? liquidityPoolPda : null,
+      poolTokenVault: usePool ? poolTokenVaultPda : null,
+      mint,
       sellerTokenAccount: sellerTokenAccount,
-      tokenCreator: tokenInfo.creator,
+      platformFeeVault: platformFeeVaultPda,
+      creatorVault: creatorVaultPda,
       seller: publicKey,
+      holderTrackerProgram: holderTracking ? HOLDER_TRACKER_PROGRAM_ID : null,
+      holderStats: holderTracking ? holderStatsPda(mint) : null,
+      holderPosition: holderTracking ? holderPositionPda(sellerTokenAccount) : null,
       tokenProgram: TOKEN_2022_PROGRAM_ID,
       associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
       systemProgram: SystemProgram.programId,
     })
+    .remainingAccounts(hookAccounts)
     .rpc();
 
   return tx;
 };
 
 export const launchToDex = async (
-  program: Program<MemeTokenLaunchpad>,
+  program: Program<MemeLaunchpad>,
   publicKey: PublicKey,
-  tokenId: BN,
-  liquidityAmount: BN
+  tokenId: BN
 ): Promise<TransactionSignature> => {
   const [programStatePda] = PublicKey.findProgramAddressSync(
     [Buffer.from("program_state")],
@@ -313,11 +512,12 @@ export const launchToDex = async (
     program.programId
   );
 
-  const [mintPda] = PublicKey.findProgramAddressSync(
-    [Buffer.from("mint"), bondingCurvePda.toBuffer()],
+  const [platformFeeVaultPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("platform_fee_vault")],
     program.programId
   );
 
+  const mint = tokenInfo.mint;
   const nextTxId = tokenInfo.transactionCount.add(new BN(1));
   const [transactionPda] = PublicKey.findProgramAddressSync(
     [
@@ -329,29 +529,150 @@ export const launchToDex = async (
     program.programId
   );
 
+  const holderTracking = tokenInfo.holderTracking;
+  let launchEscrow: PublicKey | null = null;
+  let escrowTokenAccount: PublicKey | null = null;
+  let escrowLpAccount: PublicKey | null = null;
+  let ammPool: PublicKey | null = null;
+  let ammLpMint: PublicKey | null = null;
+  let ammTokenVault: PublicKey | null = null;
+  let liquidityPool: PublicKey | null = null;
+  let poolTokenVault: PublicKey | null = null;
+  let ammProgram: PublicKey;
+  let hookAccounts: Awaited<ReturnType<typeof transferHookAccounts>> = [];
+
+  if ("builtinPool" in tokenInfo.graduationTarget) {
+    [liquidityPool] = PublicKey.findProgramAddressSync(
+      [Buffer.from("liquidity_pool"), tokenId.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    [poolTokenVault] = PublicKey.findProgramAddressSync(
+      [Buffer.from("pool_vault"), tokenId.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    ammProgram = program.programId;
+  } else if ("constantProductAmm" in tokenInfo.graduationTarget) {
+    // The escrow deposits into a pool owned by the token's AMM program
+    ammProgram = tokenInfo.graduationProgram;
+    [launchEscrow] = PublicKey.findProgramAddressSync(
+      [Buffer.from("launch_escrow"), tokenId.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    [ammPool] = PublicKey.findProgramAddressSync(
+      [Buffer.from("pool"), mint.toBuffer(), launchEscrow.toBuffer()],
+      ammProgram
+    );
+    [ammLpMint] = PublicKey.findProgramAddressSync(
+      [Buffer.from("lp_mint"), ammPool.toBuffer()],
+      ammProgram
+    );
+    [ammTokenVault] = PublicKey.findProgramAddressSync(
+      [Buffer.from("token_vault"), ammPool.toBuffer()],
+      ammProgram
+    );
+    escrowTokenAccount = getAssociatedTokenAddressSync(
+      mint,
+      launchEscrow,
+      true,
+      TOKEN_2022_PROGRAM_ID
+    );
+    escrowLpAccount = getAssociatedTokenAddressSync(
+      ammLpMint,
+      launchEscrow,
+      true,
+      TOKEN_2022_PROGRAM_ID
+    );
+
+    if (holderTracking) {
+      hookAccounts = await transferHookAccounts(
+        program.provider.connection,
+        mint,
+        escrowTokenAccount,
+        ammTokenVault,
+        launchEscrow
+      );
+    }
+  } else {
+    throw new Error("Concentrated-liquidity launches are not supported from the app");
+  }
+
+  // Liquidity is minted into the pool vault or the escrow token account
+  const liquidityTokenAccount = poolTokenVault ?? escrowTokenAccount!;
+
+  const [airdropPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("airdrop"), tokenId.toArrayLike(Buffer, "le", 8)],
+    program.programId
+  );
+  const airdropTokenAccount = getAssociatedTokenAddressSync(
+    mint,
+    airdropPda,
+    true,
+    TOKEN_2022_PROGRAM_ID
+  );
+  const hasAirdrop = tokenInfo.airdropAllocation.gt(new BN(0));
+
   const tx = await program.methods
-    .launchToDex(tokenId, liquidityAmount)
+    .launchToDex(tokenId, nextTxId)
     .accountsPartial({
       programState: programStatePda,
       tokenInfo: tokenInfoPda,
       bondingCurve: bondingCurvePda,
       transaction: transactionPda,
-      mint: mintPda,
-      tokenCreator: tokenInfo.creator,
+      platformFeeVault: platformFeeVaultPda,
+      mint,
+      launchEscrow,
+      escrowTokenAccount,
+      escrowLpAccount,
+      ammPool,
+      ammLpMint,
+      ammTokenVault,
+      liquidityPool,
+      poolTokenVault,
+      airdrop: hasAirdrop ? airdropPda : null,
+      airdropTokenAccount: hasAirdrop ? airdropTokenAccount : null,
+      holderTrackerProgram: holderTracking ? HOLDER_TRACKER_PROGRAM_ID : null,
+      holderStats: holderTracking ? holderStatsPda(mint) : null,
+      liquidityPosition: holderTracking ? holderPositionPda(liquidityTokenAccount) : null,
+      airdropPosition: hasAirdrop && holderTracking ? holderPositionPda(airdropTokenAccount) : null,
       launcher: publicKey,
+      ammProgram,
       tokenProgram: TOKEN_2022_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
       systemProgram: SystemProgram.programId,
     })
+    .remainingAccounts(hookAccounts)
     .rpc();
 
   return tx;
 };
 
-export const updatePlatformSettings = async (
-  program: Program<MemeTokenLaunchpad>,
+// Settings changes wait out the governance delay before `executePlatformSettings`
+export const schedulePlatformSettings = async (
+  program: Program<MemeLaunchpad>,
   publicKey: PublicKey,
   newFeeRate: BN,
-  newLaunchThreshold: BN
+  newLaunchThreshold: BN,
+  newGovernanceDelay: BN
+): Promise<TransactionSignature> => {
+  const [programStatePda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("program_state")],
+    program.programId
+  );
+
+  const tx = await program.methods
+    .schedulePlatformSettings(newFeeRate, newLaunchThreshold, newGovernanceDelay)
+    .accountsPartial({
+      programState: programStatePda,
+      feeManager: publicKey,
+    })
+    .rpc();
+
+  return tx;
+};
+
+export const executePlatformSettings = async (
+  program: Program<MemeLaunchpad>,
+  publicKey: PublicKey
 ): Promise<TransactionSignature> => {
   const [programStatePda] = PublicKey.findProgramAddressSync(
     [Buffer.from("program_state")],
@@ -359,28 +680,29 @@ export const updatePlatformSettings = async (
   );
 
   const tx = await program.methods
-    .updatePlatformSettings(newFeeRate, newLaunchThreshold)
+    .executePlatformSettings()
     .accountsPartial({
       programState: programStatePda,
-      authority: publicKey,
+      feeManager: publicKey,
     })
     .rpc();
 
   return tx;
 };
 
-export const getAllTokens = async (program: Program<MemeTokenLaunchpad>) => {
+export const getAllTokens = async (program: Program<MemeLaunchpad>) => {
   return await program.account.tokenInfo.all();
 };
 
 export const getAllTokensByCreator = async (
-  program: Program<MemeTokenLaunchpad>,
+  program: Program<MemeLaunchpad>,
   creator: PublicKey
 ) => {
   return await program.account.tokenInfo.all([
     {
       memcmp: {
-        offset: 8,
+        // discriminator + token_id + mint
+        offset: 48,
         bytes: creator.toBase58(),
       },
     },
@@ -407,7 +729,7 @@ export const getUserTokenBalance = async (
 };
 
 export const getTokenProgress = async (
-  program: Program<MemeTokenLaunchpad>,
+  program: Program<MemeLaunchpad>,
   tokenId: BN
 ) : Promise<number> => {
    const curve = await getBondingCurve(program, tokenId);